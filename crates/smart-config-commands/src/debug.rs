@@ -8,8 +8,9 @@ use anstream::stream::{AsLockedWrite, RawStream};
 use anstyle::{AnsiColor, Color, Style};
 use smart_config::{
     ConfigRepository, ParseError, ParseErrors,
-    metadata::ConfigMetadata,
-    value::{FileFormat, ValueOrigin, WithOrigin},
+    metadata::{ConfigMetadata, TypeDescription},
+    validation::Severity,
+    value::{FileFormat, StrValue, Value, ValueOrigin, WithOrigin},
     visit::{ConfigVisitor, VisitConfig},
 };
 
@@ -35,6 +36,14 @@ const ERROR_LABEL: Style = Style::new()
     .bold()
     .bg_color(Some(Color::Ansi(AnsiColor::Red)))
     .fg_color(None);
+const WARNING_LABEL: Style = Style::new()
+    .bold()
+    .bg_color(Some(Color::Ansi(AnsiColor::Yellow)))
+    .fg_color(None);
+const INFO_LABEL: Style = Style::new()
+    .bold()
+    .bg_color(Some(Color::Ansi(AnsiColor::Blue)))
+    .fg_color(None);
 
 #[derive(Debug)]
 struct ParamValuesVisitor {
@@ -60,10 +69,13 @@ impl ConfigVisitor for ParamValuesVisitor {
 
     fn visit_param(&mut self, param_index: usize, value: &dyn any::Any) {
         let param = self.config.params[param_index];
-        let json = if param.type_description().contains_secrets() {
+        let description = param.type_description();
+        let json = if description.contains_secrets() {
             "[REDACTED]".into()
         } else {
-            param.deserializer.serialize_param(value)
+            let mut json = param.deserializer.serialize_param(value);
+            description.redact_json(&mut json, "[REDACTED]");
+            json
         };
         self.param_values.insert(param_index, json);
     }
@@ -86,6 +98,7 @@ impl ConfigErrors {
     fn new(repo: &ConfigRepository<'_>) -> Self {
         let mut by_param = HashMap::<_, Vec<_>>::new();
         let mut by_config = HashMap::<_, Vec<_>>::new();
+        let mut parsed_configs = HashMap::new();
         for config_parser in repo.iter() {
             if !config_parser.config().is_top_level() {
                 // The config should be parsed as a part of the parent config. Filtering out these configs
@@ -94,25 +107,52 @@ impl ConfigErrors {
                 continue;
             }
 
-            if let Err(errors) = config_parser.parse_opt() {
-                // Only insert errors for a certain param / config if errors for it were not encountered before.
-                let mut new_params = HashSet::new();
-                let mut new_configs = HashSet::new();
+            let config = config_parser.config();
+            let key = (config.metadata().ty.id(), config.prefix().to_owned());
+            let errors: Vec<_> = match config_parser.parse_opt_with_warnings() {
+                Ok((parsed, warnings)) => {
+                    parsed_configs.insert(key, parsed);
+                    warnings
+                }
+                Err(mut errors) => {
+                    parsed_configs.insert(key, None);
+                    let warnings = errors.take_warnings();
+                    errors.into_iter().chain(warnings).collect()
+                }
+            };
+
+            // Only insert errors for a certain param / config if errors for it were not encountered before.
+            let mut new_params = HashSet::new();
+            let mut new_configs = HashSet::new();
 
-                for err in errors {
-                    let key = (err.config().ty.id(), err.path().to_owned());
-                    if err.param().is_some() {
-                        if !by_param.contains_key(&key) || new_params.contains(&key) {
-                            by_param.entry(key.clone()).or_default().push(err);
-                            new_params.insert(key);
-                        }
-                    } else if !by_config.contains_key(&key) || new_configs.contains(&key) {
-                        by_config.entry(key.clone()).or_default().push(err);
-                        new_configs.insert(key);
+            for err in errors {
+                let key = (err.config().ty.id(), err.path().to_owned());
+                if err.param().is_some() {
+                    if !by_param.contains_key(&key) || new_params.contains(&key) {
+                        by_param.entry(key.clone()).or_default().push(err);
+                        new_params.insert(key);
                     }
+                } else if !by_config.contains_key(&key) || new_configs.contains(&key) {
+                    by_config.entry(key.clone()).or_default().push(err);
+                    new_configs.insert(key);
                 }
             }
         }
+
+        // Schema-level validations are only run for successfully parsed configs, so their errors don't duplicate parsing errors.
+        // Parsed configs are reused by validations, so that configs are not parsed twice.
+        if let Err(errors) = repo.validate_parsed(&mut parsed_configs) {
+            for err in errors {
+                let key = (err.config().ty.id(), err.path().to_owned());
+                let target = if err.param().is_some() {
+                    &mut by_param
+                } else {
+                    &mut by_config
+                };
+                target.entry(key).or_default().push(err);
+            }
+        }
+
         Self {
             by_param,
             by_config,
@@ -126,14 +166,17 @@ impl From<ConfigErrors> for Result<(), ParseErrors> {
             .by_config
             .into_values()
             .chain(errors.by_param.into_values())
-            .flatten();
+            .flatten()
+            .filter(|err| err.severity().is_fatal());
         errors.collect()
     }
 }
 
 impl<W: RawStream + AsLockedWrite> Printer<W> {
     /// Prints debug info for all param values in the provided `repo`. If params fail to deserialize,
-    /// corresponding error(s) are output as well.
+    /// corresponding error(s) are output as well. Errors for failed [schema-level validations](smart_config::ConfigSchema::validate_configs())
+    /// are output for the participating params. Non-fatal validation failures (i.e., ones with [`Warn`](Severity::Warn)
+    /// or [`Info`](Severity::Info) severity) are output as warnings; they do not influence the returned result.
     ///
     /// # Errors
     ///
@@ -214,7 +257,17 @@ impl<W: RawStream + AsLockedWrite> Printer<W> {
                 let canonical_path = param_ref.canonical_path();
 
                 let raw_value = merged.pointer(&canonical_path);
-                let param_value = param_values.get(&param_idx);
+                // The param may contain secret parts (e.g., a password in a URL).
+                let redacted_raw_value =
+                    raw_value.map(|raw| redact_raw(raw, &param.type_description()));
+                let raw_value = redacted_raw_value.as_ref();
+                let mut param_value = param_values.get(&param_idx);
+                let redacted_value;
+                if param_value.is_some() && raw_value.is_some_and(contains_secrets) {
+                    // The param isn't secret itself, but its raw value is (e.g., it was decrypted).
+                    redacted_value = serde_json::Value::from("[REDACTED]");
+                    param_value = Some(&redacted_value);
+                }
                 let mut param_written = false;
                 if param_value.is_some() || raw_value.is_some() {
                     write_param(
@@ -250,6 +303,22 @@ impl<W: RawStream + AsLockedWrite> Printer<W> {
     }
 }
 
+fn contains_secrets(value: &WithOrigin) -> bool {
+    match &value.inner {
+        Value::String(StrValue::Secret(_)) => true,
+        Value::Array(items) => items.iter().any(contains_secrets),
+        Value::Object(map) => map.values().any(contains_secrets),
+        _ => false,
+    }
+}
+
+/// Redacts secret parts of a raw value (e.g., a password in a URL).
+fn redact_raw(value: &WithOrigin, description: &TypeDescription) -> WithOrigin {
+    let mut redacted = value.clone();
+    description.redact_raw_value(&mut redacted, "[REDACTED]");
+    redacted
+}
+
 fn write_origin(writer: &mut impl io::Write, origin: &ValueOrigin) -> io::Result<()> {
     match origin {
         ValueOrigin::EnvVars => {
@@ -346,22 +415,40 @@ fn write_param(
         write!(writer, "  Origin: ")?;
         write_origin(writer, &value.origin)?;
         writeln!(writer)?;
+    } else if let (Some(_), Some(computed)) = (visited_value, &param_ref.param.computed_default) {
+        writeln!(writer, "  Origin: default {computed}")?;
     }
     Ok(())
 }
 
 fn write_de_errors(writer: &mut impl io::Write, errors: &[ParseError]) -> io::Result<()> {
-    if errors.len() == 1 {
-        write!(writer, "  {ERROR_LABEL}Error:{ERROR_LABEL:#} ")?;
-        write_de_error(writer, &errors[0])
-    } else {
-        writeln!(writer, "  {ERROR_LABEL}Errors:{ERROR_LABEL:#}")?;
-        for err in errors {
-            write!(writer, "  - ")?;
-            write_de_error(writer, err)?;
+    const GROUPS: [(Severity, Style, &str); 3] = [
+        (Severity::Deny, ERROR_LABEL, "Error"),
+        (Severity::Warn, WARNING_LABEL, "Warning"),
+        (Severity::Info, INFO_LABEL, "Info"),
+    ];
+
+    for (severity, style, label) in GROUPS {
+        let errors: Vec<_> = errors
+            .iter()
+            .filter(|err| err.severity() == severity)
+            .collect();
+        match errors.as_slice() {
+            [] => {}
+            [err] => {
+                write!(writer, "  {style}{label}:{style:#} ")?;
+                write_de_error(writer, err)?;
+            }
+            _ => {
+                writeln!(writer, "  {style}{label}s:{style:#}")?;
+                for err in errors {
+                    write!(writer, "  - ")?;
+                    write_de_error(writer, err)?;
+                }
+            }
         }
-        Ok(())
     }
+    Ok(())
 }
 
 fn write_de_error(writer: &mut impl io::Write, err: &ParseError) -> io::Result<()> {
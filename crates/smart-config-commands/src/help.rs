@@ -4,8 +4,12 @@ use anstream::stream::{AsLockedWrite, RawStream};
 use anstyle::{AnsiColor, Color, Style};
 use smart_config::{
     ConfigRef, ConfigSchema,
-    metadata::{BasicTypes, ConfigTag, ConfigVariant, TypeDescription, TypeSuffixes},
+    metadata::{
+        BasicTypes, ConfigTag, ConfigVariant, Deprecation, TagRepresentation, TypeDescription,
+        TypeSuffixes,
+    },
     pat::{PatternDisplay, RawStr},
+    validation::Severity,
 };
 
 use crate::{
@@ -18,6 +22,7 @@ const INDENT: &str = "  ";
 const DIMMED: Style = Style::new().dimmed();
 const MAIN_NAME: Style = Style::new().bold();
 const DEPRECATED: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Red)));
+const NON_FATAL: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Yellow)));
 const DEFAULT_VARIANT: Style = Style::new().bold();
 const FIELD: Style = Style::new().underline();
 const UNIT: Style = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Cyan)));
@@ -51,8 +56,11 @@ impl<W: RawStream + AsLockedWrite> Printer<W> {
                 continue;
             }
 
-            let validations = config.metadata().validations;
-            if !validations.is_empty() {
+            let metadata = config.metadata();
+            if !metadata.validations.is_empty()
+                || !metadata.constraints.is_empty()
+                || config_deprecation(config).is_some()
+            {
                 write_config_help(&mut writer, config)?;
                 writeln!(&mut writer)?;
             }
@@ -74,12 +82,18 @@ impl<W: RawStream + AsLockedWrite> Printer<W> {
     }
 }
 
+fn config_deprecation(config: ConfigRef<'_>) -> Option<&'static Deprecation> {
+    let (_, link) = config.parent_link()?;
+    link.deprecation.as_ref()
+}
+
 fn write_config_help(writer: &mut impl io::Write, config: ConfigRef<'_>) -> io::Result<()> {
-    writeln!(
+    write!(
         writer,
         "{MAIN_NAME}{CONFIG_PATH}{}{CONFIG_PATH:#}{MAIN_NAME:#}",
         config.prefix()
     )?;
+    write_deprecation(writer, config_deprecation(config))?;
     for (alias, options) in config.aliases() {
         let config_style = if options.is_deprecated {
             CONFIG_PATH.strikethrough()
@@ -99,14 +113,46 @@ fn write_config_help(writer: &mut impl io::Write, config: ConfigRef<'_>) -> io::
         config.metadata().ty.name_in_code()
     )?;
 
-    writeln!(writer, "{INDENT}{FIELD}Validations{FIELD:#}:")?;
-    for &validation in config.metadata().validations {
-        let description = validation.to_string();
-        writeln!(writer, "{INDENT}- {description}")?;
+    let validations = config.metadata().validations;
+    if !validations.is_empty() {
+        writeln!(writer, "{INDENT}{FIELD}Validations{FIELD:#}:")?;
+        for &validation in validations {
+            let description = validation.to_string();
+            write!(writer, "{INDENT}- {description}")?;
+            write_severity(writer, validation.severity())?;
+        }
+    }
+
+    let constraints = config.metadata().constraints;
+    if !constraints.is_empty() {
+        writeln!(writer, "{INDENT}{FIELD}Constraints{FIELD:#}:")?;
+        for constraint in constraints {
+            writeln!(writer, "{INDENT}- {constraint}")?;
+        }
     }
     Ok(())
 }
 
+/// Writes the deprecation marker (if any) and terminates the line.
+fn write_deprecation(
+    writer: &mut impl io::Write,
+    deprecation: Option<&Deprecation>,
+) -> io::Result<()> {
+    if let Some(deprecation) = deprecation {
+        writeln!(writer, " {DEPRECATED}[{deprecation}]{DEPRECATED:#}")
+    } else {
+        writeln!(writer)
+    }
+}
+
+fn write_severity(writer: &mut impl io::Write, severity: Severity) -> io::Result<()> {
+    if severity.is_fatal() {
+        writeln!(writer)
+    } else {
+        writeln!(writer, " {NON_FATAL}[{severity}]{NON_FATAL:#}")
+    }
+}
+
 fn write_tag_help(
     writer: &mut impl io::Write,
     config: ConfigRef<'_>,
@@ -118,6 +164,17 @@ fn write_tag_help(
         param: tag.param,
     }
     .write_locations(writer)?;
+    match tag.representation {
+        TagRepresentation::External => writeln!(
+            writer,
+            "{INDENT}{FIELD}Representation{FIELD:#}: externally tagged; variant params are nested under the variant key"
+        )?,
+        TagRepresentation::Untagged => writeln!(
+            writer,
+            "{INDENT}{FIELD}Representation{FIELD:#}: untagged; variant is detected by the set params"
+        )?,
+        _ => { /* internal tag, no need to explain */ }
+    }
     writeln!(
         writer,
         "{INDENT}{FIELD}Type{FIELD:#}: string tag with variants:"
@@ -132,13 +189,14 @@ fn write_tag_help(
             String::new()
         };
 
-        writeln!(
+        write!(
             writer,
             "{INDENT}- {STRING}'{name}'{STRING:#} {DIMMED}[Rust: {config_name}::{rust_name}]{DIMMED:#}{default_marker}",
             name = variant.name,
             config_name = config.metadata().ty.name_in_code(),
             rust_name = variant.rust_name
         )?;
+        write_deprecation(writer, variant.deprecation.as_ref())?;
         if !variant.aliases.is_empty() {
             write!(writer, "{INDENT}  {FIELD}Aliases{FIELD:#}: ")?;
             for (i, &alias) in variant.aliases.iter().enumerate() {
@@ -149,6 +207,7 @@ fn write_tag_help(
             }
             writeln!(writer)?;
         }
+        write_variant_keys(writer, config, tag, variant)?;
 
         if !variant.help.is_empty() {
             for line in variant.help.lines() {
@@ -161,6 +220,35 @@ fn write_tag_help(
     ParamRef::write_tag_conditions(writer, condition_count, conditions.iter().copied())
 }
 
+/// Writes keys identifying the variant for non-internally tagged enums.
+fn write_variant_keys(
+    writer: &mut impl io::Write,
+    config: ConfigRef<'_>,
+    tag: &ConfigTag,
+    variant: &ConfigVariant,
+) -> io::Result<()> {
+    let (field, keys): (_, Vec<_>) = match tag.representation {
+        TagRepresentation::External => ("Keys", variant.external_keys().collect()),
+        TagRepresentation::Untagged => {
+            let keys = config.metadata().variant_keys(variant);
+            ("Params", keys.into_iter().map(str::to_owned).collect())
+        }
+        _ => return Ok(()),
+    };
+    if keys.is_empty() {
+        return Ok(());
+    }
+
+    write!(writer, "{INDENT}  {FIELD}{field}{FIELD:#}: ")?;
+    for (i, key) in keys.iter().enumerate() {
+        write!(writer, "{STRING}'{key}'{STRING:#}")?;
+        if i + 1 < keys.len() {
+            write!(writer, ", ")?;
+        }
+    }
+    writeln!(writer)
+}
+
 impl ParamRef<'_> {
     fn write_locations(&self, writer: &mut impl io::Write) -> io::Result<()> {
         let all_paths = self.all_paths();
@@ -179,17 +267,19 @@ impl ParamRef<'_> {
             } else {
                 Style::new()
             };
-            main_name = false;
             write!(
                 writer,
                 "{DIMMED}{prefix}{prefix_sep}{DIMMED:#}{name_style}{name}{name_style:#}"
             )?;
 
-            if options.is_deprecated {
+            if main_name {
+                write_deprecation(writer, self.param.deprecation.as_ref())?;
+            } else if options.is_deprecated {
                 writeln!(writer, " {DEPRECATED}[deprecated alias]{DEPRECATED:#}")?;
             } else {
                 writeln!(writer)?;
             }
+            main_name = false;
         }
         Ok(())
     }
@@ -217,6 +307,8 @@ impl ParamRef<'_> {
             write!(writer, "{INDENT}{FIELD}Default{FIELD:#}: ")?;
             write_json_value(writer, default, 2)?;
             writeln!(writer)?;
+        } else if let Some(computed) = &self.param.computed_default {
+            writeln!(writer, "{INDENT}{FIELD}Default{FIELD:#}: {computed}")?;
         }
 
         let example = self
@@ -229,6 +321,17 @@ impl ParamRef<'_> {
             writeln!(writer)?;
         }
 
+        if !self.param.env_vars.is_empty() {
+            write!(writer, "{INDENT}{FIELD}Env vars{FIELD:#}: ")?;
+            for (i, env_var) in self.param.env_vars.iter().enumerate() {
+                write!(writer, "{STRING}{env_var}{STRING:#}")?;
+                if i + 1 < self.param.env_vars.len() {
+                    write!(writer, ", ")?;
+                }
+            }
+            writeln!(writer)?;
+        }
+
         if let Some(fallback) = self.param.fallback {
             write!(writer, "{INDENT}{FIELD}Fallbacks{FIELD:#}: ")?;
             let fallback = fallback.to_string();
@@ -297,6 +400,14 @@ fn write_separator(
     }
 }
 
+fn format_allowed_values(values: &[String]) -> String {
+    let values: Vec<_> = values
+        .iter()
+        .map(|value| format!("{STRING}{value}{STRING:#}"))
+        .collect();
+    format!("; one of [{}]", values.join(", "))
+}
+
 fn write_type_description(
     writer: &mut impl io::Write,
     relation_to_parent: Option<&str>,
@@ -327,11 +438,15 @@ fn write_type_description(
     } else {
         String::new()
     };
+    let allowed_values = description
+        .allowed_values()
+        .map(format_allowed_values)
+        .unwrap_or_default();
 
     let field_name = relation_to_parent.unwrap_or("Type");
     writeln!(
         writer,
-        "{:>indent$}{FIELD}{field_name}{FIELD:#}: {ty}{details}{unit}",
+        "{:>indent$}{FIELD}{field_name}{FIELD:#}: {ty}{details}{unit}{allowed_values}",
         ""
     )?;
 
@@ -339,14 +454,23 @@ fn write_type_description(
     if let (None, Some(suffixes)) = (relation_to_parent, description.suffixes()) {
         let suffixes = match suffixes {
             TypeSuffixes::DurationUnits => Some(format!(
-                "duration units from millis to weeks, e.g. {STRING}_ms{STRING:#} or {STRING}_in_sec{STRING:#}"
+                "duration units from nanos to weeks, e.g. {STRING}_ms{STRING:#} or {STRING}_in_sec{STRING:#}"
             )),
             TypeSuffixes::SizeUnits => Some(format!(
-                "byte size units up to gigabytes, e.g. {STRING}_mb{STRING:#} or {STRING}_in_kib{STRING:#}"
+                "binary and decimal byte size units up to pebibytes, e.g. {STRING}_mb{STRING:#} or {STRING}_in_kib{STRING:#}"
             )),
             TypeSuffixes::EtherUnits => Some(format!(
                 "ether value units, e.g. {STRING}_gwei{STRING:#} or {STRING}_in_ether{STRING:#}"
             )),
+            TypeSuffixes::RateUnits => Some(format!(
+                "rate units, e.g. {STRING}_per_sec{STRING:#} or {STRING}_per_min{STRING:#}"
+            )),
+            TypeSuffixes::BandwidthUnits => Some(format!(
+                "bandwidth units, e.g. {STRING}_mib_per_sec{STRING:#} or {STRING}_kb_per_s{STRING:#}"
+            )),
+            TypeSuffixes::RatioUnits => Some(format!(
+                "ratio units, e.g. {STRING}_percent{STRING:#} or {STRING}_bps{STRING:#}"
+            )),
             _ => None,
         };
         if let Some(suffixes) = &suffixes {
@@ -361,8 +485,9 @@ fn write_type_description(
     let validations = description.validations();
     if !validations.is_empty() {
         writeln!(writer, "{:>indent$}{FIELD}Validations{FIELD:#}:", "")?;
-        for validation in validations {
-            writeln!(writer, "{:>indent$}- {validation}", "")?;
+        for (validation, severity) in validations {
+            write!(writer, "{:>indent$}- {validation}", "")?;
+            write_severity(writer, *severity)?;
         }
     }
 
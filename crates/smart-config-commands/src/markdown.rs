@@ -4,8 +4,12 @@ use anstream::stream::{AsLockedWrite, RawStream};
 use cmark_writer::{CommonMarkWriter, ListItem, Node};
 use smart_config::{
     ConfigRef, ConfigSchema,
-    metadata::{BasicTypes, ConfigTag, ConfigVariant, TypeDescription, TypeSuffixes},
+    metadata::{
+        BasicTypes, ComputedDefault, ConfigTag, ConfigVariant, Deprecation, ParamConstraint,
+        ParamConstraintKind, TagRepresentation, TypeDescription, TypeSuffixes,
+    },
     pat::PatternDisplay,
+    validation::Severity,
 };
 
 use crate::{ParamRef, Printer, schema_ref::collect_conditions};
@@ -212,6 +216,14 @@ fn render_config_reference(
             vec![code(config.metadata().ty.name_in_code())],
         ));
     }
+    if let Some((_, link)) = config.parent_link()
+        && let Some(deprecation) = &link.deprecation
+    {
+        nodes.push(labeled_paragraph(
+            "Deprecated",
+            format_deprecation(deprecation),
+        ));
+    }
     if options.include_aliases {
         let aliases = format_aliases(config.aliases());
         if !aliases.is_empty() {
@@ -225,7 +237,22 @@ fn render_config_reference(
                 .metadata()
                 .validations
                 .iter()
-                .map(|validation| list_item(vec![paragraph(vec![text(&validation.to_string())])]))
+                .map(|validation| {
+                    let description =
+                        format_validation(&validation.to_string(), validation.severity());
+                    list_item(vec![paragraph(description)])
+                })
+                .collect(),
+        ));
+    }
+    if !config.metadata().constraints.is_empty() {
+        nodes.push(labeled_paragraph("Constraints", vec![]));
+        nodes.push(Node::UnorderedList(
+            config
+                .metadata()
+                .constraints
+                .iter()
+                .map(|constraint| list_item(vec![paragraph(format_constraint(constraint))]))
                 .collect(),
         ));
     }
@@ -271,6 +298,19 @@ fn render_tag_reference(
         ));
     }
 
+    let representation = match tag.representation {
+        TagRepresentation::External => {
+            Some("externally tagged; variant params are nested under the variant key")
+        }
+        TagRepresentation::Untagged => Some("untagged; variant is detected by the set params"),
+        _ => None,
+    };
+    if let Some(representation) = representation {
+        nodes.push(labeled_paragraph(
+            "Representation",
+            vec![text(representation)],
+        ));
+    }
     nodes.push(labeled_paragraph(
         "Type",
         vec![text("string tag with variants:")],
@@ -307,12 +347,33 @@ fn render_variant(config: ConfigRef<'_>, tag: &ConfigTag, variant: &ConfigVarian
         content.push(text(variant.help));
     }
 
-    let mut blocks = vec![paragraph(content)];
+    let mut details = vec![];
+    if let Some(deprecation) = &variant.deprecation {
+        details.push(list_item(vec![labeled_paragraph(
+            "Deprecated",
+            format_deprecation(deprecation),
+        )]));
+    }
     if !variant.aliases.is_empty() {
         let aliases = format_strs_as_code(variant.aliases.iter().copied());
-        blocks.push(Node::UnorderedList(vec![list_item(vec![
-            labeled_paragraph("Aliases", aliases),
-        ])]));
+        details.push(list_item(vec![labeled_paragraph("Aliases", aliases)]));
+    }
+    let keys = match tag.representation {
+        TagRepresentation::External => Some(("Keys", variant.external_keys().collect())),
+        TagRepresentation::Untagged => {
+            let keys = config.metadata().variant_keys(variant);
+            Some(("Params", keys.into_iter().map(str::to_owned).collect()))
+        }
+        _ => None,
+    };
+    if let Some((label, keys)) = keys.filter(|(_, keys): &(_, Vec<String>)| !keys.is_empty()) {
+        let keys = format_strs_as_code(keys.iter().map(String::as_str));
+        details.push(list_item(vec![labeled_paragraph(label, keys)]));
+    }
+
+    let mut blocks = vec![paragraph(content)];
+    if !details.is_empty() {
+        blocks.push(Node::UnorderedList(details));
     }
     list_item(blocks)
 }
@@ -328,6 +389,12 @@ fn render_param_reference(
         vec![code(&param_ref.canonical_path())],
     ));
 
+    if let Some(deprecation) = &param_ref.param.deprecation {
+        nodes.push(labeled_paragraph(
+            "Deprecated",
+            format_deprecation(deprecation),
+        ));
+    }
     if options.include_aliases {
         let aliases = format_aliases(param_ref.all_paths().skip(1));
         if !aliases.is_empty() {
@@ -335,13 +402,19 @@ fn render_param_reference(
         }
     }
     if let Some(env_options) = &options.include_env_vars {
-        nodes.push(labeled_paragraph(
-            "Environment variable",
-            vec![code(&env_var_name(
-                &env_options.prefix,
-                &param_ref.canonical_path(),
-            ))],
-        ));
+        let derived_name = env_var_name(&env_options.prefix, &param_ref.canonical_path());
+        let env_vars = param_ref
+            .param
+            .env_vars
+            .iter()
+            .copied()
+            .chain([derived_name.as_str()]);
+        let label = if param_ref.param.env_vars.is_empty() {
+            "Environment variable"
+        } else {
+            "Environment variables"
+        };
+        nodes.push(labeled_paragraph(label, format_strs_as_code(env_vars)));
     }
 
     let description = param_ref.param.type_description();
@@ -370,6 +443,11 @@ fn render_param_reference(
     let default = param_ref.param.default_value_json();
     if let Some(default) = &default {
         render_json_value(nodes, "Default", default)?;
+    } else if let Some(computed) = &param_ref.param.computed_default {
+        nodes.push(labeled_paragraph(
+            "Default",
+            format_computed_default(computed),
+        ));
     }
     let example = param_ref
         .param
@@ -412,6 +490,16 @@ fn render_type_doc(
         summary.push(text("; unit: "));
         summary.push(code(&unit.to_string()));
     }
+    if let Some(values) = description.allowed_values() {
+        summary.push(text("; one of ["));
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                summary.push(text(", "));
+            }
+            summary.push(code(value));
+        }
+        summary.push(text("]"));
+    }
 
     let mut doc = DetailDoc::new(field, summary);
     if is_top_level && let Some(suffixes) = description.suffixes().and_then(format_suffixes) {
@@ -424,7 +512,9 @@ fn render_type_doc(
             children: description
                 .validations()
                 .iter()
-                .map(|validation| DetailDoc::new("", vec![text(validation)]))
+                .map(|(validation, severity)| {
+                    DetailDoc::new("", format_validation(validation, *severity))
+                })
                 .collect(),
         });
     }
@@ -587,6 +677,71 @@ fn format_aliases<A: AsRef<str>>(
     rendered
 }
 
+fn format_deprecation(deprecation: &Deprecation) -> Vec<Node> {
+    let mut rendered = vec![];
+    if let Some(since) = deprecation.since {
+        rendered.extend([text("since "), code(since)]);
+    }
+    if let Some(remove_in) = deprecation.remove_in {
+        if !rendered.is_empty() {
+            rendered.push(text(", "));
+        }
+        rendered.extend([text("removed in "), code(remove_in)]);
+    }
+    if let Some(note) = deprecation.note {
+        if !rendered.is_empty() {
+            rendered.push(text("; "));
+        }
+        rendered.push(text(note));
+    }
+    if rendered.is_empty() {
+        rendered.push(text("yes"));
+    }
+    rendered
+}
+
+fn format_computed_default(computed: &ComputedDefault) -> Vec<Node> {
+    if let Some(description) = computed.description {
+        vec![text("computed as "), text(description)]
+    } else {
+        let mut rendered = vec![text("computed from ")];
+        rendered.extend(format_strs_as_code(computed.dependencies.iter().copied()));
+        rendered
+    }
+}
+
+fn format_validation(description: &str, severity: Severity) -> Vec<Node> {
+    let mut rendered = vec![text(description)];
+    if !severity.is_fatal() {
+        rendered.push(text(&format!(" (severity: {severity})")));
+    }
+    rendered
+}
+
+fn format_constraint(constraint: &ParamConstraint) -> Vec<Node> {
+    match constraint.kind {
+        ParamConstraintKind::RequiredWith { param, other } => vec![
+            code(param.name),
+            text(" is required if "),
+            code(other.name),
+            text(" is set"),
+        ],
+        ParamConstraintKind::RequiredIf { param, condition } => {
+            vec![code(param.name), text(" is required if "), text(condition)]
+        }
+        ParamConstraintKind::ConflictsWith { param, other } => {
+            vec![code(param.name), text(" conflicts with "), code(other.name)]
+        }
+        ParamConstraintKind::OneOfRequired { params } => {
+            let mut rendered = vec![text("at least one of ")];
+            rendered.extend(format_strs_as_code(params.iter().map(|param| param.name)));
+            rendered.push(text(" is required"));
+            rendered
+        }
+        _ => vec![text(&constraint.to_string())],
+    }
+}
+
 fn format_strs_as_code<'a>(values: impl Iterator<Item = &'a str>) -> Vec<Node> {
     let mut rendered = vec![];
     for (i, value) in values.enumerate() {
@@ -601,13 +756,13 @@ fn format_strs_as_code<'a>(values: impl Iterator<Item = &'a str>) -> Vec<Node> {
 fn format_suffixes(suffixes: TypeSuffixes) -> Option<Vec<Node>> {
     match suffixes {
         TypeSuffixes::DurationUnits => Some(vec![
-            text("duration units from millis to weeks, for example "),
+            text("duration units from nanos to weeks, for example "),
             code("_ms"),
             text(" or "),
             code("_in_sec"),
         ]),
         TypeSuffixes::SizeUnits => Some(vec![
-            text("byte size units up to gigabytes, for example "),
+            text("binary and decimal byte size units up to pebibytes, for example "),
             code("_mb"),
             text(" or "),
             code("_in_kib"),
@@ -618,6 +773,24 @@ fn format_suffixes(suffixes: TypeSuffixes) -> Option<Vec<Node>> {
             text(" or "),
             code("_in_ether"),
         ]),
+        TypeSuffixes::RateUnits => Some(vec![
+            text("rate units, for example "),
+            code("_per_sec"),
+            text(" or "),
+            code("_per_min"),
+        ]),
+        TypeSuffixes::BandwidthUnits => Some(vec![
+            text("bandwidth units, for example "),
+            code("_mib_per_sec"),
+            text(" or "),
+            code("_kb_per_s"),
+        ]),
+        TypeSuffixes::RatioUnits => Some(vec![
+            text("ratio units, for example "),
+            code("_percent"),
+            text(" or "),
+            code("_bps"),
+        ]),
         _ => None,
     }
 }
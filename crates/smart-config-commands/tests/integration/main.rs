@@ -1,17 +1,17 @@
 //! **Important.** The generated snapshots are specific to stable Rust; nightly Rust provides better spanning for types
 //! (`Option<u64>` instead of `Option`).
 
-use std::fmt;
+use std::{fmt, path::PathBuf};
 
 use anstream::AutoStream;
 use smart_config::{
     ConfigSchema, DescribeConfig, DeserializeConfig, Environment, ExampleConfig, SerializerOptions,
     testing::Tester,
 };
-use smart_config_commands::{MarkdownOptions, Printer};
+use smart_config_commands::{EnvVarOptions, MarkdownOptions, Printer};
 use test_casing::{Product, test_casing};
 
-use crate::configs::{ObjectStoreConfig, TestConfig, create_mock_repo};
+use crate::configs::{ObjectStoreConfig, S3Config, TestConfig, create_mock_repo};
 
 mod configs;
 
@@ -25,6 +25,42 @@ enum DataAvailabilityConfig {
     ObjectStore(ObjectStoreConfig),
 }
 
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+struct ArchiveConfig {
+    /// Object store to put archived data to.
+    #[config(nest)]
+    store: ArchiveStoreConfig,
+    /// Source of the archived data.
+    #[config(nest)]
+    source: ArchiveSourceConfig,
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(tag = external, rename_all = "snake_case")]
+enum ArchiveStoreConfig {
+    /// Stores objects locally as files.
+    #[config(default)]
+    Local,
+    /// Stores objects in AWS S3.
+    #[config(alias = "aws")]
+    S3(S3Config),
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(untagged)]
+enum ArchiveSourceConfig {
+    /// Fetches data over HTTP.
+    Http {
+        /// URL to fetch data from.
+        url: String,
+    },
+    /// Reads data from a local file.
+    File {
+        /// Path to the file.
+        path: PathBuf,
+    },
+}
+
 #[test]
 fn full_config_help() {
     let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
@@ -51,6 +87,24 @@ fn filtered_config_help() {
     insta::assert_snapshot!("help_filtered", buffer);
 }
 
+#[test]
+fn config_help_with_coerced_param_names() {
+    let mut schema = ConfigSchema::default();
+    schema
+        .coerce_param_names(true)
+        .insert(&TestConfig::DESCRIPTION, "test")
+        .unwrap();
+
+    let mut buffer = vec![];
+    Printer::custom(AutoStream::never(&mut buffer))
+        .print_help(&schema, |param| {
+            param.all_paths().any(|(path, _)| path.contains("fund"))
+        })
+        .unwrap();
+    let buffer = String::from_utf8(buffer).unwrap();
+    insta::assert_snapshot!("help_coerced_param_names", buffer);
+}
+
 #[test]
 fn embedded_enum_config_help() {
     let schema = ConfigSchema::new(&DataAvailabilityConfig::DESCRIPTION, "da");
@@ -63,6 +117,18 @@ fn embedded_enum_config_help() {
     insta::assert_snapshot!("help_embedded_enum", buffer);
 }
 
+#[test]
+fn non_internal_enum_configs_help() {
+    let schema = ConfigSchema::new(&ArchiveConfig::DESCRIPTION, "archive");
+
+    let mut buffer = vec![];
+    Printer::custom(AutoStream::never(&mut buffer))
+        .print_help(&schema, |_| true)
+        .unwrap();
+    let buffer = String::from_utf8(buffer).unwrap();
+    insta::assert_snapshot!("help_non_internal_enums", buffer);
+}
+
 #[test]
 fn full_config_markdown_reference() {
     let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
@@ -89,6 +155,24 @@ fn filtered_config_markdown_reference() {
     insta::assert_snapshot!("markdown_filtered", buffer);
 }
 
+#[test]
+fn config_markdown_reference_with_env_vars() {
+    let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
+    let mut options = MarkdownOptions::default();
+    let mut env_options = EnvVarOptions::default();
+    env_options.prefix = "APP_".to_owned();
+    options.include_env_vars = Some(env_options);
+
+    let mut buffer = vec![];
+    Printer::custom(AutoStream::never(&mut buffer))
+        .print_markdown_reference(&schema, &options, |param| {
+            param.all_paths().any(|(path, _)| path.ends_with("port"))
+        })
+        .unwrap();
+    let buffer = String::from_utf8(buffer).unwrap();
+    insta::assert_snapshot!("markdown_env_vars", buffer);
+}
+
 #[test]
 fn embedded_enum_config_markdown_reference() {
     let schema = ConfigSchema::new(&DataAvailabilityConfig::DESCRIPTION, "da");
@@ -101,6 +185,18 @@ fn embedded_enum_config_markdown_reference() {
     insta::assert_snapshot!("markdown_embedded_enum", buffer);
 }
 
+#[test]
+fn non_internal_enum_configs_markdown_reference() {
+    let schema = ConfigSchema::new(&ArchiveConfig::DESCRIPTION, "archive");
+
+    let mut buffer = vec![];
+    Printer::custom(AutoStream::never(&mut buffer))
+        .print_markdown_reference(&schema, &MarkdownOptions::default(), |_| true)
+        .unwrap();
+    let buffer = String::from_utf8(buffer).unwrap();
+    insta::assert_snapshot!("markdown_non_internal_enums", buffer);
+}
+
 #[test]
 fn full_config_debug() {
     let mut tester = Tester::<()>::default();
@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::{quote, quote_spanned};
-use syn::{DeriveInput, spanned::Spanned};
+use syn::{DeriveInput, Member, spanned::Spanned};
 
 use crate::utils::{
     ConfigContainer, ConfigContainerFields, ConfigEnumVariant, ConfigField, RenameRule,
@@ -42,12 +42,17 @@ impl ConfigEnumVariant {
 
 impl ConfigContainer {
     fn process_fields<'a>(
+        cr: &'a proc_macro2::TokenStream,
         fields: &'a [ConfigField],
         param_index: &'a mut usize,
         nested_index: &'a mut usize,
     ) -> (proc_macro2::TokenStream, Vec<proc_macro2::TokenStream>) {
+        let local_var_for = |i: usize| Ident::new(&format!("__{i}"), fields[i].name.span());
+
         let mut init = proc_macro2::TokenStream::default();
-        let fields = fields.iter().enumerate().map(|(i, field)| {
+        // Params with computed defaults are deserialized after all other params since they may depend on them.
+        let mut computed_init = proc_macro2::TokenStream::default();
+        let field_inits = fields.iter().enumerate().map(|(i, field)| {
             let index;
             if field.attrs.nest {
                 index = *nested_index;
@@ -58,26 +63,53 @@ impl ConfigContainer {
             }
 
             let name = &field.name;
-            let value = field.deserialize_param(index);
-            let local_var = Ident::new(&format!("__{i}"), name.span());
-            init.extend(quote_spanned! {name.span()=>
-                let #local_var = #value;
-            });
+            let local_var = local_var_for(i);
+            if let Some(computed) = &field.attrs.computed_default {
+                let closure = &computed.closure;
+                let dependencies = computed.dependencies().map(|ident| {
+                    let dep_idx = fields
+                        .iter()
+                        .position(
+                            |field| matches!(&field.name, Member::Named(name) if name == ident),
+                        )
+                        .expect("checked when parsing");
+                    local_var_for(dep_idx)
+                });
+                let validations = if field.attrs.validations.is_empty() {
+                    quote!(&())
+                } else {
+                    let validations = field.validations(cr);
+                    quote!(&#cr::de::_private::ComputedValidations::new(&[#(#validations,)*]))
+                };
+                computed_init.extend(quote_spanned! {name.span()=>
+                    #[allow(clippy::redundant_closure_call)]
+                    let #local_var = ctx.deserialize_param_or_compute(#index, #validations, || {
+                        ::core::option::Option::Some((#closure)(#(#dependencies.as_ref().ok()?,)*))
+                    });
+                });
+            } else {
+                let value = field.deserialize_param(index);
+                init.extend(quote_spanned! {name.span()=>
+                    let #local_var = #value;
+                });
+            }
             quote_spanned!(name.span()=> #name: #local_var?)
         });
-        let fields = fields.collect();
+        let fields = field_inits.collect();
+        init.extend(computed_init);
         (init, fields)
     }
 
     fn derive_deserialize_config(&self) -> proc_macro2::TokenStream {
         let name = &self.name;
 
+        let cr = self.cr(Span::call_site());
         let mut param_index = 0;
         let mut nested_index = 0;
         let instance = match &self.fields {
             ConfigContainerFields::Struct(fields) => {
                 let (init, fields) =
-                    Self::process_fields(fields, &mut param_index, &mut nested_index);
+                    Self::process_fields(&cr, fields, &mut param_index, &mut nested_index);
                 quote!({
                     #init
                     Self { #(#fields,)* }
@@ -87,8 +119,12 @@ impl ConfigContainer {
                 let match_hands = variants.iter().map(|variant| {
                     let name = &variant.name;
                     let matches = variant.matches(self.attrs.rename_all);
-                    let (init, variant_fields) =
-                        Self::process_fields(&variant.fields, &mut param_index, &mut nested_index);
+                    let (init, variant_fields) = Self::process_fields(
+                        &cr,
+                        &variant.fields,
+                        &mut param_index,
+                        &mut nested_index,
+                    );
                     quote!(#matches => {
                         #init
                         Self::#name { #(#variant_fields,)* }
@@ -108,9 +144,9 @@ impl ConfigContainer {
             }
         };
 
-        let cr = self.cr(Span::call_site());
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         quote! {
-            impl #cr::DeserializeConfig for #name {
+            impl #impl_generics #cr::DeserializeConfig for #name #ty_generics #where_clause {
                 #[allow(unused_mut)]
                 fn deserialize_config(
                     mut ctx: #cr::de::DeserializeContext<'_>,
@@ -3,11 +3,12 @@
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
 use quote::{ToTokens, quote, quote_spanned};
-use syn::{DeriveInput, LitStr, Type, spanned::Spanned};
+use syn::{DeriveInput, LitStr, Member, Type, spanned::Spanned};
 
 use crate::utils::{
     Alias, ConfigContainer, ConfigContainerFields, ConfigEnumVariant, ConfigField, DefaultValue,
-    RenameRule, Validation, wrap_in_option,
+    Deprecation, RenameRule, TagRepresentation, Validation, instantiate_with_computed_defaults,
+    wrap_in_option,
 };
 
 impl DefaultValue {
@@ -34,12 +35,28 @@ impl DefaultValue {
 impl Validation {
     fn wrap(&self, cr: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
         let expr = &self.expr;
-        if let Some(description) = &self.description {
+        let validation = if let Some(description) = &self.description {
             quote_spanned! {expr.span()=>
                 #cr::validation::_private::WithDescription::new(#expr, #description)
             }
         } else {
             quote!(#expr)
+        };
+
+        if let Some(severity) = &self.severity {
+            let variant = match severity.to_string().as_str() {
+                "warn" => quote_spanned!(severity.span()=> Warn),
+                "info" => quote_spanned!(severity.span()=> Info),
+                _ => quote_spanned!(severity.span()=> Deny),
+            };
+            quote_spanned! {expr.span()=>
+                #cr::validation::_private::WithSeverity::new(
+                    #validation,
+                    #cr::validation::Severity::#variant,
+                )
+            }
+        } else {
+            validation
         }
     }
 }
@@ -52,6 +69,37 @@ impl Alias {
     }
 }
 
+impl Deprecation {
+    fn describe(&self, cr: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let note = self.note.iter();
+        let since = self.since.iter();
+        let remove_in = self.remove_in.iter();
+        quote_spanned! {self.span=>
+            #cr::metadata::Deprecation::new()
+                #(.with_note(#note))*
+                #(.since(#since))*
+                #(.remove_in(#remove_in))*
+        }
+    }
+
+    fn describe_opt(
+        this: Option<&Self>,
+        cr: &proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        wrap_in_option(this.map(|deprecation| deprecation.describe(cr)))
+    }
+
+    fn validate_versions(&self, cr: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let versions = self.since.iter().chain(&self.remove_in);
+        let validations = versions.map(|version| {
+            quote_spanned! {version.span()=>
+                const _: () = #cr::metadata::_private::assert_version(#version);
+            }
+        });
+        quote!(#(#validations)*)
+    }
+}
+
 impl ConfigField {
     /// **Important.** The ordering of deserializer wrappers is important! E.g., the post-validation wrapper
     /// must come last.
@@ -78,22 +126,17 @@ impl ConfigField {
             let wrapped = deserialize_if.wrap(cr);
             // A reference is required to convert to `&dyn Validate<_>`. `()`s are here to correctly handle some validation expressions
             // (e.g., `a..b` ranges; unless a range is parenthesized, `&` will be interpreted as a part of the range start).
+            // `const` expression ensures that the reference is `'static`; without it, the referenced value may not be promoted
+            // (e.g., in generic configs, where the deserializer is constructed in an inline `const` block).
             deserializer = quote_spanned! {deserialize_if.expr.span()=>
-                #cr::de::_private::DeserializeIf::<#ty, _>::new(#deserializer, &(#wrapped))
+                #cr::de::_private::DeserializeIf::<#ty, _>::new(#deserializer, const { &(#wrapped) })
             };
         }
         if let Some(default_fn) = self.default_fn() {
             deserializer = quote!(#cr::de::WithDefault::new(#deserializer, #default_fn));
         }
         if !self.attrs.validations.is_empty() {
-            let validations = self.attrs.validations.iter().map(|val| {
-                let wrapped = val.wrap(cr);
-                // A reference is required to convert to `&dyn Validate<_>`. `()`s are here to correctly handle some validation expressions
-                // (e.g., `a..b` ranges; unless a range is parenthesized, `&` will be interpreted as a part of the range start).
-                // `const` expression is required for static (non-constant) items (e.g., regular expressions) that implement `Drop`;
-                // without it, using such an item will lead to borrow checker errors.
-                quote_spanned!(val.expr.span()=> const { &(#wrapped) })
-            });
+            let validations = self.validations(cr);
             deserializer =
                 quote!(#cr::de::_private::Validated::new(#deserializer, &[#(#validations,)*]));
         }
@@ -101,6 +144,21 @@ impl ConfigField {
         deserializer
     }
 
+    /// Returns `&dyn Validate<_>` expressions for the param validations.
+    pub(crate) fn validations<'a>(
+        &'a self,
+        cr: &'a proc_macro2::TokenStream,
+    ) -> impl Iterator<Item = proc_macro2::TokenStream> + 'a {
+        self.attrs.validations.iter().map(|val| {
+            let wrapped = val.wrap(cr);
+            // A reference is required to convert to `&dyn Validate<_>`. `()`s are here to correctly handle some validation expressions
+            // (e.g., `a..b` ranges; unless a range is parenthesized, `&` will be interpreted as a part of the range start).
+            // `const` expression is required for static (non-constant) items (e.g., regular expressions) that implement `Drop`;
+            // without it, using such an item will lead to borrow checker errors.
+            quote_spanned!(val.expr.span()=> const { &(#wrapped) })
+        })
+    }
+
     fn validate_names(&self, parent: &ConfigContainer) -> proc_macro2::TokenStream {
         let name_span = self.name_span();
         let param_name = self.param_name();
@@ -119,9 +177,24 @@ impl ConfigField {
             }
         });
 
+        let env_vars_validation = self.attrs.env_vars.iter().map(|env_var| {
+            let cr = parent.cr(env_var.span());
+            quote_spanned! {env_var.span()=>
+                const _: () = #cr::metadata::_private::assert_env_var_name(#env_var);
+            }
+        });
+
+        let deprecation_validation = self
+            .attrs
+            .deprecation
+            .as_ref()
+            .map(|deprecation| deprecation.validate_versions(&parent.cr(deprecation.span)));
+
         quote! {
             #name_validation
             #(#aliases_validation)*
+            #(#env_vars_validation)*
+            #deprecation_validation
         }
     }
 
@@ -158,9 +231,35 @@ impl ConfigField {
 
         let cr = parent.cr(name_span);
         let deserializer = self.deserializer(&cr);
-        let tag_variant = wrap_in_option(variant_idx.map(|idx| quote!(&TAG_VARIANTS[#idx])));
+        let erased_deserializer = if parent.is_generic() {
+            quote_spanned! {name_span=>
+                &#cr::de::_private::LazyErased::<#ty, _>::new(deserializer, || const { #deserializer })
+            }
+        } else {
+            quote_spanned!(name_span=> &#cr::de::_private::Erased::<#ty, _>::new(deserializer))
+        };
+        let tag_variants = parent.tag_variants_ref();
+        let tag_variant = wrap_in_option(variant_idx.map(|idx| quote!(&#tag_variants[#idx])));
 
         let aliases = self.attrs.aliases.iter().map(|alias| alias.describe(&cr));
+        let env_vars = &self.attrs.env_vars;
+        let deprecation = Deprecation::describe_opt(self.attrs.deprecation.as_ref(), &cr);
+        let computed_default = self.attrs.computed_default.as_ref().map(|computed| {
+            let fields = parent.fields.variant_fields(variant_idx);
+            let dependencies = computed.dependencies().map(|ident| {
+                fields
+                    .iter()
+                    .find(|field| matches!(&field.name, Member::Named(name) if name == ident))
+                    .expect("checked when parsing")
+                    .param_name()
+            });
+            let description = computed
+                .description
+                .as_ref()
+                .map(|description| quote!(.with_description(#description)));
+            quote!(#cr::metadata::ComputedDefault::new(&[#(#dependencies,)*]) #description)
+        });
+        let computed_default = wrap_in_option(computed_default);
 
         quote_spanned! {name_span=> {
             let deserializer = #deserializer;
@@ -168,13 +267,16 @@ impl ConfigField {
             #cr::metadata::ParamMetadata {
                 name: #param_name,
                 aliases: &[#(#aliases,)*],
+                env_vars: &[#(#env_vars,)*],
                 help: #help,
                 rust_field_name: ::core::stringify!(#name),
                 rust_type: #cr::metadata::RustType::of::<#ty>(#ty_in_code),
                 expecting: #cr::de::_private::extract_expected_types::<#ty, _>(&deserializer),
                 tag_variant: #tag_variant,
-                deserializer: &#cr::de::_private::Erased::<#ty, _>::new(deserializer),
+                deprecation: #deprecation,
+                deserializer: #erased_deserializer,
                 default_value: #default_value,
+                computed_default: #computed_default,
                 example_value: #example_value,
                 fallback: #fallback,
             }
@@ -195,7 +297,9 @@ impl ConfigField {
         } else {
             self.param_name()
         };
-        let tag_variant = wrap_in_option(variant_idx.map(|idx| quote!(&TAG_VARIANTS[#idx])));
+        let tag_variants = parent.tag_variants_ref();
+        let tag_variant = wrap_in_option(variant_idx.map(|idx| quote!(&#tag_variants[#idx])));
+        let deprecation = Deprecation::describe_opt(self.attrs.deprecation.as_ref(), &cr);
 
         quote_spanned! {self.name_span()=>
             #cr::metadata::NestedConfigMetadata {
@@ -203,6 +307,7 @@ impl ConfigField {
                 aliases: &[#(#aliases,)*],
                 rust_field_name: ::core::stringify!(#name),
                 tag_variant: #tag_variant,
+                deprecation: #deprecation,
                 meta: &<#ty as #cr::DescribeConfig>::DESCRIPTION,
             }
         }
@@ -242,6 +347,7 @@ impl ConfigEnumVariant {
         let rust_name = &self.name;
         let aliases = self.attrs.aliases.iter();
         let help = &self.attrs.help;
+        let deprecation = Deprecation::describe_opt(self.attrs.deprecation.as_ref(), cr);
 
         quote_spanned! {self.name.span()=>
             #cr::metadata::ConfigVariant {
@@ -249,6 +355,7 @@ impl ConfigEnumVariant {
                 aliases: &[#(#aliases,)*],
                 rust_name: ::core::stringify!(#rust_name),
                 help: #help,
+                deprecation: #deprecation,
             }
         }
     }
@@ -334,8 +441,9 @@ impl ConfigContainer {
             }
         };
 
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         quote! {
-            impl #cr::visit::VisitConfig for #name {
+            impl #impl_generics #cr::visit::VisitConfig for #name #ty_generics #where_clause {
                 fn visit_config(&self, visitor: &mut dyn #cr::visit::ConfigVisitor) {
                     #visit_impl
                 }
@@ -354,17 +462,235 @@ impl ConfigContainer {
         }
     }
 
+    /// Describes param constraints. Constraints are only supported for struct configs; this is checked when parsing.
+    fn describe_constraints(&self, cr: &proc_macro2::TokenStream) -> Vec<proc_macro2::TokenStream> {
+        let ConfigContainerFields::Struct(fields) = &self.fields else {
+            return vec![];
+        };
+        let param_index = |ident: &Member| {
+            fields
+                .iter()
+                .filter(|field| !field.attrs.nest)
+                .position(|field| field.name == *ident)
+                .expect("referenced param was checked when parsing")
+        };
+
+        let params = self.params_ref();
+        let mut constraints = vec![];
+        for field in fields.iter().filter(|field| !field.attrs.nest) {
+            let name = &field.name;
+            let index = param_index(name);
+            for other in &field.attrs.required_with {
+                let other_index = param_index(&Member::Named(other.clone()));
+                constraints.push(quote_spanned! {other.span()=>
+                    #cr::metadata::ParamConstraint {
+                        kind: #cr::metadata::ParamConstraintKind::RequiredWith {
+                            param: &#params[#index],
+                            other: &#params[#other_index],
+                        },
+                        is_satisfied: |config| {
+                            let config = config.downcast_ref::<Self>().unwrap();
+                            config.#name.is_some() || config.#other.is_none()
+                        },
+                    }
+                });
+            }
+            for condition in &field.attrs.required_if {
+                let expr = &condition.expr;
+                let description = &condition.description;
+                constraints.push(quote_spanned! {expr.span()=>
+                    #cr::metadata::ParamConstraint {
+                        kind: #cr::metadata::ParamConstraintKind::RequiredIf {
+                            param: &#params[#index],
+                            condition: #description,
+                        },
+                        is_satisfied: |config| {
+                            let config = config.downcast_ref::<Self>().unwrap();
+                            config.#name.is_some() || !(#expr)(config)
+                        },
+                    }
+                });
+            }
+            for other in &field.attrs.conflicts_with {
+                let other_index = param_index(&Member::Named(other.clone()));
+                constraints.push(quote_spanned! {other.span()=>
+                    #cr::metadata::ParamConstraint {
+                        kind: #cr::metadata::ParamConstraintKind::ConflictsWith {
+                            param: &#params[#index],
+                            other: &#params[#other_index],
+                        },
+                        is_satisfied: |config| {
+                            let config = config.downcast_ref::<Self>().unwrap();
+                            config.#name.is_none() || config.#other.is_none()
+                        },
+                    }
+                });
+            }
+        }
+
+        for group in &self.attrs.one_of_required {
+            let (first, rest) = group.split_first().expect("checked when parsing");
+            let span = first.span();
+            let indices = group
+                .iter()
+                .map(|ident| param_index(&Member::Named(ident.clone())));
+            constraints.push(quote_spanned! {span=>
+                #cr::metadata::ParamConstraint {
+                    kind: #cr::metadata::ParamConstraintKind::OneOfRequired {
+                        params: &[#(&#params[#indices],)*],
+                    },
+                    is_satisfied: |config| {
+                        let config = config.downcast_ref::<Self>().unwrap();
+                        config.#first.is_some() #(|| config.#rest.is_some())*
+                    },
+                }
+            });
+        }
+        constraints
+    }
+
+    /// Returns the compile-time metadata check, either to be placed into the deserializer (for generic configs),
+    /// or as a standalone item.
+    fn metadata_assertion(
+        &self,
+        cr: &proc_macro2::TokenStream,
+    ) -> (
+        Option<proc_macro2::TokenStream>,
+        Option<proc_macro2::TokenStream>,
+    ) {
+        // Metadata of a generic config can only be checked once the config is monomorphized, so the check
+        // is placed into the deserializer, which is instantiated together with the metadata.
+        if self.is_generic() {
+            let assertion = quote! {
+                const { <Self as #cr::DescribeConfig>::DESCRIPTION.assert_valid() };
+            };
+            (Some(assertion), None)
+        } else {
+            let name = &self.name;
+            let assertion = quote! {
+                const _: () = <#name as #cr::DescribeConfig>::DESCRIPTION.assert_valid();
+            };
+            (None, Some(assertion))
+        }
+    }
+
+    /// Returns a reference to the params constant. Generic configs define it as an inherent associated constant
+    /// since items nested in `DESCRIPTION` cannot refer to generic params.
+    fn params_ref(&self) -> proc_macro2::TokenStream {
+        if self.is_generic() {
+            quote!(Self::__SMART_CONFIG_PARAMS)
+        } else {
+            quote!(PARAMS)
+        }
+    }
+
+    /// Same as [`Self::params_ref()`], but for enum tag variants.
+    fn tag_variants_ref(&self) -> proc_macro2::TokenStream {
+        if self.is_generic() {
+            quote!(Self::__SMART_CONFIG_TAG_VARIANTS)
+        } else {
+            quote!(TAG_VARIANTS)
+        }
+    }
+
+    /// Describes the enum tag, adding its param to `params`. Returns the variants constant and the tag description.
+    fn describe_tag(
+        &self,
+        cr: &proc_macro2::TokenStream,
+        params: &mut Vec<proc_macro2::TokenStream>,
+    ) -> Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+        let ConfigContainerFields::Enum {
+            tag,
+            representation,
+            variants,
+        } = &self.fields
+        else {
+            return None;
+        };
+
+        let (default_variant_idx, default) = variants
+            .iter()
+            .enumerate()
+            .find_map(|(i, variant)| {
+                variant
+                    .attrs
+                    .default
+                    .then(|| (i, variant.name(self.attrs.rename_all)))
+            })
+            .unzip();
+
+        let expected_variants = variants
+            .iter()
+            .flat_map(|variant| variant.expected_variants(self.attrs.rename_all));
+
+        let tag_span = tag.span();
+        let tag = ConfigField::from_tag(
+            cr,
+            tag,
+            *representation,
+            expected_variants,
+            default.as_deref(),
+        );
+        let tag_index = params.len();
+        params.push(tag.describe_param(self, None));
+
+        let tag_variants = variants
+            .iter()
+            .map(|variant| variant.describe(cr, self.attrs.rename_all));
+
+        let tag_variants_const = if self.is_generic() {
+            quote_spanned! {tag_span=>
+                #[doc(hidden)]
+                const __SMART_CONFIG_TAG_VARIANTS: &'static [#cr::metadata::ConfigVariant] =
+                    &[#(#tag_variants,)*];
+            }
+        } else {
+            quote_spanned! {tag_span=>
+                const TAG_VARIANTS: &[#cr::metadata::ConfigVariant] = &[#(#tag_variants,)*];
+            }
+        };
+        let tag_variants = self.tag_variants_ref();
+        let default_variant =
+            wrap_in_option(default_variant_idx.map(|i| quote!(&#tag_variants[#i])));
+        let params = self.params_ref();
+        let representation = match representation {
+            TagRepresentation::Internal => quote!(Internal),
+            TagRepresentation::External => quote!(External),
+            TagRepresentation::Untagged => quote!(Untagged),
+        };
+        let tag_description = quote_spanned! {tag_span=>
+            #cr::metadata::ConfigTag {
+                param: &#params[#tag_index],
+                variants: #tag_variants,
+                default_variant: #default_variant,
+                representation: #cr::metadata::TagRepresentation::#representation,
+            }
+        };
+        Some((tag_variants_const, tag_description))
+    }
+
     fn derive_describe_config(&self) -> proc_macro2::TokenStream {
         let name = &self.name;
         let cr = self.cr(name.span());
-        let name_str = name.to_string();
+        let name_str = self.name_with_generics();
         let help = &self.help;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
         let all_fields = self.fields.all_fields();
         let validations = all_fields
             .iter()
             .filter(|(_, field)| !field.attrs.flatten)
             .map(|(_, field)| field.validate_names(self));
+        let variant_validations = if let ConfigContainerFields::Enum { variants, .. } = &self.fields
+        {
+            let validations = variants.iter().filter_map(|variant| {
+                let deprecation = variant.attrs.deprecation.as_ref()?;
+                Some(deprecation.validate_versions(&self.cr(deprecation.span)))
+            });
+            validations.collect()
+        } else {
+            vec![]
+        };
 
         let is_enum = matches!(&self.fields, ConfigContainerFields::Enum { .. });
         let params = all_fields
@@ -375,47 +701,7 @@ impl ConfigContainer {
             });
         let mut params: Vec<_> = params.collect();
 
-        let mut tag_variants_const = None;
-        let mut tag_description = None;
-        if let ConfigContainerFields::Enum { tag, variants } = &self.fields {
-            // Add the tag field description
-            let (default_variant_idx, default) = variants
-                .iter()
-                .enumerate()
-                .find_map(|(i, variant)| {
-                    variant
-                        .attrs
-                        .default
-                        .then(|| (i, variant.name(self.attrs.rename_all)))
-                })
-                .unzip();
-
-            let expected_variants = variants
-                .iter()
-                .flat_map(|variant| variant.expected_variants(self.attrs.rename_all));
-
-            let tag_span = tag.span();
-            let tag = ConfigField::from_tag(&cr, tag, expected_variants, default.as_deref());
-            let tag_index = params.len();
-            params.push(tag.describe_param(self, None));
-
-            let tag_variants = variants
-                .iter()
-                .map(|variant| variant.describe(&cr, self.attrs.rename_all));
-
-            tag_variants_const = Some(quote_spanned! {tag_span=>
-                const TAG_VARIANTS: &[#cr::metadata::ConfigVariant] = &[#(#tag_variants,)*];
-            });
-            let default_variant =
-                wrap_in_option(default_variant_idx.map(|i| quote!(&TAG_VARIANTS[#i])));
-            tag_description = Some(quote_spanned! {tag_span=>
-                #cr::metadata::ConfigTag {
-                    param: &PARAMS[#tag_index],
-                    variants: TAG_VARIANTS,
-                    default_variant: #default_variant,
-                }
-            });
-        }
+        let (tag_variants_const, tag_description) = self.describe_tag(&cr, &mut params).unzip();
         let tag_description = wrap_in_option(tag_description);
 
         let nested_configs = all_fields.iter().filter_map(|(variant_idx, field)| {
@@ -425,46 +711,84 @@ impl ConfigContainer {
             None
         });
 
+        let constraints = self.describe_constraints(&cr);
         let config_validations = self
             .attrs
             .validations
             .iter()
-            .map(|val| Self::erase_validation(val, &cr, &name));
+            .map(|val| Self::erase_validation(val, &cr, &quote!(Self)));
 
-        quote! {
-            impl #cr::DescribeConfig for #name {
-                const DESCRIPTION: #cr::metadata::ConfigMetadata = {
-                    const PARAMS: &[#cr::metadata::ParamMetadata] = &[#(#params,)*];
+        let (generic_assertion, assertion) = self.metadata_assertion(&cr);
+
+        let ty = if self.is_generic() {
+            quote!(#cr::metadata::RustType::generic::<Self>(#name_str))
+        } else {
+            quote!(#cr::metadata::RustType::of::<Self>(#name_str))
+        };
+        let params_ref = self.params_ref();
+        let metadata = quote! {
+            #cr::metadata::ConfigMetadata {
+                ty: #ty,
+                help: #help,
+                params: #params_ref,
+                tag: #tag_description,
+                nested_configs: &[#(#nested_configs,)*],
+                constraints: &[#(#constraints,)*],
+                deserializer: |ctx| {
+                    #generic_assertion
+                    use #cr::metadata::_private::DeserializeBoxedConfig as _;
+                    let receiver = &::core::marker::PhantomData::<Self>;
+                    receiver.deserialize_boxed_config(ctx)
+                },
+                visitor: #cr::metadata::_private::box_config_visitor::<Self>(),
+                validations: &[#(#config_validations,)*],
+            }
+        };
+
+        let describe_impl = if self.is_generic() {
+            quote! {
+                impl #impl_generics #name #ty_generics #where_clause {
+                    #[doc(hidden)]
+                    const __SMART_CONFIG_PARAMS: &'static [#cr::metadata::ParamMetadata] =
+                        &[#(#params,)*];
                     #tag_variants_const
+                }
 
-                    #cr::metadata::ConfigMetadata {
-                        ty: #cr::metadata::RustType::of::<#name>(#name_str),
-                        help: #help,
-                        params: PARAMS,
-                        tag: #tag_description,
-                        nested_configs: &[#(#nested_configs,)*],
-                        deserializer: |ctx| {
-                            use #cr::metadata::_private::DeserializeBoxedConfig as _;
-                            let receiver = &::core::marker::PhantomData::<#name>;
-                            receiver.deserialize_boxed_config(ctx)
-                        },
-                        visitor: #cr::metadata::_private::box_config_visitor::<Self>(),
-                        validations: &[#(#config_validations,)*],
-                    }
-                };
+                impl #impl_generics #cr::DescribeConfig for #name #ty_generics #where_clause {
+                    const DESCRIPTION: #cr::metadata::ConfigMetadata = #metadata;
+                }
+            }
+        } else {
+            quote! {
+                impl #cr::DescribeConfig for #name {
+                    const DESCRIPTION: #cr::metadata::ConfigMetadata = {
+                        const PARAMS: &[#cr::metadata::ParamMetadata] = &[#(#params,)*];
+                        #tag_variants_const
+
+                        #metadata
+                    };
+                }
             }
+        };
 
+        quote! {
+            #describe_impl
             #(#validations)*
-            const _: () = <#name as #cr::DescribeConfig>::DESCRIPTION.assert_valid();
+            #(#variant_validations)*
+            #assertion
         }
     }
 
-    fn default_fields(fields: &[ConfigField]) -> syn::Result<Vec<proc_macro2::TokenStream>> {
-        let fields = fields.iter().map(|field| {
-            let name = &field.name;
+    fn default_instance(
+        constructor: &proc_macro2::TokenStream,
+        fields: &[ConfigField],
+    ) -> syn::Result<proc_macro2::TokenStream> {
+        instantiate_with_computed_defaults(constructor, fields, |field| {
             let name_span = field.name.span();
-            let field_instance = if let Some(default) = &field.attrs.default {
+            Ok(Some(if let Some(default) = &field.attrs.default {
                 default.instance(name_span)
+            } else if field.attrs.computed_default.is_some() {
+                return Ok(None);
             } else if ConfigField::is_option(&field.ty) {
                 quote_spanned!(field.ty.span()=> ::core::option::Option::None)
             } else if field.attrs.nest {
@@ -473,18 +797,13 @@ impl ConfigContainer {
             } else {
                 let msg = "Cannot derive(Default): field does not have a default value";
                 return Err(syn::Error::new(name_span, msg));
-            };
-            Ok(quote_spanned!(name_span=> #name: #field_instance))
-        });
-        fields.collect()
+            }))
+        })
     }
 
     fn derive_default(&self) -> syn::Result<proc_macro2::TokenStream> {
         let instance = match &self.fields {
-            ConfigContainerFields::Struct(fields) => {
-                let fields = Self::default_fields(fields)?;
-                quote!(Self { #(#fields,)* })
-            }
+            ConfigContainerFields::Struct(fields) => Self::default_instance(&quote!(Self), fields)?,
             ConfigContainerFields::Enum { variants, .. } => {
                 let default_variant = variants
                     .iter()
@@ -493,15 +812,15 @@ impl ConfigContainer {
                         let msg = "Cannot derive(Default): enum does not have a variant marked with #[config(default)]";
                         syn::Error::new(self.name.span(), msg)
                     })?;
-                let fields = Self::default_fields(&default_variant.fields)?;
                 let variant_name = &default_variant.name;
-                quote!(Self::#variant_name { #(#fields,)* })
+                Self::default_instance(&quote!(Self::#variant_name), &default_variant.fields)?
             }
         };
 
         let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         Ok(quote! {
-            impl ::core::default::Default for #name {
+            impl #impl_generics ::core::default::Default for #name #ty_generics #where_clause {
                 fn default() -> Self {
                     #instance
                 }
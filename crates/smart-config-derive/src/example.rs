@@ -3,20 +3,24 @@ use proc_macro2::Span;
 use quote::{quote, quote_spanned};
 use syn::DeriveInput;
 
-use crate::utils::{ConfigContainer, ConfigContainerFields, ConfigField};
+use crate::utils::{
+    ConfigContainer, ConfigContainerFields, ConfigField, instantiate_with_computed_defaults,
+};
 
 impl ConfigField {
+    /// Returns `None` if the example value should be computed using the `default_from` closure.
     fn example_initializer(
         &self,
         cr: &proc_macro2::TokenStream,
-    ) -> syn::Result<proc_macro2::TokenStream> {
-        let name = &self.name;
+    ) -> syn::Result<Option<proc_macro2::TokenStream>> {
         let name_span = self.name_span();
         let val = if let Some(example) = &self.attrs.example {
             // `example` attribute takes precedence, even if it's specified on a config
             quote!(#example)
         } else if let Some(default) = &self.attrs.default {
             default.instance(name_span)
+        } else if self.attrs.computed_default.is_some() {
+            return Ok(None);
         } else if self.attrs.nest {
             quote_spanned!(name_span=> #cr::ExampleConfig::example_config())
         } else if Self::is_option(&self.ty) {
@@ -25,7 +29,7 @@ impl ConfigField {
             let msg = "example or default value required to derive `ExampleConfig`";
             return Err(syn::Error::new(name_span, msg));
         };
-        Ok(quote_spanned!(name_span=> #name: #val))
+        Ok(Some(val))
     }
 }
 
@@ -36,12 +40,9 @@ impl ConfigContainer {
 
         let example_impl = match &self.fields {
             ConfigContainerFields::Struct(fields) => {
-                let fields: syn::Result<Vec<_>> = fields
-                    .iter()
-                    .map(|field| field.example_initializer(&cr))
-                    .collect();
-                let fields = fields?;
-                quote!(Self { #(#fields,)* })
+                instantiate_with_computed_defaults(&quote!(Self), fields, |field| {
+                    field.example_initializer(&cr)
+                })?
             }
             ConfigContainerFields::Enum { .. } => {
                 let mut msg = "Deriving `ExampleConfig` for enum configs isn't supported yet; implement `ExampleConfig` manually".to_owned();
@@ -52,8 +53,9 @@ impl ConfigContainer {
             }
         };
 
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         Ok(quote! {
-            impl #cr::ExampleConfig for #name {
+            impl #impl_generics #cr::ExampleConfig for #name #ty_generics #where_clause {
                 fn example_config() -> Self {
                     #example_impl
                 }
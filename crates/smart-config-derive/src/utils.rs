@@ -5,12 +5,13 @@ use std::{
     iter,
 };
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Span};
 use quote::{quote, quote_spanned};
 use syn::{
-    Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, Field, Fields, GenericArgument,
-    Index, Lit, LitStr, Member, Path, PathArguments, Token, Type, TypePath, ext::IdentExt,
-    parse::ParseStream, spanned::Spanned,
+    Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr, ExprClosure, Field, Fields,
+    GenericArgument, GenericParam, Generics, Index, Lit, LitStr, Member, Pat, Path, PathArguments,
+    Token, Type, TypePath, ext::IdentExt, parse::ParseStream, punctuated::Punctuated,
+    spanned::Spanned,
 };
 
 pub(crate) fn wrap_in_option(val: Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
@@ -59,29 +60,247 @@ fn parse_docs(attrs: &[Attribute]) -> String {
 pub(crate) struct Validation {
     pub(crate) expr: Expr,
     pub(crate) description: Option<Expr>,
+    pub(crate) severity: Option<Ident>,
 }
 
 impl Validation {
+    const SEVERITIES: [&'static str; 3] = ["deny", "warn", "info"];
+
     fn new(input: ParseStream<'_>) -> syn::Result<Self> {
         let content;
         syn::parenthesized!(content in input);
         let expr = content.parse()?;
 
-        let description = if content.is_empty() {
-            None
-        } else {
+        let mut description = None;
+        let mut severity = None;
+        while !content.is_empty() {
             content.parse::<Token![,]>()?;
-            Some(content.parse()?)
+            if content.is_empty() {
+                break;
+            }
+
+            let is_severity = content
+                .fork()
+                .parse::<Ident>()
+                .is_ok_and(|id| id == "severity")
+                && content.peek2(Token![=]);
+            if is_severity {
+                let key: Ident = content.parse()?;
+                content.parse::<Token![=]>()?;
+                let value: Ident = content.parse()?;
+                if severity.is_some() {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "severity is specified multiple times",
+                    ));
+                }
+                if !Self::SEVERITIES.iter().any(|&name| value == name) {
+                    let msg = format!(
+                        "unknown severity; should be one of: {}",
+                        Self::SEVERITIES.join(", ")
+                    );
+                    return Err(syn::Error::new(value.span(), msg));
+                }
+                severity = Some(value);
+            } else if description.is_none() && severity.is_none() {
+                description = Some(content.parse()?);
+            } else {
+                return Err(content.error("expected `severity = deny | warn | info`"));
+            }
+        }
+        Ok(Self {
+            expr,
+            description,
+            severity,
+        })
+    }
+}
+
+/// Deprecation info specified via `deprecated` / `deprecated(..)` attribute.
+#[derive(Debug)]
+pub(crate) struct Deprecation {
+    pub(crate) span: Span,
+    pub(crate) note: Option<LitStr>,
+    pub(crate) since: Option<LitStr>,
+    pub(crate) remove_in: Option<LitStr>,
+}
+
+impl Deprecation {
+    fn new(meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<Self> {
+        let mut this = Self {
+            span: meta.path.span(),
+            note: None,
+            since: None,
+            remove_in: None,
         };
-        Ok(Self { expr, description })
+        if !meta.input.peek(syn::token::Paren) {
+            return Ok(this);
+        }
+
+        meta.parse_nested_meta(|meta| {
+            let field = if meta.path.is_ident("note") {
+                &mut this.note
+            } else if meta.path.is_ident("since") {
+                &mut this.since
+            } else if meta.path.is_ident("remove_in") {
+                &mut this.remove_in
+            } else {
+                return Err(meta.error("Unsupported deprecation attribute; expected one of `note`, `since` or `remove_in`"));
+            };
+            if field.is_some() {
+                return Err(meta.error("Duplicate deprecation attribute"));
+            }
+            *field = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+        Ok(this)
+    }
+
+    fn set(target: &mut Option<Self>, meta: &syn::meta::ParseNestedMeta<'_>) -> syn::Result<()> {
+        if target.is_some() {
+            let msg = "Deprecation info is already specified";
+            return Err(meta.error(msg));
+        }
+        *target = Some(Self::new(meta)?);
+        Ok(())
     }
 }
 
+/// Default value computed from other params of the same config, specified via `default_from(..)` attribute.
+#[derive(Debug)]
+pub(crate) struct ComputedDefault {
+    /// Closure computing the default value. Closure args name the params the value depends on.
+    pub(crate) closure: ExprClosure,
+    pub(crate) description: Option<LitStr>,
+}
+
+impl ComputedDefault {
+    fn new(input: ParseStream<'_>) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let expr: Expr = content.parse()?;
+        let Expr::Closure(closure) = expr else {
+            let msg = "`default_from` expects a closure with args named after params of the config, e.g. `|port| port + 1`";
+            return Err(syn::Error::new(expr.span(), msg));
+        };
+
+        let mut description = None;
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+            if !content.is_empty() {
+                description = Some(content.parse()?);
+                if !content.is_empty() {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+        }
+
+        if closure.inputs.is_empty() {
+            let msg = "`default_from` closure must depend on at least one param of the config";
+            return Err(syn::Error::new(closure.span(), msg));
+        }
+        let this = Self {
+            closure,
+            description,
+        };
+        for input in &this.closure.inputs {
+            Self::arg_ident(input)?;
+        }
+        Ok(this)
+    }
+
+    fn arg_ident(input: &Pat) -> syn::Result<&Ident> {
+        let pat = match input {
+            Pat::Type(pat) => pat.pat.as_ref(),
+            _ => input,
+        };
+        match pat {
+            Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => Ok(&pat.ident),
+            _ => {
+                let msg =
+                    "`default_from` closure args must be identifiers naming params of the config";
+                Err(syn::Error::new(input.span(), msg))
+            }
+        }
+    }
+
+    /// Returns the names of fields the default value depends on.
+    pub(crate) fn dependencies(&self) -> impl Iterator<Item = &Ident> + '_ {
+        self.closure
+            .inputs
+            .iter()
+            .map(|input| Self::arg_ident(input).expect("checked when parsing"))
+    }
+
+    /// Annotates types of untyped closure args, so that the closure body can use methods on args.
+    fn annotate_arg_types(&mut self, fields: &[(Member, Type)]) {
+        for input in &mut self.closure.inputs {
+            if matches!(input, Pat::Type(_)) {
+                continue;
+            }
+            let ident = Self::arg_ident(input).expect("checked when parsing");
+            let (_, ty) = fields
+                .iter()
+                .find(|(name, _)| matches!(name, Member::Named(name) if name == ident))
+                .expect("checked previously");
+            *input = Pat::Type(syn::parse_quote_spanned!(ident.span()=> #input: &#ty));
+        }
+    }
+}
+
+/// Instantiates a struct config or an enum variant using the `constructor` path (e.g., `Self`). Fields are initialized
+/// with `init_field`; if it returns `None`, the field value is computed from other fields using its `default_from` closure.
+pub(crate) fn instantiate_with_computed_defaults(
+    constructor: &proc_macro2::TokenStream,
+    fields: &[ConfigField],
+    mut init_field: impl FnMut(&ConfigField) -> syn::Result<Option<proc_macro2::TokenStream>>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let local_var_for = |i: usize| Ident::new(&format!("__{i}"), fields[i].name_span());
+
+    let mut init = proc_macro2::TokenStream::default();
+    let mut computed_init = proc_macro2::TokenStream::default();
+    for (i, field) in fields.iter().enumerate() {
+        let name_span = field.name_span();
+        let local_var = local_var_for(i);
+        if let Some(value) = init_field(field)? {
+            init.extend(quote_spanned!(name_span=> let #local_var = #value;));
+            continue;
+        }
+
+        let computed = field
+            .attrs
+            .computed_default
+            .as_ref()
+            .expect("field initializer must be specified for fields without computed default");
+        let closure = &computed.closure;
+        let dependencies = computed.dependencies().map(|ident| {
+            let dep_idx = fields
+                .iter()
+                .position(|field| matches!(&field.name, Member::Named(name) if name == ident))
+                .expect("checked when parsing");
+            local_var_for(dep_idx)
+        });
+        computed_init.extend(quote_spanned! {name_span=>
+            #[allow(clippy::redundant_closure_call)]
+            let #local_var = (#closure)(#(&#dependencies,)*);
+        });
+    }
+
+    let names = fields.iter().map(|field| &field.name);
+    let local_vars = (0..fields.len()).map(local_var_for);
+    Ok(quote!({
+        #init
+        #computed_init
+        #constructor { #(#names: #local_vars,)* }
+    }))
+}
+
 #[derive(Debug)]
 pub(crate) struct ConfigVariantAttrs {
     pub(crate) rename: Option<LitStr>,
     pub(crate) aliases: Vec<LitStr>,
     pub(crate) default: bool,
+    pub(crate) deprecation: Option<Deprecation>,
     pub(crate) help: String,
 }
 
@@ -92,6 +311,7 @@ impl ConfigVariantAttrs {
         let mut rename = None;
         let mut aliases = vec![];
         let mut default = false;
+        let mut deprecation = None;
         for attr in config_attrs {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("rename") {
@@ -103,6 +323,13 @@ impl ConfigVariantAttrs {
                 } else if meta.path.is_ident("default") {
                     default = true;
                     Ok(())
+                } else if meta.path.is_ident("deprecated") {
+                    if meta.input.peek(Token![=]) {
+                        let msg = "Deprecated aliases are not supported for enum variants; \
+                                   use `deprecated` / `deprecated(..)` to deprecate the variant itself";
+                        return Err(meta.error(msg));
+                    }
+                    Deprecation::set(&mut deprecation, &meta)
                 } else {
                     Err(meta.error("Unsupported attribute"))
                 }
@@ -112,6 +339,7 @@ impl ConfigVariantAttrs {
             rename,
             aliases,
             default,
+            deprecation,
             help: parse_docs(attrs),
         })
     }
@@ -155,14 +383,21 @@ pub(crate) struct ConfigFieldAttrs {
     pub(crate) rename: Option<LitStr>,
     pub(crate) aliases: Vec<Alias>,
     pub(crate) default: Option<DefaultValue>,
+    pub(crate) computed_default: Option<ComputedDefault>,
     pub(crate) example: Option<Expr>,
     pub(crate) fallback: Option<Expr>,
+    /// Explicit env var names; the name from the `env` attribute (if any) goes first.
+    pub(crate) env_vars: Vec<LitStr>,
+    pub(crate) deprecation: Option<Deprecation>,
     pub(crate) flatten: bool,
     pub(crate) nest: bool,
     pub(crate) is_secret: bool,
     pub(crate) with: Option<Expr>,
     pub(crate) deserialize_if: Option<Validation>,
     pub(crate) validations: Vec<Validation>,
+    pub(crate) required_with: Vec<Ident>,
+    pub(crate) required_if: Vec<Validation>,
+    pub(crate) conflicts_with: Vec<Ident>,
 }
 
 impl ConfigFieldAttrs {
@@ -173,14 +408,21 @@ impl ConfigFieldAttrs {
         let mut rename = None;
         let mut aliases = vec![];
         let mut default = None;
+        let mut computed_default = None;
         let mut example = None;
         let mut fallback = None;
+        let mut env = None;
+        let mut env_aliases = vec![];
+        let mut deprecation = None;
         let mut nested_span = None;
         let mut flatten_span = None;
         let mut with = None;
         let mut secret_span = None;
         let mut deserialize_if = None;
         let mut validations = vec![];
+        let mut required_with = vec![];
+        let mut required_if = vec![];
+        let mut conflicts_with = vec![];
         for attr in config_attrs {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("rename") {
@@ -193,11 +435,15 @@ impl ConfigFieldAttrs {
                     });
                     Ok(())
                 } else if meta.path.is_ident("deprecated") {
-                    aliases.push(Alias {
-                        lit: meta.value()?.parse()?,
-                        is_deprecated: true,
-                    });
-                    Ok(())
+                    if meta.input.peek(Token![=]) {
+                        aliases.push(Alias {
+                            lit: meta.value()?.parse()?,
+                            is_deprecated: true,
+                        });
+                        Ok(())
+                    } else {
+                        Deprecation::set(&mut deprecation, &meta)
+                    }
                 } else if meta.path.is_ident("default") {
                     default = Some(if meta.input.peek(syn::Token![=]) {
                         DefaultValue::Path(meta.value()?.parse()?)
@@ -208,12 +454,25 @@ impl ConfigFieldAttrs {
                 } else if meta.path.is_ident("default_t") {
                     default = Some(DefaultValue::Expr(meta.value()?.parse()?));
                     Ok(())
+                } else if meta.path.is_ident("default_from") {
+                    computed_default = Some(ComputedDefault::new(meta.input)?);
+                    Ok(())
                 } else if meta.path.is_ident("example") {
                     example = Some(meta.value()?.parse::<Expr>()?);
                     Ok(())
                 } else if meta.path.is_ident("fallback") {
                     fallback = Some(meta.value()?.parse::<Expr>()?);
                     Ok(())
+                } else if meta.path.is_ident("env") {
+                    if env.is_some() {
+                        let msg = "`env` can be specified at most once; use `env_alias` for additional names";
+                        return Err(meta.error(msg));
+                    }
+                    env = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else if meta.path.is_ident("env_alias") {
+                    env_aliases.push(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
                 } else if meta.path.is_ident("flatten") {
                     flatten_span = Some(meta.path.span());
                     Ok(())
@@ -230,7 +489,30 @@ impl ConfigFieldAttrs {
                     validations.push(Validation::new(meta.input)?);
                     Ok(())
                 } else if meta.path.is_ident("deserialize_if") {
-                    deserialize_if = Some(Validation::new(meta.input)?);
+                    let condition = Validation::new(meta.input)?;
+                    if let Some(severity) = &condition.severity {
+                        let msg = "severity cannot be specified for `deserialize_if` conditions";
+                        return Err(syn::Error::new(severity.span(), msg));
+                    }
+                    deserialize_if = Some(condition);
+                    Ok(())
+                } else if meta.path.is_ident("required_with") {
+                    required_with.push(meta.value()?.parse::<Ident>()?);
+                    Ok(())
+                } else if meta.path.is_ident("required_if") {
+                    let condition = Validation::new(meta.input)?;
+                    if let Some(severity) = &condition.severity {
+                        let msg = "severity cannot be specified for `required_if` conditions";
+                        return Err(syn::Error::new(severity.span(), msg));
+                    }
+                    if condition.description.is_none() {
+                        let msg = "`required_if` condition must have a human-readable description";
+                        return Err(syn::Error::new(condition.expr.span(), msg));
+                    }
+                    required_if.push(condition);
+                    Ok(())
+                } else if meta.path.is_ident("conflicts_with") {
+                    conflicts_with.push(meta.value()?.parse::<Ident>()?);
                     Ok(())
                 } else {
                     Err(meta.error("Unsupported attribute"))
@@ -250,10 +532,29 @@ impl ConfigFieldAttrs {
             let msg = "cannot specify `with` for a `nest`ed / `flatten`ed configuration";
             return Err(syn::Error::new(with.span(), msg));
         }
+        if let Some(computed_default) = &computed_default {
+            let span = computed_default.closure.span();
+            if nest {
+                let msg =
+                    "cannot specify `default_from` for a `nest`ed / `flatten`ed configuration";
+                return Err(syn::Error::new(span, msg));
+            }
+            if default.is_some() {
+                let msg =
+                    "`default_from` cannot be specified together with `default` / `default_t`";
+                return Err(syn::Error::new(span, msg));
+            }
+        }
         if let (Some(fallback), true) = (&fallback, nest) {
             let msg = "cannot specify `fallback` for a `nest`ed / `flatten`ed configuration";
             return Err(syn::Error::new(fallback.span(), msg));
         }
+        let env_vars: Vec<_> = env.into_iter().chain(env_aliases).collect();
+        if let (Some(env_var), true) = (env_vars.first(), nest) {
+            let msg =
+                "cannot specify `env` / `env_alias` for a `nest`ed / `flatten`ed configuration";
+            return Err(syn::Error::new(env_var.span(), msg));
+        }
         if let (Some(filter), true) = (&deserialize_if, nest) {
             let msg = "cannot specify `filter` for a `nest`ed / `flatten`ed configuration";
             return Err(syn::Error::new(filter.expr.span(), msg));
@@ -264,6 +565,22 @@ impl ConfigFieldAttrs {
             return Err(syn::Error::new(deserialize_if.expr.span(), msg));
         }
 
+        let constraint_span = required_with
+            .first()
+            .map(Ident::span)
+            .or_else(|| required_if.first().map(|cond| cond.expr.span()))
+            .or_else(|| conflicts_with.first().map(Ident::span));
+        if let Some(span) = constraint_span {
+            if nest {
+                let msg = "param constraints cannot be specified for a `nest`ed / `flatten`ed configuration";
+                return Err(syn::Error::new(span, msg));
+            }
+            if !is_option {
+                let msg = "param constraints can only be specified for `Option`s";
+                return Err(syn::Error::new(span, msg));
+            }
+        }
+
         if let (Some(flatten_span), Some(_)) = (flatten_span, &rename) {
             let msg = "`rename` attribute is useless for flattened configs; did you mean to make a config nested?";
             return Err(syn::Error::new(flatten_span, msg));
@@ -276,6 +593,10 @@ impl ConfigFieldAttrs {
             let msg = "aliases for flattened configs are not supported yet; did you mean to make a config nested?";
             return Err(syn::Error::new(flatten_span, msg));
         }
+        if let (Some(flatten_span), Some(_)) = (flatten_span, &deprecation) {
+            let msg = "flattened configs cannot be deprecated; deprecate their params instead";
+            return Err(syn::Error::new(flatten_span, msg));
+        }
         if let (Some(secret_span), true) = (secret_span, nest) {
             let msg = "only params can be marked as secret, sub-configs cannot";
             return Err(syn::Error::new(secret_span, msg));
@@ -285,13 +606,19 @@ impl ConfigFieldAttrs {
             rename,
             aliases,
             default,
+            computed_default,
             example,
             fallback,
+            env_vars,
+            deprecation,
             flatten,
             nest,
             with,
             deserialize_if,
             validations,
+            required_with,
+            required_if,
+            conflicts_with,
             is_secret: secret_span.is_some(),
         })
     }
@@ -344,6 +671,7 @@ impl ConfigField {
     pub(crate) fn from_tag(
         cr: &proc_macro2::TokenStream,
         tag: &LitStr,
+        representation: TagRepresentation,
         variants: impl Iterator<Item = String>,
         default: Option<&str>,
     ) -> Self {
@@ -352,6 +680,8 @@ impl ConfigField {
         let with = syn::parse_quote! {
             #cr::de::_private::TagDeserializer::new(&[#(#variants,)*], #default_opt)
         };
+        // For non-internal tags, the default variant is applied by the deserializer since it depends on the present params.
+        let default = default.filter(|_| representation == TagRepresentation::Internal);
 
         Self {
             attrs: ConfigFieldAttrs {
@@ -483,8 +813,9 @@ impl RenameRule {
 pub(crate) struct ConfigContainerAttrs {
     pub(crate) cr: Option<Path>,
     pub(crate) rename_all: Option<RenameRule>,
-    pub(crate) tag: Option<LitStr>,
+    pub(crate) tag: Option<ConfigTagAttr>,
     pub(crate) validations: Vec<Validation>,
+    pub(crate) one_of_required: Vec<Vec<Ident>>,
     pub(crate) derive_default: bool,
 }
 
@@ -496,6 +827,7 @@ impl ConfigContainerAttrs {
         let mut rename_all = None;
         let mut tag = None;
         let mut validations = vec![];
+        let mut one_of_required = vec![];
         let mut derive_default = false;
         for attr in config_attrs {
             attr.parse_nested_meta(|meta| {
@@ -509,11 +841,34 @@ impl ConfigContainerAttrs {
                     rename_all = Some((rule, parsed));
                     Ok(())
                 } else if meta.path.is_ident("tag") {
-                    tag = Some(meta.value()?.parse::<LitStr>()?);
-                    Ok(())
+                    let value = meta.value()?;
+                    let new_tag = if value.peek(LitStr) {
+                        ConfigTagAttr::Param(value.parse()?)
+                    } else {
+                        let ident: Ident = value.parse()?;
+                        if ident != "external" {
+                            let msg = "Expected a string tag name or `external`";
+                            return Err(syn::Error::new(ident.span(), msg));
+                        }
+                        ConfigTagAttr::External(ident)
+                    };
+                    ConfigTagAttr::set(&mut tag, new_tag)
+                } else if meta.path.is_ident("untagged") {
+                    let ident = meta.path.get_ident().unwrap().clone();
+                    ConfigTagAttr::set(&mut tag, ConfigTagAttr::Untagged(ident))
                 } else if meta.path.is_ident("validate") {
                     validations.push(Validation::new(meta.input)?);
                     Ok(())
+                } else if meta.path.is_ident("one_of_required") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let fields = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                    if fields.len() < 2 {
+                        let msg = "`one_of_required` must list at least 2 fields";
+                        return Err(meta.error(msg));
+                    }
+                    one_of_required.push(fields.into_iter().collect());
+                    Ok(())
                 } else if meta.path.is_ident("derive") {
                     let content;
                     syn::parenthesized!(content in meta.input);
@@ -537,7 +892,7 @@ impl ConfigContainerAttrs {
                 return Err(syn::Error::new(rename_all.span(), msg));
             }
             if let Some(tag) = &tag {
-                let msg = "`tag` attribute must not be used on struct configs";
+                let msg = "`tag` / `untagged` attributes must not be used on struct configs";
                 return Err(syn::Error::new(tag.span(), msg));
             }
         }
@@ -547,11 +902,63 @@ impl ConfigContainerAttrs {
             rename_all: rename_all.map(|(_, parsed)| parsed),
             tag,
             validations,
+            one_of_required,
             derive_default,
         })
     }
 }
 
+/// Name of the tag param for enum configs without an explicitly named tag.
+const IMPLICIT_TAG_NAME: &str = "_variant";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TagRepresentation {
+    Internal,
+    External,
+    Untagged,
+}
+
+/// `tag` / `untagged` container attribute.
+#[derive(Debug)]
+pub(crate) enum ConfigTagAttr {
+    Param(LitStr),
+    External(Ident),
+    Untagged(Ident),
+}
+
+impl ConfigTagAttr {
+    fn set(dest: &mut Option<Self>, tag: Self) -> syn::Result<()> {
+        if dest.is_some() {
+            let msg = "Enum tag is already specified; `tag` and `untagged` attributes are mutually exclusive";
+            return Err(syn::Error::new(tag.span(), msg));
+        }
+        *dest = Some(tag);
+        Ok(())
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Self::Param(name) => name.span(),
+            Self::External(ident) | Self::Untagged(ident) => ident.span(),
+        }
+    }
+
+    /// Returns the tag param name together with the tag representation.
+    fn param_name(&self) -> (LitStr, TagRepresentation) {
+        match self {
+            Self::Param(name) => (name.clone(), TagRepresentation::Internal),
+            Self::External(ident) => (
+                LitStr::new(IMPLICIT_TAG_NAME, ident.span()),
+                TagRepresentation::External,
+            ),
+            Self::Untagged(ident) => (
+                LitStr::new(IMPLICIT_TAG_NAME, ident.span()),
+                TagRepresentation::Untagged,
+            ),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ConfigEnumVariant {
     pub(crate) attrs: ConfigVariantAttrs,
@@ -587,11 +994,21 @@ pub(crate) enum ConfigContainerFields {
     Struct(Vec<ConfigField>),
     Enum {
         tag: LitStr,
+        representation: TagRepresentation,
         variants: Vec<ConfigEnumVariant>,
     },
 }
 
 impl ConfigContainerFields {
+    /// Returns fields for the specified enum variant, or all fields for struct configs.
+    pub(crate) fn variant_fields(&self, variant_idx: Option<usize>) -> &[ConfigField] {
+        match (self, variant_idx) {
+            (Self::Struct(fields), _) => fields,
+            (Self::Enum { variants, .. }, Some(idx)) => &variants[idx].fields,
+            (Self::Enum { .. }, None) => &[],
+        }
+    }
+
     /// Returns the variant index together with each field. For struct configs, all indices are 0.
     pub(crate) fn all_fields(&self) -> Vec<(usize, &ConfigField)> {
         match self {
@@ -611,19 +1028,19 @@ impl ConfigContainerFields {
 pub(crate) struct ConfigContainer {
     pub(crate) attrs: ConfigContainerAttrs,
     pub(crate) name: Ident,
+    /// Generics with `'static` bounds added for all type params.
+    pub(crate) generics: Generics,
     pub(crate) help: String,
     pub(crate) fields: ConfigContainerFields,
 }
 
 impl ConfigContainer {
     pub(crate) fn new(raw: &DeriveInput) -> syn::Result<Self> {
-        if raw.generics.type_params().count() != 0
-            || raw.generics.const_params().count() != 0
-            || raw.generics.lifetimes().count() != 0
-        {
-            let message = "generics are not supported";
-            return Err(syn::Error::new_spanned(&raw.generics, message));
+        if let Some(lifetime) = raw.generics.lifetimes().next() {
+            let message = "lifetime generics are not supported; configs must be `'static`";
+            return Err(syn::Error::new_spanned(lifetime, message));
         }
+        let generics = Self::static_generics(&raw.generics);
 
         let attrs = ConfigContainerAttrs::new(&raw.attrs, matches!(&raw.data, Data::Struct(_)))?;
         let fields = match &raw.data {
@@ -635,18 +1052,166 @@ impl ConfigContainer {
             }
         };
 
+        Self::check_param_constraints(&attrs, &fields)?;
         let name = raw.ident.clone();
 
         Ok(Self {
             attrs,
             name,
+            generics,
             help: parse_docs(&raw.attrs),
             fields,
         })
     }
 
+    /// Adds `'static` bounds for all type params since configs are required to be `'static`.
+    fn static_generics(generics: &Generics) -> Generics {
+        let mut generics = generics.clone();
+        let type_params: Vec<_> = generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+        let where_clause = generics.make_where_clause();
+        for ident in type_params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#ident: 'static));
+        }
+        generics
+    }
+
+    /// Returns the type name including generic params, e.g. `PoolConfig<T>`.
+    pub(crate) fn name_with_generics(&self) -> String {
+        let params: Vec<_> = self
+            .generics
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(param) => Some(param.ident.to_string()),
+                GenericParam::Const(param) => Some(param.ident.to_string()),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+        if params.is_empty() {
+            self.name.to_string()
+        } else {
+            format!("{}<{}>", self.name, params.join(", "))
+        }
+    }
+
+    pub(crate) fn is_generic(&self) -> bool {
+        !self.generics.params.is_empty()
+    }
+
+    /// Checks that param constraints only reference optional params of the config.
+    fn check_param_constraints(
+        attrs: &ConfigContainerAttrs,
+        fields: &ConfigContainerFields,
+    ) -> syn::Result<()> {
+        let ConfigContainerFields::Struct(fields) = fields else {
+            let field_constraints = fields.all_fields().into_iter().find_map(|(_, field)| {
+                let attrs = &field.attrs;
+                attrs
+                    .required_with
+                    .first()
+                    .or_else(|| attrs.conflicts_with.first())
+                    .map(Ident::span)
+                    .or_else(|| attrs.required_if.first().map(|cond| cond.expr.span()))
+            });
+            let container_constraints = attrs.one_of_required.iter().flatten().next();
+            if let Some(span) = field_constraints.or_else(|| container_constraints.map(Ident::span))
+            {
+                let msg = "param constraints are not supported for enum configs yet";
+                return Err(syn::Error::new(span, msg));
+            }
+            return Ok(());
+        };
+
+        let field_refs = fields.iter().flat_map(|field| {
+            let refs = field.attrs.required_with.iter();
+            let refs = refs.chain(&field.attrs.conflicts_with);
+            refs.map(move |ident| (Some(field), ident))
+        });
+        let container_refs = attrs
+            .one_of_required
+            .iter()
+            .flatten()
+            .map(|ident| (None, ident));
+        for (source_field, ident) in field_refs.chain(container_refs) {
+            let referenced = fields
+                .iter()
+                .find(|field| matches!(&field.name, Member::Named(name) if name == ident));
+            let Some(referenced) = referenced else {
+                let msg = "referenced field is not defined in the config";
+                return Err(syn::Error::new(ident.span(), msg));
+            };
+            if source_field.is_some_and(|field| field.name == referenced.name) {
+                let msg = "param cannot reference itself in a constraint";
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+            if referenced.attrs.nest {
+                let msg = "referenced field must be a param, not a nested config";
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+            if !ConfigField::is_option(&referenced.ty) {
+                let msg = "referenced param must be an `Option`";
+                return Err(syn::Error::new(ident.span(), msg));
+            }
+        }
+        Ok(())
+    }
+
     fn extract_struct_fields(data: &DataStruct) -> syn::Result<Vec<ConfigField>> {
-        data.fields.iter().map(ConfigField::new).collect()
+        let mut fields = data
+            .fields
+            .iter()
+            .map(ConfigField::new)
+            .collect::<syn::Result<Vec<_>>>()?;
+        Self::resolve_computed_defaults(&mut fields)?;
+        Ok(fields)
+    }
+
+    /// Checks that computed defaults only reference params in the same struct / enum variant, and annotates
+    /// types of the dependencies.
+    fn resolve_computed_defaults(fields: &mut [ConfigField]) -> syn::Result<()> {
+        for (i, field) in fields.iter().enumerate() {
+            let Some(computed_default) = &field.attrs.computed_default else {
+                continue;
+            };
+            for ident in computed_default.dependencies() {
+                let referenced = fields
+                    .iter()
+                    .position(|field| matches!(&field.name, Member::Named(name) if name == ident));
+                let Some(referenced_idx) = referenced else {
+                    let msg = "referenced field is not defined in the config";
+                    return Err(syn::Error::new(ident.span(), msg));
+                };
+                let referenced = &fields[referenced_idx];
+                if referenced_idx == i {
+                    let msg = "param default cannot depend on the param itself";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                if referenced.attrs.nest {
+                    let msg = "referenced field must be a param, not a nested config";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+                if referenced.attrs.computed_default.is_some() && referenced_idx > i {
+                    let msg = "param with a computed default can only depend on such params declared before it";
+                    return Err(syn::Error::new(ident.span(), msg));
+                }
+            }
+        }
+
+        let fields_snapshot: Vec<_> = fields
+            .iter()
+            .map(|field| (field.name.clone(), field.ty.clone()))
+            .collect();
+        for field in fields {
+            if let Some(computed_default) = &mut field.attrs.computed_default {
+                computed_default.annotate_arg_types(&fields_snapshot);
+            }
+        }
+        Ok(())
     }
 
     fn extract_enum_fields(
@@ -710,6 +1275,7 @@ impl ConfigContainer {
                 }
                 Fields::Unit => { /* no fields to add */ }
             }
+            Self::resolve_computed_defaults(&mut variant_fields)?;
             variants.push(ConfigEnumVariant {
                 attrs,
                 name: variant.ident.clone(),
@@ -722,16 +1288,32 @@ impl ConfigContainer {
             let msg = "Cannot use an enum without fields as a config; this is useless";
             return Err(syn::Error::new_spanned(&data.variants, msg));
         }
-        let tag = container_attrs.tag.clone().ok_or_else(|| {
-            let msg = "Only tagged enums are supported as configs. Please add #[config(tag = ..)] to the enum";
+        let tag = container_attrs.tag.as_ref().ok_or_else(|| {
+            let msg = "Enum configs must specify their representation. Please add #[config(tag = ..)] \
+                or #[config(untagged)] to the enum";
             syn::Error::new_spanned(&data.variants, msg)
         })?;
+        let (tag, representation) = tag.param_name();
         if merged_fields_by_name.contains_key(&tag.value()) {
             let msg = "Tag name coincides with an existing param name";
             return Err(syn::Error::new(tag.span(), msg));
         }
+        if representation == TagRepresentation::Untagged {
+            let undetectable_variant = variants
+                .iter()
+                .find(|variant| variant.fields.is_empty() && !variant.attrs.default);
+            if let Some(variant) = undetectable_variant {
+                let msg = "Variants without fields cannot be detected in untagged enum configs; \
+                    consider marking the variant as #[config(default)]";
+                return Err(syn::Error::new(variant.name.span(), msg));
+            }
+        }
 
-        Ok(ConfigContainerFields::Enum { tag, variants })
+        Ok(ConfigContainerFields::Enum {
+            tag,
+            representation,
+            variants,
+        })
     }
 
     // Need to specify span as an input, since setting span to e.g. `self.name.span()` will lead to "stretched" error spans
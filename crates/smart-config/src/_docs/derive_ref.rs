@@ -12,6 +12,41 @@
 //! Each field in the struct / each enum variant is considered a configuration param (by default),
 //! or a sub-config (if `#[config(nest)]` or `#[config(flatten)]` is present for the field).
 //!
+//! # Generics
+//!
+//! Configs may have type and const generic params (but not lifetimes, since configs must be `'static`).
+//! Each monomorphization of a generic config gets its own [metadata](crate::metadata::ConfigMetadata), so e.g.
+//! `PoolConfig<SocketAddr, 16>` and `PoolConfig<String, 16>` are distinct configs from the [schema](crate::ConfigSchema) perspective.
+//! Correspondingly, the [config type name](crate::metadata::RustType::name_in_code()) includes concrete generic args.
+//! The macros copy generic params and bounds from the config declaration and add `'static` bounds for type params;
+//! all other bounds required by the generated code (e.g., [`WellKnown`](crate::de::WellKnown) for param types
+//! or [`DeserializeConfig`](crate::DeserializeConfig) for nested configs) must be specified explicitly.
+//!
+//! Compile-time checks for the config metadata (see [below](#validations)) are postponed for generic configs
+//! until the config is used.
+//!
+//! ```
+//! # use std::net::SocketAddr;
+//! # use smart_config::{de::WellKnown, testing, DescribeConfig, DeserializeConfig};
+//! #[derive(Debug, DescribeConfig, DeserializeConfig)]
+//! struct PoolConfig<Addr: WellKnown, const MAX_SIZE: u32> {
+//!     /// Address to connect to.
+//!     address: Addr,
+//!     /// Pool size.
+//!     #[config(default_t = MAX_SIZE, validate(1..=MAX_SIZE))]
+//!     size: u32,
+//! }
+//!
+//! let input = smart_config::config!("address": "127.0.0.1:5432");
+//! let config = testing::test::<PoolConfig<SocketAddr, 16>>(input).unwrap();
+//! assert_eq!(config.address.port(), 5_432);
+//! assert_eq!(config.size, 16);
+//!
+//! let input = smart_config::config!("address": "127.0.0.1:5432", "size": 32);
+//! let errors = testing::test::<PoolConfig<SocketAddr, 16>>(input).unwrap_err();
+//! assert_eq!(errors.first().path(), "size");
+//! ```
+//!
 //! # Container attributes
 //!
 //! ## `validate`
@@ -29,13 +64,67 @@
 //!
 //! Specifies a post-deserialization validation for the config. This is useful to check invariants involving multiple params.
 //! Multiple validations are supported by specifying the attribute multiple times.
+//! A validation can be made non-fatal by specifying its [severity](crate::validation::Severity) after the description,
+//! e.g. `validate(Self::check, "description", severity = warn)`.
+//! Invariants spanning multiple configs can be checked with [schema-level validations](crate::ConfigSchema::validate_configs()).
 //!
 //! ## `tag`
 //!
-//! **Type:** string
+//! **Type:** string or `external`
 //!
 //! Specifies the param name holding the enum tag, similar to the corresponding attribute in `serde`.
-//! Unlike `serde`, this attribute is *required* for enums; this is to ensure that source merging is well-defined.
+//! Unlike `serde`, either this attribute or [`untagged`](#untagged) is *required* for enums;
+//! this is to ensure that source merging is well-defined.
+//!
+//! `tag = external` specifies the externally tagged representation (the default one in `serde`): variant params are nested
+//! in an object under the variant key, e.g. `storage: { s3: { bucket: ... } }`. The variant key is the variant name
+//! (or one of its aliases) converted to `snake_case`; exactly one variant key must be present in the config object.
+//! Internally, the variant is still stored in a hidden tag param, so merging sources works the same as for internal tags:
+//! the variant from the last source specifying it wins. Unit variants are represented as empty objects (e.g., `memory: {}`).
+//! The [default variant](#default) is used only if the config object doesn't contain any variant params.
+//!
+//! ```
+//! # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+//! #[derive(Debug, DescribeConfig, DeserializeConfig)]
+//! #[config(tag = external)]
+//! enum StorageConfig {
+//!     #[config(default)]
+//!     Memory,
+//!     S3 { bucket: String },
+//! }
+//!
+//! let input = smart_config::config!("s3.bucket": "test");
+//! let config = testing::test::<StorageConfig>(input).unwrap();
+//! assert!(matches!(config, StorageConfig::S3 { bucket } if bucket == "test"));
+//! ```
+//!
+//! ## `untagged`
+//!
+//! Specifies that the enum variant is detected by the shape of the config object, i.e., by which variant-specific params
+//! are present in it. The variant is detected if it's the only variant having all present variant-specific params;
+//! otherwise, parsing fails with an error listing params for each variant. Variant detection is performed for each source
+//! separately, before merging sources. A source that doesn't allow to determine the variant unambiguously (e.g., one
+//! only containing params shared by several variants) doesn't contribute to the detection, so the variant must be
+//! determined by another source; if several sources determine the variant, the source with the highest priority wins.
+//! Variants without fields cannot be detected, so they must be marked as [default](#default).
+//!
+//! ```
+//! # use std::path::PathBuf;
+//! # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+//! #[derive(Debug, DescribeConfig, DeserializeConfig)]
+//! #[config(untagged)]
+//! enum ConnectionConfig {
+//!     Url { url: String },
+//!     Socket { path: PathBuf },
+//! }
+//!
+//! let input = smart_config::config!("path": "/var/run/app.sock");
+//! let config = testing::test::<ConnectionConfig>(input).unwrap();
+//! assert!(matches!(config, ConnectionConfig::Socket { .. }));
+//!
+//! let input = smart_config::config!("url": "https://example.com/", "path": "/var/run/app.sock");
+//! assert!(testing::test::<ConnectionConfig>(input).is_err());
+//! ```
 //!
 //! ## `rename_all`
 //!
@@ -57,6 +146,14 @@
 //!
 //! [clippy-acronyms]: https://rust-lang.github.io/rust-clippy/master/index.html#/upper_case_acronyms
 //!
+//! ## `one_of_required`
+//!
+//! **Type:** parenthesized list of field names, e.g. `one_of_required(url, path)`
+//!
+//! Requires at least one of the listed params to be set. All listed params must be `Option`s; a param is considered set
+//! if it is not `None` after deserialization. Can be specified multiple times for different groups of params.
+//! See also [param constraints](#required_with-required_if-conflicts_with). Not supported for enum configs yet.
+//!
 //! ## `derive(Default)`
 //!
 //! Derives `Default` according to the default values of params (+ the default variant for enum configs).
@@ -73,9 +170,18 @@
 //!
 //! ## `default`
 //!
-//! If specified, marks the variant as default – one which will be used if the tag param is not set in the input.
+//! If specified, marks the variant as default – one which will be used if the tag param is not set in the input
+//! (or, for [externally tagged and untagged](#tag) enums, if no variant params are set).
 //! At most one variant can be marked as default.
 //!
+//! ## `deprecated`
+//!
+//! **Type:** flag or list of deprecation details
+//!
+//! Marks the variant as deprecated. Has the same format and semantics as the corresponding field attribute (see below);
+//! usages of the variant (including via its aliases) are reported. Unlike fields, variants don't support
+//! deprecated aliases (`deprecated = "..."`).
+//!
 //! # Field attributes
 //!
 //! ## `rename`, `alias`
@@ -99,10 +205,41 @@
 //!
 //! ## `deprecated`
 //!
-//! **Type:** string
-//!
-//! Similar to `alias`, with the difference that the alias is marked as deprecated in the schema docs,
-//! and its usages are logged on the `WARN` level.
+//! **Type:** string, flag, or list of optional deprecation details: `note` (string), `since` and `remove_in` (version strings)
+//!
+//! With a string value (`deprecated = "..."`), the attribute is similar to `alias`, with the difference that the alias is marked
+//! as deprecated in the schema docs, and its usages are logged on the `WARN` level.
+//!
+//! Otherwise (`deprecated` or `deprecated(..)`), marks the param or the nested config itself as deprecated;
+//! flattened configs cannot be deprecated.
+//! The [deprecation info](crate::metadata::Deprecation) is rendered in the config help, and usages of the deprecated param / config
+//! are logged on the `WARN` level and reported as [warnings](crate::ParseErrors::warnings()). If the application version is set
+//! via [`DeserializerOptions::set_app_version()`](crate::de::DeserializerOptions::set_app_version()) and is greater or equal to `remove_in`,
+//! usages are reported as errors instead. Versions are [semantic versions](https://semver.org/) in which minor / patch components
+//! may be omitted (e.g., `1.0` or `0.9.3-rc.1`); they are [validated](#validations) in compile time.
+//!
+//! A deprecated alias and `deprecated(..)` can be specified for the same param.
+//!
+//! ```
+//! # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+//! #[derive(Debug, DescribeConfig, DeserializeConfig)]
+//! struct RateLimitConfig {
+//!     #[config(deprecated(note = "use `max_rps`", since = "0.9", remove_in = "1.0"))]
+//!     rps: Option<u32>,
+//!     max_rps: Option<u32>,
+//! }
+//!
+//! let mut tester = testing::Tester::<RateLimitConfig>::default();
+//! tester.set_app_version("0.9.3");
+//! let config = tester.test(smart_config::config!("rps": 100))?;
+//! assert_eq!(config.rps, Some(100));
+//!
+//! tester.set_app_version("1.0.0");
+//! let err = tester.test(smart_config::config!("rps": 100)).unwrap_err();
+//! let err = err.first().inner().to_string();
+//! assert!(err.contains("`rps` is deprecated since 0.9, removed in 1.0"), "{err}");
+//! # anyhow::Ok(())
+//! ```
 //!
 //! ## `default`
 //!
@@ -117,6 +254,37 @@
 //!
 //! Allows to specify the default typed value for the param. The provided expression doesn't need to be constant.
 //!
+//! ## `default_from`
+//!
+//! **Type:** closure with args named after other params of the config, optionally followed by a string description
+//!
+//! Computes the default value for the param from other params of the same config (or the same enum variant).
+//! The closure receives references to the deserialized dependency values; if any of them fails to deserialize,
+//! the default isn't computed. Param validations are checked on the computed value; errors are attributed to the synthetic value origin
+//! mentioning the dependencies.
+//! A param can depend on params with computed defaults declared before it.
+//!
+//! `default_from` cannot be combined with `default` / `default_t`, and cannot be used for nested / flattened configs.
+//! The [computed default](crate::metadata::ComputedDefault) is rendered in the config help using the provided description, or
+//! the list of dependencies if the description is not provided.
+//!
+//! ```
+//! # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+//! #[derive(Debug, DescribeConfig, DeserializeConfig)]
+//! struct ServerConfig {
+//!     #[config(default_t = 3000)]
+//!     port: u16,
+//!     #[config(default_from(|port| port + 1, "`port` + 1"))]
+//!     metrics_port: u16,
+//! }
+//!
+//! let config: ServerConfig = testing::test(smart_config::config!("port": 8080))?;
+//! assert_eq!(config.metrics_port, 8081);
+//! let config: ServerConfig = testing::test(smart_config::config!("metrics_port": 9000))?;
+//! assert_eq!((config.port, config.metrics_port), (3000, 9000));
+//! # anyhow::Ok(())
+//! ```
+//!
 //! ## `example`
 //!
 //! **Type:** expression with field type
@@ -131,6 +299,43 @@
 //! Allows to provide a fallback source for the param. See the [`fallback`](crate::fallback) module docs for the discussion of fallbacks
 //! and intended use cases.
 //!
+//! ## `env`, `env_alias`
+//!
+//! **Type:** string
+//!
+//! Binds the param to the exact env var name(s) regardless of the param location and the prefix of the [`Environment`](crate::Environment)
+//! source. `env` specifies the primary name and can be used at most once; `env_alias` specifies additional names and can be repeated.
+//! Names must consist of uppercase ASCII letters, digits and `_`, and cannot start with a digit; they are [validated](#validations) in compile time.
+//!
+//! Explicit env vars have the same priority as the `Environment` source they are read by, but are overridden by the conventional env var
+//! for the param in the same source (e.g., `APP_API_HTTP_PORT`); if multiple explicit vars are set, the first one in the declaration order wins.
+//! The names are listed in the [param metadata](crate::metadata::ParamMetadata::env_vars) and thus are rendered in the config help.
+//! Like the prefixed vars, explicit vars are read when the `Environment` is created; once it is inserted into a repository,
+//! only the vars with names declared in the schema are used.
+//!
+//! ```
+//! # use smart_config::{testing, DescribeConfig, DeserializeConfig, Environment};
+//! #[derive(DescribeConfig, DeserializeConfig)]
+//! struct ApiConfig {
+//!     #[config(env = "LEGACY_PORT", env_alias = "PORT")]
+//!     http_port: u16,
+//! }
+//!
+//! let mut tester = testing::Tester::default();
+//! tester.set_env("PORT", "3000");
+//! let config: ApiConfig = tester.test(Environment::prefixed("APP_"))?;
+//! assert_eq!(config.http_port, 3000);
+//!
+//! tester.set_env("LEGACY_PORT", "8080");
+//! let config: ApiConfig = tester.test(Environment::prefixed("APP_"))?;
+//! assert_eq!(config.http_port, 8080);
+//!
+//! tester.set_env("APP_HTTP_PORT", "8000");
+//! let config: ApiConfig = tester.test(Environment::prefixed("APP_"))?;
+//! assert_eq!(config.http_port, 8000);
+//! # anyhow::Ok(())
+//! ```
+//!
 //! ## `with`
 //!
 //! **Type:** const expression implementing [`DeserializeParam`]
@@ -162,6 +367,49 @@
 //! to `None` in the case [automated null coercion](crate::de::Optional#encoding-nulls) doesn't apply.
 //! See the [`validation`](crate::validation) module for examples of usage.
 //!
+//! ## `required_with`, `required_if`, `conflicts_with`
+//!
+//! **Type:**
+//!
+//! - `required_with`, `conflicts_with`: name of another `Option`al param field in the same config, e.g. `required_with = tls_cert`
+//! - `required_if`: pointer to a function with the `fn(&_) -> bool` signature taking the config,
+//!   and a human-readable condition description separated by a comma
+//!
+//! Constraints on the presence of an `Option`al param. A param is considered set if it is not `None` after deserialization.
+//!
+//! - `required_with` requires the param to be set if the other param is set.
+//! - `required_if` requires the param to be set if the condition function returns `true`.
+//! - `conflicts_with` forbids setting the param together with the other param.
+//!
+//! Each attribute can be specified multiple times. Constraints are checked after the config is deserialized;
+//! a violated constraint produces an error for each participating param, with the origin of the param value (or of the enclosing
+//! config if the param is not set). Constraints are listed in the [metadata](crate::metadata::ConfigMetadata::constraints)
+//! and thus are rendered in the config help. Not supported for enum configs yet.
+//!
+//! ```
+//! # use std::path::PathBuf;
+//! # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+//! #[derive(Debug, DescribeConfig, DeserializeConfig)]
+//! #[config(one_of_required(url, socket))]
+//! struct ServerConfig {
+//!     url: Option<String>,
+//!     #[config(conflicts_with = url)]
+//!     socket: Option<PathBuf>,
+//!     tls_cert: Option<PathBuf>,
+//!     #[config(required_with = tls_cert)]
+//!     tls_key: Option<PathBuf>,
+//! }
+//!
+//! let input = smart_config::config!("url": "https://example.com/", "tls_cert": "cert.pem");
+//! let errors = testing::test::<ServerConfig>(input).unwrap_err();
+//! let paths: Vec<_> = errors.iter().map(|err| err.path()).collect();
+//! assert_eq!(paths, ["tls_key", "tls_cert"]);
+//! assert_eq!(
+//!     errors.first().validation(),
+//!     Some("`tls_key` is required if `tls_cert` is set")
+//! );
+//! ```
+//!
 //! # Validations
 //!
 //! The following validations are performed by the macro in compile time:
@@ -169,5 +417,8 @@
 //! - Param / sub-config names and aliases must be non-empty, consist of lowercase ASCII alphanumeric chars or underscore
 //!   and not start with a digit (i.e., follow the `[a-z_][a-z0-9_]*` regex).
 //! - Param names / aliases cannot coincide with nested config names.
+//! - Explicit env var names must be non-empty, consist of uppercase ASCII alphanumeric chars or underscore
+//!   and not start with a digit (i.e., follow the `[A-Z_][A-Z0-9_]*` regex).
+//! - Deprecation versions must be semantic versions, in which minor / patch components may be omitted.
 //!
 //! [`DeserializeParam`]: crate::de::DeserializeParam
@@ -1,6 +1,6 @@
 //! Private functionality used by derive macros. Not part of the public API.
 
-use std::{any, fmt, marker::PhantomData, sync::Arc};
+use std::{any, fmt, marker::PhantomData, mem, sync::Arc};
 
 use serde::{Deserialize, de::Error as DeError};
 use serde_json::Value;
@@ -8,8 +8,12 @@ use serde_json::Value;
 use super::{DeserializeContext, DeserializeParam, deserializer::ValueDeserializer};
 use crate::{
     error::{ErrorWithOrigin, LowLevelError},
-    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    metadata::{
+        BasicTypes, ConfigMetadata, ConfigTag, ConfigVariant, ParamMetadata, TagRepresentation,
+        TypeDescription,
+    },
     validation::Validate,
+    value::{Pointer, ValueOrigin},
 };
 
 pub const fn extract_expected_types<T, De: DeserializeParam<T>>(_: &De) -> BasicTypes {
@@ -73,6 +77,56 @@ impl<T: 'static, De: DeserializeParam<T>> ErasedDeserializer for Erased<T, De> {
     }
 }
 
+/// Variant of [`Erased`] used in generic configs. A deserializer for a generic param type cannot be borrowed
+/// in a constant (it may have interior mutability), so it's instead re-created on each call.
+pub struct LazyErased<T, De> {
+    factory: fn() -> De,
+    _ty: PhantomData<fn(T)>,
+}
+
+impl<T, De: fmt::Debug> fmt::Debug for LazyErased<T, De> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("LazyErased")
+            .field(&(self.factory)())
+            .finish()
+    }
+}
+
+impl<T: 'static, De: DeserializeParam<T>> LazyErased<T, De> {
+    /// `witness` is necessary to infer the deserializer type; it is immediately forgotten.
+    pub const fn new(witness: De, factory: fn() -> De) -> Self {
+        mem::forget(witness);
+        Self {
+            factory,
+            _ty: PhantomData,
+        }
+    }
+}
+
+impl<T: 'static, De: DeserializeParam<T>> ErasedDeserializer for LazyErased<T, De> {
+    fn describe(&self, description: &mut TypeDescription) {
+        (self.factory)().describe(description);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Box<dyn any::Any>, ErrorWithOrigin> {
+        (self.factory)()
+            .deserialize_param(ctx, param)
+            .map(|val| Box::new(val) as _)
+    }
+
+    fn serialize_param(&self, param: &dyn any::Any) -> serde_json::Value {
+        let param: &T = param
+            .downcast_ref()
+            .expect("Internal error: incorrect param type provided for serialization");
+        (self.factory)().serialize_param(param)
+    }
+}
+
 /// Deserializer for enum tags.
 #[derive(Debug)]
 pub struct TagDeserializer {
@@ -92,6 +146,66 @@ impl TagDeserializer {
     }
 }
 
+impl TagDeserializer {
+    /// Returns the enum config object if the tag is not internal and the config object contains variant-specific params.
+    /// This means that the variant could not be detected, and the default variant must not be used.
+    fn undetected_variant_config(
+        ctx: &DeserializeContext<'_>,
+        tag: &ConfigTag,
+    ) -> Option<&'static ConfigMetadata> {
+        if tag.representation == TagRepresentation::Internal {
+            return None;
+        }
+        let config = ctx.current_config;
+        let config_path = ctx.path.rsplit_once('.').map_or("", |(parent, _)| parent);
+        let config_object = ctx
+            .root_value
+            .get(Pointer(config_path))?
+            .inner
+            .as_object()?;
+        let has_variant_params = tag.variants.iter().any(|variant| {
+            config
+                .variant_keys(variant)
+                .into_iter()
+                .any(|key| config_object.contains_key(key))
+        });
+        has_variant_params.then_some(config)
+    }
+
+    #[cold]
+    fn missing_variant_error(
+        config: &'static ConfigMetadata,
+        tag: &ConfigTag,
+        param: &'static ParamMetadata,
+    ) -> ErrorWithOrigin {
+        let message = match tag.representation {
+            TagRepresentation::External => {
+                let keys = tag.variants.iter().flat_map(ConfigVariant::external_keys);
+                let keys: Vec<_> = keys.map(|key| format!("`{key}`")).collect();
+                format!(
+                    "cannot determine enum variant; expected exactly one object-valued key out of {}",
+                    keys.join(", ")
+                )
+            }
+            TagRepresentation::Untagged => {
+                let variants = tag.variants.iter().map(|variant| {
+                    let keys = config.variant_keys(variant);
+                    let keys: Vec<_> = keys.into_iter().map(|key| format!("`{key}`")).collect();
+                    format!("'{}' ({})", variant.name, keys.join(", "))
+                });
+                let variants: Vec<_> = variants.collect();
+                format!(
+                    "cannot unambiguously determine enum variant from the present params; \
+                     expected params of one of variants: {}",
+                    variants.join(", ")
+                )
+            }
+            TagRepresentation::Internal => return DeError::missing_field(param.name),
+        };
+        DeError::custom(message)
+    }
+}
+
 impl DeserializeParam<&'static str> for TagDeserializer {
     const EXPECTING: BasicTypes = BasicTypes::STRING;
 
@@ -101,18 +215,25 @@ impl DeserializeParam<&'static str> for TagDeserializer {
 
     fn deserialize_param(
         &self,
-        ctx: DeserializeContext<'_>,
+        mut ctx: DeserializeContext<'_>,
         param: &'static ParamMetadata,
     ) -> Result<&'static str, ErrorWithOrigin> {
         let s = if let Some(current_value) = ctx.current_value() {
             String::deserialize(ValueDeserializer::new(current_value, ctx.de_options))?
+        } else if let Some(tag) = &ctx.current_config.tag
+            && let Some(config) = Self::undetected_variant_config(&ctx, tag)
+        {
+            return Err(Self::missing_variant_error(config, tag, param));
         } else if let Some(default) = self.default_value {
             return Ok(default);
+        } else if let Some(tag) = &ctx.current_config.tag {
+            return Err(Self::missing_variant_error(ctx.current_config, tag, param));
         } else {
             return Err(DeError::missing_field(param.name));
         };
 
-        self.expected
+        let variant_name = self
+            .expected
             .iter()
             .copied()
             .find(|&variant| variant == s)
@@ -123,7 +244,23 @@ impl DeserializeParam<&'static str> for TagDeserializer {
                     .map(|val| val.origin.clone())
                     .unwrap_or_default();
                 ErrorWithOrigin::json(err, origin)
+            })?;
+
+        let variant = ctx.current_config.tag.and_then(|tag| {
+            tag.variants.iter().find(|variant| {
+                variant.name == variant_name || variant.aliases.contains(&variant_name)
             })
+        });
+        if let Some(variant) = variant
+            && let Some(deprecation) = &variant.deprecation
+            && ctx.push_deprecation_error("enum variant", variant.name, deprecation)
+        {
+            return Err(ErrorWithOrigin::new(
+                LowLevelError::Validation,
+                Arc::default(),
+            ));
+        }
+        Ok(variant_name)
     }
 
     fn serialize_param(&self, &param: &&'static str) -> serde_json::Value {
@@ -157,14 +294,24 @@ fn validate<T>(
     value: &T,
     ctx: &mut DeserializeContext<'_>,
     validations: &[&'static dyn Validate<T>],
+) -> Result<(), ErrorWithOrigin> {
+    let origin = ctx
+        .current_value()
+        .map_or_else(Arc::default, |val| val.origin.clone());
+    validate_with_origin(value, &origin, ctx, validations)
+}
+
+fn validate_with_origin<T>(
+    value: &T,
+    origin: &Arc<ValueOrigin>,
+    ctx: &mut DeserializeContext<'_>,
+    validations: &[&'static dyn Validate<T>],
 ) -> Result<(), ErrorWithOrigin> {
     let mut has_errors = false;
     for &validation in validations {
         let _span = tracing::trace_span!("validation", %validation).entered();
-        if let Err(err) = validation.validate(value) {
-            tracing::warn!(%validation, %err, "validation failed");
-            ctx.push_generic_error(err, Some(validation.to_string()));
-            has_errors = true;
+        if let Err(err) = validation.validate_with_origin(value, origin) {
+            has_errors |= ctx.push_validation_error(err.set_origin_if_unset(origin), validation);
         }
     }
 
@@ -226,6 +373,71 @@ impl<T, De: DeserializeParam<Option<T>>> DeserializeParam<Option<T>> for Validat
     }
 }
 
+/// Validations for a param with a [computed default](crate::metadata::ComputedDefault). Unlike with [`Validated`],
+/// validations are run on the computed value directly, rather than on the deserialized param value.
+pub trait ValidateComputed<T> {
+    fn validate_computed(
+        &self,
+        value: &T,
+        origin: &Arc<ValueOrigin>,
+        ctx: &mut DeserializeContext<'_>,
+    ) -> Result<(), ErrorWithOrigin>;
+}
+
+/// No validations.
+impl<T> ValidateComputed<T> for () {
+    fn validate_computed(
+        &self,
+        _value: &T,
+        _origin: &Arc<ValueOrigin>,
+        _ctx: &mut DeserializeContext<'_>,
+    ) -> Result<(), ErrorWithOrigin> {
+        Ok(())
+    }
+}
+
+pub struct ComputedValidations<'a, T: 'static>(&'a [&'static dyn Validate<T>]);
+
+impl<T: 'static> fmt::Debug for ComputedValidations<'_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_tuple("ComputedValidations")
+            .field(&self.0)
+            .finish()
+    }
+}
+
+impl<'a, T> ComputedValidations<'a, T> {
+    pub const fn new(validations: &'a [&'static dyn Validate<T>]) -> Self {
+        Self(validations)
+    }
+}
+
+impl<T> ValidateComputed<T> for ComputedValidations<'_, T> {
+    fn validate_computed(
+        &self,
+        value: &T,
+        origin: &Arc<ValueOrigin>,
+        ctx: &mut DeserializeContext<'_>,
+    ) -> Result<(), ErrorWithOrigin> {
+        validate_with_origin(value, origin, ctx, self.0)
+    }
+}
+
+impl<T> ValidateComputed<Option<T>> for ComputedValidations<'_, T> {
+    fn validate_computed(
+        &self,
+        value: &Option<T>,
+        origin: &Arc<ValueOrigin>,
+        ctx: &mut DeserializeContext<'_>,
+    ) -> Result<(), ErrorWithOrigin> {
+        if let Some(value) = value {
+            validate_with_origin(value, origin, ctx, self.0)?;
+        }
+        Ok(())
+    }
+}
+
 pub struct DeserializeIf<T: 'static, De> {
     inner: De,
     condition: &'static dyn Validate<T>,
@@ -1,6 +1,11 @@
-use serde::{Serialize, de::DeserializeOwned};
+use alloy::primitives::Address;
+use serde::{
+    Serialize,
+    de::{DeserializeOwned, Error as DeError},
+};
 
 use crate::{
+    ChainId,
     de::{DeserializeContext, DeserializeParam, Qualified, Serde, WellKnown, WellKnownOption},
     error::ErrorWithOrigin,
     metadata::{BasicTypes, ParamMetadata, TypeDescription},
@@ -10,33 +15,128 @@ use crate::{
 const HASH_DE: Qualified<Serde![str]> =
     Qualified::new(Serde![str], "hex string with optional 0x prefix");
 
-macro_rules! impl_well_known_hash {
-    ($($ty:ident),+) => {
-        $(
-        /// Accepts a hex string with an optional `0x` prefix.
-        #[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
-        impl WellKnown for alloy::primitives::aliases::$ty {
-            type Deserializer = Qualified<Serde![str]>;
-            const DE: Self::Deserializer = HASH_DE;
-        }
-
-        #[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
-        impl WellKnownOption for alloy::primitives::aliases::$ty {}
-        )+
-    };
+/// Accepts a hex string with an optional `0x` prefix. This covers aliases like
+/// [`B256`](alloy::primitives::B256) and [`TxHash`](alloy::primitives::TxHash).
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+impl<const N: usize> WellKnown for alloy::primitives::FixedBytes<N> {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = HASH_DE;
 }
 
-impl_well_known_hash!(B64, B128, B160, B256, B512);
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+impl<const N: usize> WellKnownOption for alloy::primitives::FixedBytes<N> {}
 
 /// Accepts a hex string with an optional `0x` prefix.
 #[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
-impl WellKnown for alloy::primitives::Address {
+impl WellKnown for alloy::primitives::Bytes {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer =
+        Qualified::new(Serde![str], "hex-encoded bytes with optional 0x prefix");
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+impl WellKnownOption for alloy::primitives::Bytes {}
+
+/// Accepts an integer, a decimal string, or a well-known chain name like `mainnet`.
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+impl WellKnown for ChainId {
+    type Deserializer = Qualified<Serde![int, str]>;
+    const DE: Self::Deserializer = Qualified::new(
+        Serde![int, str],
+        "chain ID or well-known chain name, like 'mainnet' or 'sepolia'",
+    );
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+impl WellKnownOption for ChainId {}
+
+/// Accepts a hex string with an optional `0x` prefix. The [EIP-55] checksum is not checked;
+/// use [`ChecksummedAddress`] to enforce it.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+impl WellKnown for Address {
     type Deserializer = Qualified<Serde![str]>;
     const DE: Self::Deserializer = HASH_DE;
 }
 
 #[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
-impl WellKnownOption for alloy::primitives::Address {}
+impl WellKnownOption for Address {}
+
+/// Deserializer for [`Address`]es enforcing the [EIP-55] mixed-case checksum. The address must be `0x`-prefixed;
+/// all-lowercase or all-uppercase addresses are rejected since they don't contain a checksum.
+/// The address is serialized with the checksum as well.
+///
+/// This deserializer is opt-in; by default, addresses are deserialized without checking the checksum.
+/// It can be applied to `Option<Address>` params as well.
+///
+/// [EIP-55]: https://eips.ethereum.org/EIPS/eip-55
+///
+/// # Examples
+///
+/// ```
+/// # use alloy::primitives::{address, Address};
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+/// use smart_config::de::ChecksummedAddress;
+///
+/// #[derive(Debug, DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = ChecksummedAddress)]
+///     operator: Address,
+/// }
+///
+/// let source = smart_config::config!("operator": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+/// let config: TestConfig = testing::test(source)?;
+/// assert_eq!(config.operator, address!("5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"));
+///
+/// // Mistyped case is detected
+/// let source = smart_config::config!("operator": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD");
+/// let err = testing::test::<TestConfig>(source).unwrap_err();
+/// let err = err.first().inner().to_string();
+/// assert!(err.contains("invalid EIP-55 checksum"), "{err}");
+/// # anyhow::Ok(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+#[derive(Debug, Clone, Copy)]
+pub struct ChecksummedAddress;
+
+impl DeserializeParam<Address> for ChecksummedAddress {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("0x-prefixed hex address with EIP-55 checksum");
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Address, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("0x-prefixed hex address"));
+        };
+        let s = s.expose();
+        if !s.starts_with("0x") {
+            return Err(deserializer.invalid_type("0x-prefixed hex address"));
+        }
+        let address: Address = s
+            .parse()
+            .map_err(|err| deserializer.enrich_err(DeError::custom(err)))?;
+        let checksummed = address.to_checksum(None);
+        if s != checksummed {
+            let err = DeError::custom(format!(
+                "invalid EIP-55 checksum for address {s}; expected {checksummed}"
+            ));
+            return Err(deserializer.enrich_err(err));
+        }
+        Ok(address)
+    }
+
+    fn serialize_param(&self, param: &Address) -> serde_json::Value {
+        param.to_checksum(None).into()
+    }
+}
 
 /// Hex deserializer enforcing a `0x` prefix. This prefix is not required by `U*` deserializers,
 /// but the value may be ambiguous otherwise (e.g., `34` being equal to 0x34, not decimal 34).
@@ -89,16 +189,20 @@ macro_rules! impl_well_known_uint {
 
 impl_well_known_uint!(U8, U16, U32, U64, U128, U160, U256, U512);
 
+// Allows using 256-bit integers in `TokenAmount`s.
+crate::types::impl_token_int!(alloy::primitives::U256);
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
 
-    use alloy::primitives::{Address, B256, U128, U160, U256};
+    use alloy::primitives::{Address, B256, Bytes, FixedBytes, TxHash, U128, U160, U256};
     use smart_config_derive::{DescribeConfig, DeserializeConfig};
 
     use crate::{
-        config,
+        ChainId, Environment, TokenAmount, config,
         testing::{test, test_complete},
+        testonly::test_config_roundtrip,
     };
 
     #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
@@ -155,4 +259,165 @@ mod tests {
             "{inner}"
         );
     }
+
+    #[test]
+    fn deserializing_u256_token_amount() {
+        #[derive(Debug, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct TokenConfig {
+            #[config(with = crate::de::TokenSymbol("tkn"))]
+            amount: TokenAmount<36, U256>,
+        }
+
+        // Amounts with more significant digits than supported by `Decimal` are parsed exactly
+        let json = config!("amount": "1_000_000_000_000_000_000_000.000_000_000_000_000_000_000_000_000_000_005 tkn");
+        let config = test::<TokenConfig>(json).unwrap();
+        assert_eq!(
+            config.amount,
+            TokenAmount(U256::from(10).pow(U256::from(57)) + U256::from(5_000))
+        );
+        assert_eq!(
+            config.amount.to_string(),
+            "1000000000000000000000.000000000000000000000000000000005"
+        );
+
+        let json = config!("amount": "1e23");
+        let config = test::<TokenConfig>(json).unwrap();
+        assert_eq!(
+            config.amount,
+            TokenAmount(U256::from(10).pow(U256::from(59)))
+        );
+        let json = config!("amount": "1.000_000_000_000_000_005e23 tkn");
+        let config = test::<TokenConfig>(json).unwrap();
+        assert_eq!(
+            config.amount,
+            TokenAmount(
+                U256::from(10).pow(U256::from(59))
+                    + U256::from(5) * U256::from(10).pow(U256::from(41))
+            )
+        );
+        assert_eq!(config.amount.to_string(), "100000000000000000500000");
+
+        let json = config!("amount": "1e42");
+        let err = test::<TokenConfig>(json).unwrap_err();
+        let err = err.first().inner().to_string();
+        assert!(err.contains("does not fit"), "{err}");
+    }
+
+    #[test]
+    fn deserializing_bytes_and_fixed_bytes() {
+        #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct BytesConfig {
+            calldata: Bytes,
+            selector: FixedBytes<4>,
+            tx_hash: Option<TxHash>,
+        }
+
+        let json = config!(
+            "calldata": "0x0102ff",
+            "selector": "a9059cbb",
+            "tx_hash": "0x1111111111111111111111111111111111111111111111111111111111111111",
+        );
+        let config = test_complete::<BytesConfig>(json).unwrap();
+        assert_eq!(config.calldata, Bytes::from_static(&[1, 2, 0xff]));
+        assert_eq!(config.selector, FixedBytes([0xa9, 0x05, 0x9c, 0xbb]));
+        assert_eq!(config.tx_hash, Some(TxHash::repeat_byte(0x11)));
+
+        let json = config!("calldata": "0x", "selector": "0x01");
+        let err = test::<BytesConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.first().path(), "selector");
+    }
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct ChecksumConfig {
+        #[config(with = super::ChecksummedAddress)]
+        address: Address,
+        #[config(default, with = super::ChecksummedAddress)]
+        other_address: Option<Address>,
+    }
+
+    #[test]
+    fn deserializing_checksummed_address() {
+        const CHECKSUMMED: &str = "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed";
+        let expected: Address = CHECKSUMMED.parse().unwrap();
+
+        let json = config!("address": CHECKSUMMED);
+        let config = test::<ChecksumConfig>(json).unwrap();
+        assert_eq!(config.address, expected);
+        assert_eq!(config.other_address, None);
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["address"], CHECKSUMMED);
+
+        let json = config!("address": CHECKSUMMED, "other_address": CHECKSUMMED);
+        let config = test_complete::<ChecksumConfig>(json).unwrap();
+        assert_eq!(config.other_address, Some(expected));
+    }
+
+    #[test]
+    fn checksummed_address_errors() {
+        let invalid_addresses = [
+            // Lowercase / uppercase addresses don't contain a checksum
+            "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed",
+            "0x5AAEB6053F3E94C9B9A09F33669435E7EF1BEAED",
+            // Single mistyped char
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD",
+        ];
+        for address in invalid_addresses {
+            let json = config!("address": address);
+            let err = test::<ChecksumConfig>(json).unwrap_err();
+            let err = err.first().inner().to_string();
+            assert!(err.contains("invalid EIP-55 checksum"), "{err}");
+            assert!(
+                err.contains("expected 0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+                "{err}"
+            );
+        }
+
+        let json = config!("address": "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed");
+        let err = test::<ChecksumConfig>(json).unwrap_err();
+        let err = err.first().inner().to_string();
+        assert!(err.contains("0x-prefixed hex address"), "{err}");
+
+        let json = config!("address": "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeA");
+        let err = test::<ChecksumConfig>(json).unwrap_err();
+        let err = err.first().inner().to_string();
+        assert!(!err.contains("checksum"), "{err}");
+    }
+
+    #[test]
+    fn deserializing_chain_ids() {
+        #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct ChainConfig {
+            chain_id: ChainId,
+            #[config(default)]
+            settlement_chain_id: Option<ChainId>,
+        }
+
+        let json = config!("chain_id": 324, "settlement_chain_id": "Sepolia");
+        let config: ChainConfig = test(json).unwrap();
+        assert_eq!(config.chain_id, ChainId(324));
+        assert_eq!(config.settlement_chain_id, Some(ChainId::SEPOLIA));
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["settlement_chain_id"], 11_155_111);
+
+        let env = Environment::from_iter("", [("CHAIN_ID", "mainnet")]);
+        let config: ChainConfig = test(env).unwrap();
+        assert_eq!(config.chain_id, ChainId::MAINNET);
+        let env = Environment::from_iter("", [("CHAIN_ID", "270")]);
+        let config: ChainConfig = test(env).unwrap();
+        assert_eq!(config.chain_id, ChainId(270));
+
+        let json = config!("chain_id": "mainnet2");
+        let err = test::<ChainConfig>(json).unwrap_err();
+        let err = err.first().inner().to_string();
+        assert!(err.contains("well-known chain name"), "{err}");
+        let json = config!("chain_id": -1);
+        let err = test::<ChainConfig>(json).unwrap_err();
+        let err = err.first().inner().to_string();
+        assert!(err.contains("invalid value"), "{err}");
+    }
 }
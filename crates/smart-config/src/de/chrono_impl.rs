@@ -0,0 +1,297 @@
+use chrono::{DateTime, FixedOffset, NaiveTime, Utc};
+use serde::{Deserialize, de::Error as DeError};
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam, Qualified, Serde, WellKnown, WellKnownOption},
+    error::ErrorWithOrigin,
+    metadata::{BasicTypes, ParamMetadata, TimeUnit, TypeDescription},
+    value::Value,
+};
+
+const TIMESTAMP_DE: Qualified<Serde![str]> = Qualified::new(
+    Serde![str],
+    "RFC 3339 timestamp, e.g. '2025-01-01T12:00:00Z'",
+);
+
+/// Accepts an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) timestamp, e.g. `2025-01-01T12:00:00Z`
+/// or `2025-01-01T15:00:00+03:00`. The timestamp is converted to UTC and serialized in the RFC 3339 format.
+///
+/// To deserialize a Unix timestamp instead, use [`TimeUnit`] as the deserializer.
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnown for DateTime<Utc> {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = TIMESTAMP_DE;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnownOption for DateTime<Utc> {}
+
+/// Accepts an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) timestamp, e.g. `2025-01-01T15:00:00+03:00`.
+/// Unlike with `DateTime<Utc>`, the UTC offset is retained.
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnown for DateTime<FixedOffset> {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = TIMESTAMP_DE;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnownOption for DateTime<FixedOffset> {}
+
+/// Accepts a time of day with optional seconds and fractional seconds, e.g. `14:30`, `14:30:00` or `14:30:00.250`.
+/// Serialized with seconds (and fractional seconds if they are non-zero).
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnown for NaiveTime {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = Qualified::new(Serde![str], "time of day, e.g. '14:30:00'");
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnownOption for NaiveTime {}
+
+/// Accepts a UTC offset, e.g. `+03:00`, `-05:30` or `Z` (for UTC). Serialized in the `+03:00` format.
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnown for FixedOffset {
+    type Deserializer = OffsetDeserializer;
+    const DE: Self::Deserializer = OffsetDeserializer;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl WellKnownOption for FixedOffset {}
+
+/// Deserializer for [`FixedOffset`]s, which don't implement `serde` traits.
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+#[derive(Debug)]
+pub struct OffsetDeserializer;
+
+impl DeserializeParam<FixedOffset> for OffsetDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("UTC offset, e.g. '+03:00' or 'Z'");
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<FixedOffset, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("UTC offset"));
+        };
+        let s = s.expose();
+        if s == "Z" || s == "z" {
+            return Ok(FixedOffset::east_opt(0).unwrap());
+        }
+        s.parse()
+            .map_err(|err| deserializer.enrich_err(DeError::custom(err)))
+    }
+
+    fn serialize_param(&self, param: &FixedOffset) -> serde_json::Value {
+        param.to_string().into()
+    }
+}
+
+/// Supports deserializing a [`DateTime`] from a Unix timestamp, with `self` being the unit of measurement
+/// (e.g., [`TimeUnit::Seconds`] or [`TimeUnit::Millis`]). Negative timestamps are supported.
+///
+/// # Examples
+///
+/// ```
+/// # use chrono::{DateTime, Utc};
+/// # use smart_config::{metadata::TimeUnit, testing, DescribeConfig, DeserializeConfig};
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = TimeUnit::Seconds)]
+///     genesis_timestamp: DateTime<Utc>,
+/// }
+///
+/// let source = smart_config::config!("genesis_timestamp": 1_735_732_800);
+/// let config = testing::test::<TestConfig>(source)?;
+/// assert_eq!(config.genesis_timestamp.to_rfc3339(), "2025-01-01T12:00:00+00:00");
+/// # anyhow::Ok(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl DeserializeParam<DateTime<Utc>> for TimeUnit {
+    const EXPECTING: BasicTypes = BasicTypes::INTEGER;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("Unix timestamp")
+            .set_unit((*self).into());
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<DateTime<Utc>, ErrorWithOrigin> {
+        const NANOS_IN_SEC: i128 = 1_000_000_000;
+
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let timestamp = i64::deserialize(deserializer)?;
+        let nanos = self.timestamp_to_nanos(timestamp);
+        let secs = i64::try_from(nanos.div_euclid(NANOS_IN_SEC)).ok();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        // Cannot happen since the value is in 0..10^9
+        let subsec_nanos = nanos.rem_euclid(NANOS_IN_SEC) as u32;
+        secs.and_then(|secs| DateTime::from_timestamp(secs, subsec_nanos))
+            .ok_or_else(|| {
+                let plural = self.plural();
+                let err =
+                    DeError::custom(format!("timestamp {timestamp} {plural} is out of range"));
+                deserializer.enrich_err(err)
+            })
+    }
+
+    fn serialize_param(&self, param: &DateTime<Utc>) -> serde_json::Value {
+        let nanos = i128::from(param.timestamp()) * 1_000_000_000
+            + i128::from(param.timestamp_subsec_nanos());
+        self.timestamp_from_nanos(nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, FixedOffset, NaiveTime, TimeZone, Utc};
+
+    use crate::{
+        DescribeConfig, DeserializeConfig, Environment, config,
+        metadata::{TimeUnit, UnitOfMeasurement},
+        testing::test,
+        testonly::test_config_roundtrip,
+    };
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        start: DateTime<Utc>,
+        #[config(default)]
+        local_start: Option<DateTime<FixedOffset>>,
+        #[config(default_t = NaiveTime::MIN)]
+        daily_at: NaiveTime,
+        #[config(default_t = FixedOffset::east_opt(0).unwrap())]
+        offset: FixedOffset,
+        #[config(default, with = TimeUnit::Seconds)]
+        genesis: DateTime<Utc>,
+        #[config(default, with = TimeUnit::Millis)]
+        genesis_ms: DateTime<Utc>,
+    }
+
+    #[test]
+    fn describing_timestamps() {
+        let params = TestConfig::DESCRIPTION.params;
+        let start = params.iter().find(|param| param.name == "start").unwrap();
+        let description = start.type_description();
+        assert_eq!(
+            description.details().unwrap(),
+            "RFC 3339 timestamp, e.g. '2025-01-01T12:00:00Z'"
+        );
+
+        let genesis = params.iter().find(|param| param.name == "genesis").unwrap();
+        let description = genesis.type_description();
+        assert_eq!(description.details().unwrap(), "Unix timestamp");
+        assert_eq!(
+            description.unit(),
+            Some(UnitOfMeasurement::Time(TimeUnit::Seconds))
+        );
+    }
+
+    #[test]
+    fn deserializing_date_and_time() {
+        let json = config!(
+            "start": "2025-01-01T15:00:00+03:00",
+            "local_start": "2025-01-01T15:00:00+03:00",
+            "daily_at": "14:30",
+            "offset": "-05:30",
+            "genesis": 1_735_732_800,
+            "genesis_ms": -1_500,
+        );
+        let config = test::<TestConfig>(json).unwrap();
+        let expected_start = Utc.with_ymd_and_hms(2025, 1, 1, 12, 0, 0).unwrap();
+        assert_eq!(config.start, expected_start);
+        let local_start = config.local_start.unwrap();
+        assert_eq!(local_start, expected_start);
+        assert_eq!(local_start.offset().local_minus_utc(), 3 * 3_600);
+        assert_eq!(config.daily_at, NaiveTime::from_hms_opt(14, 30, 0).unwrap());
+        assert_eq!(config.offset.local_minus_utc(), -(5 * 3_600 + 30 * 60));
+        assert_eq!(config.genesis, expected_start);
+        assert_eq!(
+            config.genesis_ms,
+            DateTime::from_timestamp(-2, 500_000_000).unwrap()
+        );
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["start"], "2025-01-01T12:00:00Z");
+        assert_eq!(json["local_start"], "2025-01-01T15:00:00+03:00");
+        assert_eq!(json["daily_at"], "14:30:00");
+        assert_eq!(json["offset"], "-05:30");
+        assert_eq!(json["genesis"], 1_735_732_800);
+        assert_eq!(json["genesis_ms"], -1_500);
+    }
+
+    #[test]
+    fn deserializing_date_and_time_from_env() {
+        let env = Environment::from_iter(
+            "APP_",
+            [
+                ("APP_START", "2025-01-01T12:00:00Z"),
+                ("APP_DAILY_AT", "03:15:30.250"),
+                ("APP_OFFSET", "Z"),
+                ("APP_GENESIS", "1735732800"),
+            ],
+        );
+        let config = test::<TestConfig>(env).unwrap();
+        assert_eq!(config.start, config.genesis);
+        assert_eq!(
+            config.daily_at,
+            NaiveTime::from_hms_milli_opt(3, 15, 30, 250).unwrap()
+        );
+        assert_eq!(config.offset, FixedOffset::east_opt(0).unwrap());
+    }
+
+    #[test]
+    fn date_and_time_errors() {
+        let json = config!("start": "2025-01-01");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.first().path(), "start");
+
+        let json = config!("start": "2025-01-01T12:00:00Z", "offset": "+25:00");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "offset");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("out of range"), "{inner}");
+
+        let json = config!("start": "2025-01-01T12:00:00Z", "genesis": i64::MAX);
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "genesis");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("seconds is out of range"), "{inner}");
+    }
+
+    #[test]
+    fn serializing_timestamp_not_fitting_into_i64() {
+        #[derive(Debug, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct NanosConfig {
+            #[config(with = TimeUnit::Nanos)]
+            timestamp: DateTime<Utc>,
+        }
+
+        let config = NanosConfig {
+            timestamp: Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap(),
+        };
+        let json = crate::SerializerOptions::default().serialize(&config);
+        let serialized = json["timestamp"].as_str().unwrap();
+        assert_eq!(serialized, "32503680000000000000");
+
+        let err = test::<NanosConfig>(config!("timestamp": serialized)).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.first().path(), "timestamp");
+    }
+}
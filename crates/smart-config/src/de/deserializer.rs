@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+use anyhow::Context as _;
 use serde::{
     Deserialize, Deserializer,
     de::{
@@ -12,15 +13,34 @@ use serde::{
 
 use crate::{
     error::ErrorWithOrigin,
-    utils::EnumVariant,
+    utils::{EnumVariant, parse_version},
     value::{Map, StrValue, Value, ValueOrigin, WithOrigin},
 };
 
 /// Available deserialization options.
 #[derive(Debug, Clone, Default)]
+#[non_exhaustive]
 pub struct DeserializerOptions {
     /// Enables coercion of variant names between cases, e.g. from `SHOUTING_CASE` to `shouting_case`.
     pub coerce_variant_names: bool,
+    pub(crate) app_version: Option<semver::Version>,
+}
+
+impl DeserializerOptions {
+    /// Sets the application version, e.g. `1.0.3`. If set, usages of [deprecated](crate::metadata::Deprecation) params,
+    /// nested configs and enum variants removed in this version (or earlier) are reported as errors rather than warnings.
+    ///
+    /// The version is parsed as described in [`Deprecation::is_removed_in()`](crate::metadata::Deprecation::is_removed_in()).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version cannot be parsed.
+    pub fn set_app_version(&mut self, version: &str) -> anyhow::Result<&mut Self> {
+        let version =
+            parse_version(version).with_context(|| format!("invalid app version `{version}`"))?;
+        self.app_version = Some(version);
+        Ok(self)
+    }
 }
 
 impl WithOrigin {
@@ -0,0 +1,200 @@
+//! Deserialization of host–port pairs.
+
+use crate::{
+    HostPort,
+    de::{DeserializeContext, DeserializeParam, Qualified, Serde, WellKnown, WellKnownOption},
+    error::ErrorWithOrigin,
+    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    value::Value,
+};
+
+impl WellKnown for HostPort {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = Qualified::new(
+        Serde![str],
+        "host and port, e.g. 'db.internal:5432' or '[::1]:8080'",
+    );
+}
+
+impl WellKnownOption for HostPort {}
+
+/// Deserializer for [`HostPort`]s that falls back to the specified port if the input doesn't contain one.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+/// use smart_config::{de::DefaultPort, HostPort};
+///
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct DbConfig {
+///     #[config(with = DefaultPort(5432))]
+///     server: HostPort,
+///     #[config(with = DefaultPort(5432))]
+///     replica: Option<HostPort>,
+/// }
+///
+/// let input = smart_config::config!(
+///     "server": "db.internal",
+///     "replica": "[fd00::2]:5433",
+/// );
+/// let config: DbConfig = testing::test(input)?;
+/// assert_eq!(config.server.to_string(), "db.internal:5432");
+/// assert_eq!(config.replica.unwrap().port(), 5433);
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultPort(pub u16);
+
+impl DeserializeParam<HostPort> for DefaultPort {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        let details = format!(
+            "host with optional port (default: {}), e.g. 'db.internal' or '[::1]:8080'",
+            self.0
+        );
+        description.set_details(details);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<HostPort, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("host with optional port"));
+        };
+        HostPort::parse_with_default_port(s.expose(), self.0)
+            .map_err(|err| deserializer.enrich_err(err))
+    }
+
+    fn serialize_param(&self, param: &HostPort) -> serde_json::Value {
+        param.to_string().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr, ToSocketAddrs};
+
+    use super::*;
+    use crate::{
+        DescribeConfig, DeserializeConfig, config, testing::test, testonly::test_config_roundtrip,
+    };
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct ConfigWithAddrs {
+        addr: HostPort,
+        #[config(with = DefaultPort(5432))]
+        db: HostPort,
+        #[config(with = DefaultPort(5432))]
+        replica: Option<HostPort>,
+    }
+
+    #[test]
+    fn parsing_host_port() {
+        let addr: HostPort = "db.internal:5432".parse().unwrap();
+        assert_eq!(addr.host(), "db.internal");
+        assert_eq!(addr.port(), 5432);
+        assert_eq!(addr.ip(), None);
+
+        let addr: HostPort = "127.0.0.1:80".parse().unwrap();
+        assert_eq!(addr.ip(), Some(Ipv4Addr::LOCALHOST.into()));
+        let socket_addrs: Vec<_> = addr.to_socket_addrs().unwrap().collect();
+        assert_eq!(socket_addrs, [SocketAddr::from(([127, 0, 0, 1], 80))]);
+
+        let addr: HostPort = "[::1]:8080".parse().unwrap();
+        assert_eq!(addr.host(), "::1");
+        assert_eq!(addr.to_string(), "[::1]:8080");
+        assert_eq!(
+            addr,
+            HostPort::from(SocketAddr::from(([0, 0, 0, 0, 0, 0, 0, 1], 8080)))
+        );
+        assert_eq!(addr, HostPort::new("[::1]", 8080).unwrap());
+
+        let addr = HostPort::parse_with_default_port("::1", 8080).unwrap();
+        assert_eq!(addr.to_string(), "[::1]:8080");
+        let addr = HostPort::parse_with_default_port("example.com.", 443).unwrap();
+        assert_eq!(addr.to_string(), "example.com.:443");
+    }
+
+    #[test]
+    fn host_port_parsing_errors() {
+        let err = "db.internal".parse::<HostPort>().unwrap_err().to_string();
+        assert!(err.contains("address without port"), "{err}");
+        let err = "db.internal:99999"
+            .parse::<HostPort>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid port '99999'"), "{err}");
+        let err = "fd00:1:2:3:4:5:6:7:8080"
+            .parse::<HostPort>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("enclosed in brackets"), "{err}");
+        let err = "[::1:8080".parse::<HostPort>().unwrap_err().to_string();
+        assert!(err.contains("unterminated"), "{err}");
+        let err = "[db.internal]:80"
+            .parse::<HostPort>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid IPv6 address"), "{err}");
+        let err = "-db.internal:80"
+            .parse::<HostPort>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid DNS name"), "{err}");
+        let err = "db..internal:80"
+            .parse::<HostPort>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("invalid DNS name"), "{err}");
+        let err = ":80".parse::<HostPort>().unwrap_err().to_string();
+        assert!(err.contains("host is empty"), "{err}");
+    }
+
+    #[test]
+    fn deserializing_host_ports() {
+        let config: ConfigWithAddrs = test(config!(
+            "addr": "localhost:3000",
+            "db": "db.internal",
+        ))
+        .unwrap();
+        assert_eq!(config.addr.to_string(), "localhost:3000");
+        assert_eq!(config.db.to_string(), "db.internal:5432");
+        assert_eq!(config.replica, None);
+
+        let config: ConfigWithAddrs = test(config!(
+            "addr": "[fd00::1]:3000",
+            "db": "10.0.0.1:6432",
+            "replica": "db-replica.internal",
+        ))
+        .unwrap();
+        assert_eq!(config.db.port(), 6432);
+        assert_eq!(config.replica.as_ref().unwrap().port(), 5432);
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["addr"], "[fd00::1]:3000");
+        assert_eq!(json["replica"], "db-replica.internal:5432");
+    }
+
+    #[test]
+    fn host_port_deserialization_errors() {
+        let err =
+            test::<ConfigWithAddrs>(config!("addr": "localhost", "db": "db:5432")).unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "addr");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("address without port"), "{inner}");
+
+        let err =
+            test::<ConfigWithAddrs>(config!("addr": "localhost:80", "db": "db:port")).unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "db");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("invalid port 'port'"), "{inner}");
+    }
+}
@@ -0,0 +1,76 @@
+use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+
+use crate::de::{Qualified, Serde, WellKnown, WellKnownOption};
+
+macro_rules! impl_well_known_net {
+    ($($ty:ident => $details:tt,)+) => {
+        $(
+        /// Accepts an IP network in the CIDR notation, e.g. `10.0.0.0/8` or `fd00::/8`.
+        #[cfg_attr(docsrs, doc(cfg(feature = "ipnet")))]
+        impl WellKnown for $ty {
+            type Deserializer = Qualified<Serde![str]>;
+            const DE: Self::Deserializer = Qualified::new(Serde![str], $details);
+        }
+
+        #[cfg_attr(docsrs, doc(cfg(feature = "ipnet")))]
+        impl WellKnownOption for $ty {}
+        )+
+    };
+}
+
+impl_well_known_net!(
+    IpNet => "IP network in CIDR notation, e.g. '10.0.0.0/8'",
+    Ipv4Net => "IPv4 network in CIDR notation, e.g. '10.0.0.0/8'",
+    Ipv6Net => "IPv6 network in CIDR notation, e.g. 'fd00::/8'",
+);
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use ipnet::{IpNet, Ipv4Net, Ipv6Net};
+    use smart_config_derive::{DescribeConfig, DeserializeConfig};
+
+    use crate::{config, testing::test, testonly::test_config_roundtrip};
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        #[config(default)]
+        allowed: Vec<IpNet>,
+        #[config(default)]
+        internal: Option<Ipv4Net>,
+        #[config(default)]
+        internal_v6: Option<Ipv6Net>,
+    }
+
+    #[test]
+    fn deserializing_networks() {
+        let json = config!(
+            "allowed": ["10.0.0.0/8", "fd00::/8"],
+            "internal": "192.168.1.0/24",
+            "internal_v6": "2001:db8::/32",
+        );
+        let config = test::<TestConfig>(json).unwrap();
+        assert_eq!(config.allowed.len(), 2);
+        assert!(config.allowed[0].contains(&"10.1.2.3".parse::<IpAddr>().unwrap()));
+        assert!(config.allowed[1].contains(&"fd12::1".parse::<IpAddr>().unwrap()));
+        let internal = config.internal.unwrap();
+        assert_eq!(internal.network(), Ipv4Addr::new(192, 168, 1, 0));
+        assert_eq!(internal.prefix_len(), 24);
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["internal"], "192.168.1.0/24");
+    }
+
+    #[test]
+    fn network_errors() {
+        let json = config!("internal": "fd00::/8");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "internal");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("invalid IP address syntax"), "{inner}");
+    }
+}
@@ -36,6 +36,17 @@
 //! - Alternatively, [`TimeUnit`](crate::metadata::TimeUnit) and [`SizeUnit`](crate::metadata::SizeUnit) can be used
 //!   on `Duration`s and `ByteSize`s, respectively.
 //!
+//! ## Token amounts
+//!
+//! [`TokenAmount`](crate::TokenAmount)s are deserialized from a number or a decimal string like "1.5" or "1.5 usdc"
+//! without precision loss. By default, token symbols are rejected; [`TokenSymbol`] accepts the specified symbol.
+//!
+//! ## Paths
+//!
+//! By default, [`PathBuf`](std::path::PathBuf)s are deserialized as-is, i.e., relative paths are resolved
+//! against the current working directory of the process. [`ResolvedPath`] resolves relative paths
+//! against the directory of the config file the path is read from, and expands `~` to the home directory.
+//!
 //! ## Secrets
 //!
 //! A param is secret iff it uses a [`Secret`] deserializer (perhaps, with decorators on top, like
@@ -46,29 +57,50 @@
 //! they do not get accidentally exposed via debug logs etc. See [`ConfigRepository`](crate::ConfigRepository)
 //! for details.
 //!
+//! Some param types are only *partially* secret; e.g., a [`SensitiveUrl`](crate::SensitiveUrl) may contain a password,
+//! but its host should remain visible in debug output. Such types mark their [`TypeDescription`](crate::metadata::TypeDescription)
+//! with a [redaction function](crate::metadata::TypeDescription::set_redaction()) instead of marking it as secret.
+//!
 //! [`Duration`]: std::time::Duration
 //! [`ByteSize`]: crate::ByteSize
 
-use std::any;
+use std::{any, sync::Arc};
 
 use serde::de::Error as DeError;
 
-use self::deserializer::ValueDeserializer;
+#[cfg(feature = "alloy")]
+pub use self::alloy_impl::ChecksummedAddress;
+#[cfg(feature = "chrono")]
+pub use self::chrono_impl::OffsetDeserializer;
+#[cfg(feature = "time")]
+pub use self::time_impl::TimeDeserializer;
+use self::{_private::ValidateComputed, deserializer::ValueDeserializer};
 pub use self::{
     deserializer::DeserializerOptions,
+    host_port::DefaultPort,
     macros::Serde,
     param::{
-        CustomKnownOption, DeserializeParam, Optional, OrString, Qualified, Serde, WellKnown,
-        WellKnownOption, WithDefault,
+        CustomKnownOption, DecimalLimits, DeserializeParam, Optional, OrString, Qualified, Serde,
+        SmartPtr, WellKnown, WellKnownOption, WithDefault,
+    },
+    path::ResolvedPath,
+    range::InclusiveRange,
+    repeated::{
+        Delimited, DelimitedEntries, Entries, NamedEntries, OrderedEntries, Repeated, ToEntries,
+        Tuple,
     },
-    repeated::{Delimited, DelimitedEntries, Entries, NamedEntries, Repeated, ToEntries},
     secret::{FromSecretString, Secret},
-    units::WithUnit,
+    units::{TokenSymbol, WithUnit},
 };
 use crate::{
     DescribeConfig, DeserializeConfigError, ParseError, ParseErrorCategory, ParseErrors,
+    decrypt::DecryptionFailure,
     error::{ErrorWithOrigin, LocationInConfig, LowLevelError},
-    metadata::{BasicTypes, ConfigMetadata, ParamMetadata},
+    metadata::{
+        BasicTypes, ConfigMetadata, Deprecation, NestedConfigMetadata, ParamConstraint,
+        ParamMetadata, TagRepresentation,
+    },
+    validation::{Severity, Validate},
     value::{Pointer, StrValue, Value, ValueOrigin, WithOrigin},
 };
 
@@ -76,16 +108,36 @@ use crate::{
 pub mod _private;
 #[cfg(feature = "alloy")]
 mod alloy_impl;
+#[cfg(feature = "chrono")]
+mod chrono_impl;
 mod deserializer;
+mod host_port;
+#[cfg(feature = "ipnet")]
+mod ipnet_impl;
 mod macros;
 mod param;
+mod path;
 #[cfg(feature = "primitive-types")]
 mod primitive_types_impl;
+mod range;
+mod regex_impl;
 mod repeated;
 mod secret;
+#[cfg(feature = "semver")]
+mod semver_impl;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "time")]
+mod time_impl;
+#[cfg(feature = "tls")]
+mod tls_impl;
+#[cfg(feature = "tracing-subscriber")]
+mod tracing_impl;
 mod units;
+#[cfg(feature = "url")]
+mod url_impl;
+#[cfg(feature = "uuid")]
+mod uuid_impl;
 
 /// Context for deserializing a configuration.
 #[derive(Debug)]
@@ -96,6 +148,7 @@ pub struct DeserializeContext<'a> {
     patched_current_value: Option<&'a WithOrigin>,
     current_config: &'static ConfigMetadata,
     location_in_config: Option<LocationInConfig>,
+    decryption_failures: &'a [DecryptionFailure],
     errors: &'a mut ParseErrors,
 }
 
@@ -114,10 +167,16 @@ impl<'a> DeserializeContext<'a> {
             patched_current_value: None,
             current_config,
             location_in_config: None,
+            decryption_failures: &[],
             errors,
         }
     }
 
+    pub(crate) fn with_decryption_failures(mut self, failures: &'a [DecryptionFailure]) -> Self {
+        self.decryption_failures = failures;
+        self
+    }
+
     fn child(
         &mut self,
         path: &str,
@@ -138,6 +197,7 @@ impl<'a> DeserializeContext<'a> {
             }),
             current_config: self.current_config,
             location_in_config,
+            decryption_failures: self.decryption_failures,
             errors: self.errors,
         }
     }
@@ -151,6 +211,7 @@ impl<'a> DeserializeContext<'a> {
             patched_current_value: self.patched_current_value,
             current_config: self.current_config,
             location_in_config: self.location_in_config,
+            decryption_failures: self.decryption_failures,
             errors: self.errors,
         }
     }
@@ -164,6 +225,7 @@ impl<'a> DeserializeContext<'a> {
             patched_current_value: Some(current_value),
             current_config: self.current_config,
             location_in_config: self.location_in_config,
+            decryption_failures: self.decryption_failures,
             errors: self.errors,
         }
     }
@@ -190,7 +252,10 @@ impl<'a> DeserializeContext<'a> {
     }
 
     /// Returns context for a nested configuration.
-    fn for_nested_config(&mut self, index: usize) -> DeserializeContext<'_> {
+    fn for_nested_config(
+        &mut self,
+        index: usize,
+    ) -> (DeserializeContext<'_>, &'static NestedConfigMetadata) {
         let nested_meta = self
             .current_config
             .nested_configs
@@ -201,10 +266,11 @@ impl<'a> DeserializeContext<'a> {
                 )
             });
         let path = nested_meta.name;
-        DeserializeContext {
+        let ctx = DeserializeContext {
             current_config: nested_meta.meta,
             ..self.child(path, None)
-        }
+        };
+        (ctx, nested_meta)
     }
 
     fn for_param(&mut self, index: usize) -> (DeserializeContext<'_>, &'static ParamMetadata) {
@@ -219,11 +285,113 @@ impl<'a> DeserializeContext<'a> {
 
     /// Pushes a deserialization error into the context.
     pub fn push_error(&mut self, err: ErrorWithOrigin) {
-        self.push_generic_error(err, None);
+        self.push_generic_error(err, None, Severity::Deny);
+    }
+
+    /// Reports a usage of a deprecated item with the specified human-readable `kind` (e.g., "param") at the current location.
+    /// Returns `true` if the usage is fatal, i.e. the item is removed in the current app version.
+    #[cold]
+    pub(crate) fn push_deprecation_error(
+        &mut self,
+        kind: &str,
+        name: &str,
+        deprecation: &Deprecation,
+    ) -> bool {
+        let app_version = self.de_options.app_version.as_ref();
+        let is_removed = app_version.is_some_and(|version| {
+            deprecation
+                .is_removed_in_version(version)
+                .unwrap_or_else(|err| {
+                    tracing::warn!(%err, "cannot check whether {kind} `{name}` is removed");
+                    false
+                })
+        });
+        let severity = if is_removed {
+            Severity::Deny
+        } else {
+            Severity::Warn
+        };
+        let origin = self
+            .current_value()
+            .map(|val| val.origin.clone())
+            .unwrap_or_default();
+        tracing::warn!(path = self.path, origin = %origin, %severity, "using {kind} `{name}`, which is {deprecation}");
+
+        let message = if is_removed {
+            // `unwrap()` is safe: removal is only possible if the app version is set
+            let app_version = app_version.unwrap();
+            format!("{kind} `{name}` is {deprecation} (current app version: {app_version})")
+        } else {
+            format!("{kind} `{name}` is {deprecation}")
+        };
+        let err = ErrorWithOrigin {
+            inner: LowLevelError::Json {
+                err: DeError::custom(message),
+                category: ParseErrorCategory::Deprecated,
+            },
+            origin,
+        };
+        self.push_generic_error(err, None, severity);
+        is_removed
+    }
+
+    /// Pushes an error produced by a failed `validation`. Returns `true` if the error is fatal.
+    #[cold]
+    fn push_validation_error<T: ?Sized + 'static>(
+        &mut self,
+        err: ErrorWithOrigin,
+        validation: &dyn Validate<T>,
+    ) -> bool {
+        let severity = validation.severity();
+        if matches!(severity, Severity::Info) {
+            tracing::info!(%validation, origin = %err.origin, "validation failed: {}", err.inner);
+        } else {
+            tracing::warn!(%validation, %severity, origin = %err.origin, "validation failed: {}", err.inner);
+        }
+        self.push_generic_error(err, Some(validation.to_string()), severity);
+        severity.is_fatal()
+    }
+
+    /// Pushes errors for a violated param `constraint`, one per participating param.
+    #[cold]
+    fn push_constraint_errors(&mut self, constraint: &ParamConstraint) {
+        tracing::warn!(%constraint, "param constraint violated");
+        let message = constraint.violation();
+        let description = constraint.to_string();
+        // Missing params are attributed to the enclosing config object.
+        let config_origin = self
+            .current_value()
+            .map(|val| val.origin.clone())
+            .unwrap_or_default();
+
+        for param in constraint.params() {
+            let index = self
+                .current_config
+                .params
+                .iter()
+                .position(|candidate| candidate.name == param.name)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Internal error: constraint references unknown param `{}`",
+                        param.name
+                    )
+                });
+            let (mut child_ctx, _) = self.for_param(index);
+            let origin = child_ctx
+                .current_value()
+                .map_or_else(|| config_origin.clone(), |val| val.origin.clone());
+            let err = ErrorWithOrigin::json(DeError::custom(&message), origin);
+            child_ctx.push_generic_error(err, Some(description.clone()), Severity::Deny);
+        }
     }
 
     #[cold]
-    fn push_generic_error(&mut self, err: ErrorWithOrigin, validation: Option<String>) {
+    fn push_generic_error(
+        &mut self,
+        err: ErrorWithOrigin,
+        validation: Option<String>,
+        severity: Severity,
+    ) {
         let (inner, category) = match err.inner {
             LowLevelError::Json { err, category } => (err, category),
             LowLevelError::InvalidArray
@@ -238,17 +406,33 @@ impl<'a> DeserializeContext<'a> {
             origin = val.origin.clone();
         }
 
+        let (path, location_in_config) = self.error_location();
         self.errors.push(ParseError {
             inner,
             category,
-            path: self.path.clone(),
+            path: path.to_owned(),
             origin,
             config: self.current_config,
-            location_in_config: self.location_in_config,
+            location_in_config,
             validation,
+            severity,
         });
     }
 
+    /// Returns the path and location to attribute errors to. Errors for the implicit tag of an externally tagged
+    /// or untagged enum are attributed to the enum config since the tag param isn't visible to users.
+    fn error_location(&self) -> (&str, Option<LocationInConfig>) {
+        if let Some(LocationInConfig::Param(index)) = self.location_in_config
+            && let Some(tag) = &self.current_config.tag
+            && tag.representation != TagRepresentation::Internal
+            && tag.param.name == self.current_config.params[index].name
+        {
+            let config_path = self.path.rsplit_once('.').map_or("", |(parent, _)| parent);
+            return (config_path, None);
+        }
+        (&self.path, self.location_in_config)
+    }
+
     #[tracing::instrument(
         level = "trace",
         skip_all,
@@ -270,12 +454,19 @@ impl<'a> DeserializeContext<'a> {
         let config = (self.current_config.deserializer)(self.borrow())?;
 
         let mut has_errors = false;
+        for constraint in self.current_config.constraints {
+            if !(constraint.is_satisfied)(config.as_ref()) {
+                self.push_constraint_errors(constraint);
+                has_errors = true;
+            }
+        }
+        let origin = self
+            .current_value()
+            .map_or_else(Arc::default, |val| val.origin.clone());
         for &validation in self.current_config.validations {
             let _span = tracing::trace_span!("validation", %validation).entered();
-            if let Err(err) = validation.validate(config.as_ref()) {
-                tracing::info!(%validation, origin = %err.origin, "config validation failed: {}", err.inner);
-                self.push_generic_error(err, Some(validation.to_string()));
-                has_errors = true;
+            if let Err(err) = validation.validate_with_origin(config.as_ref(), &origin) {
+                has_errors |= self.push_validation_error(err, validation);
             }
         }
 
@@ -343,12 +534,15 @@ impl DeserializeContext<'_> {
         index: usize,
         default_fn: Option<fn() -> C>,
     ) -> Result<C, DeserializeConfigError> {
-        let child_ctx = self.for_nested_config(index);
+        let (mut child_ctx, nested_meta) = self.for_nested_config(index);
         if child_ctx.current_value().is_none()
             && let Some(default) = default_fn
         {
             return Ok(default());
         }
+        if child_ctx.check_nested_config_deprecation(nested_meta) {
+            return Err(DeserializeConfigError::new());
+        }
         child_ctx.deserialize_config()
     }
 
@@ -356,7 +550,22 @@ impl DeserializeContext<'_> {
         &mut self,
         index: usize,
     ) -> Result<Option<C>, DeserializeConfigError> {
-        self.for_nested_config(index).deserialize_config_opt()
+        let (mut child_ctx, nested_meta) = self.for_nested_config(index);
+        if child_ctx.check_nested_config_deprecation(nested_meta) {
+            return Err(DeserializeConfigError::new());
+        }
+        child_ctx.deserialize_config_opt()
+    }
+
+    /// Returns `true` if the deprecated config is used and this usage is fatal.
+    fn check_nested_config_deprecation(&mut self, nested_meta: &NestedConfigMetadata) -> bool {
+        let Some(deprecation) = &nested_meta.deprecation else {
+            return false;
+        };
+        let is_used = self
+            .current_value()
+            .is_some_and(|val| !matches!(&val.inner, Value::Object(obj) if obj.is_empty()));
+        is_used && self.push_deprecation_error("config", nested_meta.name, deprecation)
     }
 
     #[tracing::instrument(
@@ -372,6 +581,23 @@ impl DeserializeContext<'_> {
         let (mut child_ctx, param) = self.for_param(index);
         tracing::Span::current().record("param", param.rust_field_name);
 
+        if let Some(failure) = child_ctx
+            .current_value()
+            .and_then(|val| val.find_decryption_failure(child_ctx.decryption_failures))
+        {
+            let err =
+                ErrorWithOrigin::json(DeError::custom(&failure.message), failure.origin.clone());
+            child_ctx.push_error(err);
+            return Err(DeserializeConfigError::new());
+        }
+
+        if let Some(deprecation) = &param.deprecation
+            && child_ctx.current_value().is_some()
+            && child_ctx.push_deprecation_error("param", param.name, deprecation)
+        {
+            return Err(DeserializeConfigError::new());
+        }
+
         // Coerce value to the expected type.
         let maybe_coerced = child_ctx
             .current_value()
@@ -409,6 +635,47 @@ impl DeserializeContext<'_> {
                 .expect("Internal error: deserializer output has wrong type")
         })
     }
+
+    /// Deserializes a param with a [computed default](crate::metadata::ComputedDefault). If the param value is missing,
+    /// the default is computed and checked against the param `validations`; errors are attributed to a synthetic origin.
+    /// `compute` returns `None` if any of the param dependencies has failed to deserialize.
+    pub fn deserialize_param_or_compute<T: 'static>(
+        &mut self,
+        index: usize,
+        validations: &impl ValidateComputed<T>,
+        compute: impl FnOnce() -> Option<T>,
+    ) -> Result<T, DeserializeConfigError> {
+        let (child_ctx, param) = self.for_param(index);
+        if child_ctx.current_value().is_some() {
+            return self.deserialize_param(index);
+        }
+        // If a dependency has failed to deserialize, the corresponding error is already reported.
+        let computed = compute().ok_or_else(DeserializeConfigError::new)?;
+
+        let dependencies = param
+            .computed_default
+            .map_or(&[][..], |computed| computed.dependencies);
+        let source = dependencies
+            .iter()
+            .find_map(|&dep| {
+                let dep_path = Pointer(&self.path).join(dep);
+                Some(self.root_value.get(Pointer(&dep_path))?.origin.clone())
+            })
+            .unwrap_or_default();
+        let dependencies: Vec<_> = dependencies.iter().map(|dep| format!("`{dep}`")).collect();
+        let origin = Arc::new(ValueOrigin::Synthetic {
+            source,
+            transform: format!("default computed from {}", dependencies.join(", ")),
+        });
+        tracing::trace!(%origin, "computed default value for param");
+
+        let (mut child_ctx, _) = self.for_param(index);
+        match validations.validate_computed(&computed, &origin, &mut child_ctx) {
+            Ok(()) => Ok(computed),
+            // Validation errors are already reported.
+            Err(_) => Err(DeserializeConfigError::new()),
+        }
+    }
 }
 
 impl WithOrigin {
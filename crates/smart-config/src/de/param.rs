@@ -1,7 +1,9 @@
 //! Parameter deserializers.
 
 use std::{
-    any, fmt,
+    any,
+    borrow::Cow,
+    fmt,
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
     num::{
         NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
@@ -9,6 +11,7 @@ use std::{
     },
     path::PathBuf,
     str::FromStr,
+    sync::Arc,
 };
 
 use serde::{
@@ -17,6 +20,7 @@ use serde::{
 };
 
 use crate::{
+    Decimal,
     de::{DeserializeContext, deserializer::ValueDeserializer},
     error::ErrorWithOrigin,
     metadata::{BasicTypes, ParamMetadata, TypeDescription},
@@ -33,8 +37,14 @@ use crate::{
 /// - [`TimeUnit`](crate::metadata::TimeUnit) deserializes [`Duration`](std::time::Duration)
 ///   from a numeric value that has the specified unit of measurement
 /// - [`SizeUnit`](crate::metadata::SizeUnit) similarly deserializes [`ByteSize`](crate::ByteSize)
+/// - [`TimeUnit`](crate::metadata::TimeUnit), [`BandwidthUnit`](crate::metadata::BandwidthUnit) and
+///   [`RatioUnit`](crate::metadata::RatioUnit) similarly deserialize [`Rate`](crate::Rate),
+///   [`Bandwidth`](crate::Bandwidth) and [`Ratio`](crate::Ratio) respectively
 /// - [`WithUnit`](super::WithUnit) deserializes `Duration`s / `ByteSize`s as an integer + unit of measurement
 ///   (either in a string or object form).
+/// - [`DecimalLimits`](super::DecimalLimits) deserializes [`Decimal`](crate::Decimal)s enforcing precision and scale limits.
+/// - [`ResolvedPath`](super::ResolvedPath) deserializes [`PathBuf`]s resolving relative paths against
+///   the directory of the containing config file.
 ///
 /// ## Decorators
 ///
@@ -80,7 +90,8 @@ pub trait DeserializeParam<T>: fmt::Debug + Send + Sync + 'static {
 /// Basic well-known types include:
 ///
 /// - `bool`
-/// - [`String`]
+/// - [`String`], `Box<str>`, `Arc<str>`, `Cow<'static, str>`
+/// - `char`
 /// - [`PathBuf`]
 /// - Signed and unsigned integers, including non-zero variants
 /// - `f32`, `f64`
@@ -98,11 +109,25 @@ pub trait DeserializeParam<T>: fmt::Debug + Send + Sync + 'static {
 /// |:-----------|:-------------|:----------------|
 /// | [`Duration`](std::time::Duration) | [`WithUnit`](super::WithUnit) | string or object |
 /// | [`ByteSize`](crate::ByteSize) | [`WithUnit`](super::WithUnit) | string or object |
+/// | [`Rate`](crate::Rate), [`Bandwidth`](crate::Bandwidth) | [`WithUnit`](super::WithUnit) | string or object |
+/// | [`Ratio`](crate::Ratio) | [`WithUnit`](super::WithUnit) | number, string or object |
+/// | [`TokenAmount`](crate::TokenAmount) | [`WithUnit`](super::WithUnit) | number or string |
+/// | [`Decimal`](crate::Decimal) | [`Serde`] | number or string |
+/// | [`HostPort`](crate::HostPort) | [`Serde`] | string |
+/// | [`RangeInclusive`](std::ops::RangeInclusive) | [`InclusiveRange`](super::InclusiveRange) | string or object |
 /// | [`Option`] | [`Optional`]† | value, or `null`, or nothing |
 /// | [`Vec`], `[_; N]`, [`HashSet`](std::collections::HashSet), [`BTreeSet`](std::collections::BTreeSet) | [`Repeated`](super::Repeated) | array |
 /// | [`HashMap`](std::collections::HashMap), [`BTreeMap`](std::collections::BTreeSet) | [`RepeatedEntries`](super::Entries) | object |
+/// | `IndexSet`, `IndexMap` (with the `indexmap` feature) | [`Repeated`](super::Repeated), [`OrderedEntries`](super::OrderedEntries) | array |
+/// | Tuples with 2 to 4 elements | [`Tuple`](super::Tuple) | array |
+/// | [`Box`], [`Arc`] | [`SmartPtr`] | depends on the pointee type |
 ///
 /// † `Option`s handling can be customized via [`WellKnownOption`] or [`CustomKnownOption`] traits.
+///
+/// [`Regex`](crate::pat::Regex) is well-known as well; it is deserialized from a string and compiled
+/// during deserialization, so that an invalid regex is reported together with its origin.
+/// [`SensitiveUrl`](crate::SensitiveUrl) is deserialized from a string as well; its secret parts
+/// are redacted in the debug output.
 #[diagnostic::on_unimplemented(
     message = "`{Self}` param cannot be deserialized",
     note = "Add #[config(with = _)] attribute to specify deserializer to use",
@@ -302,6 +327,34 @@ impl WellKnown for String {
 
 impl WellKnownOption for String {}
 
+impl WellKnown for Box<str> {
+    type Deserializer = super::Serde![str];
+    const DE: Self::Deserializer = super::Serde![str];
+}
+
+impl WellKnownOption for Box<str> {}
+
+impl WellKnown for Arc<str> {
+    type Deserializer = SmartPtr<super::Serde![str]>;
+    const DE: Self::Deserializer = SmartPtr(super::Serde![str]);
+}
+
+impl WellKnownOption for Arc<str> {}
+
+impl WellKnown for Cow<'static, str> {
+    type Deserializer = super::Serde![str];
+    const DE: Self::Deserializer = super::Serde![str];
+}
+
+impl WellKnownOption for Cow<'static, str> {}
+
+impl WellKnown for char {
+    type Deserializer = Qualified<super::Serde![str]>;
+    const DE: Self::Deserializer = Qualified::new(super::Serde![str], "single character");
+}
+
+impl WellKnownOption for char {}
+
 impl WellKnown for PathBuf {
     type Deserializer = Qualified<super::Serde![str]>;
     const DE: Self::Deserializer = Qualified::new(super::Serde![str], "filesystem path");
@@ -365,6 +418,104 @@ impl WellKnown for f64 {
 
 impl WellKnownOption for f64 {}
 
+impl WellKnown for Decimal {
+    type Deserializer = Qualified<super::Serde![float, str]>;
+    const DE: Self::Deserializer = Qualified::new(
+        super::Serde![float, str],
+        "non-negative decimal number, e.g. 1.5 or 2.5e-9",
+    );
+}
+
+impl WellKnownOption for Decimal {}
+
+/// Deserializer for [`Decimal`]s that enforces limits on the [precision](Decimal::precision())
+/// (the number of significant digits) and the [scale](Decimal::scale()) (the number of digits after the decimal point).
+/// Values exceeding the limits are rejected rather than rounded.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig, Decimal};
+/// use smart_config::de::DecimalLimits;
+///
+/// #[derive(Debug, DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = DecimalLimits::new(6, 2))]
+///     price: Decimal,
+/// }
+///
+/// let source = smart_config::config!("price": "1234.5");
+/// let config: TestConfig = testing::test(source)?;
+/// assert_eq!(config.price, Decimal::new(12_345, -1));
+///
+/// let source = smart_config::config!("price": "0.125");
+/// let err = testing::test::<TestConfig>(source).unwrap_err();
+/// let err = err.first().inner().to_string();
+/// assert!(err.contains("scale 3 that exceeds the limit 2"), "{err}");
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DecimalLimits {
+    precision: u32,
+    scale: u16,
+}
+
+impl DecimalLimits {
+    /// Creates limits with the specified max precision and scale.
+    pub const fn new(precision: u32, scale: u16) -> Self {
+        Self { precision, scale }
+    }
+
+    fn check(self, value: Decimal) -> Result<(), serde_json::Error> {
+        if value.precision() > self.precision {
+            return Err(DeError::custom(format_args!(
+                "decimal {value:#} has precision {} that exceeds the limit {}",
+                value.precision(),
+                self.precision
+            )));
+        }
+        if value.scale() > self.scale {
+            return Err(DeError::custom(format_args!(
+                "decimal {value:#} has scale {} that exceeds the limit {}",
+                value.scale(),
+                self.scale
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl DeserializeParam<Decimal> for DecimalLimits {
+    const EXPECTING: BasicTypes =
+        <<Decimal as WellKnown>::Deserializer as DeserializeParam<Decimal>>::EXPECTING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details(format!(
+            "non-negative decimal number with at most {} significant digits and at most {} digits after the decimal point",
+            self.precision, self.scale
+        ));
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Decimal, ErrorWithOrigin> {
+        let origin = ctx
+            .current_value()
+            .map(|value| value.origin.clone())
+            .unwrap_or_default();
+        let value = Decimal::DE.deserialize_param(ctx, param)?;
+        self.check(value)
+            .map_err(|err| ErrorWithOrigin::json(err, origin))?;
+        Ok(value)
+    }
+
+    fn serialize_param(&self, param: &Decimal) -> serde_json::Value {
+        Decimal::DE.serialize_param(param)
+    }
+}
+
 macro_rules! impl_well_known_int {
     ($($int:ty),+) => {
         $(
@@ -715,3 +866,90 @@ where
         self.0.serialize_param(param)
     }
 }
+
+/// Deserializer decorator for smart pointers ([`Box`] and [`Arc`]) that delegates to the deserializer
+/// for the pointee type.
+///
+/// This is the [well-known](WellKnown) deserializer for `Box<T>` and `Arc<T>` where `T` is well-known,
+/// and for `Arc<str>`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{sync::Arc, time::Duration};
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     name: Arc<str>,
+///     timeout: Box<Duration>,
+///     ports: Arc<Vec<u16>>,
+/// }
+///
+/// let input = smart_config::config!(
+///     "name": "test",
+///     "timeout": "3s",
+///     "ports": [8080, 8081],
+/// );
+/// let config: TestConfig = testing::test(input)?;
+/// assert_eq!(config.name.as_ref(), "test");
+/// assert_eq!(*config.timeout, Duration::from_secs(3));
+/// assert_eq!(*config.ports, [8080, 8081]);
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug)]
+pub struct SmartPtr<De>(pub De);
+
+macro_rules! impl_smart_ptr {
+    ($($ptr:ident),+) => {
+        $(
+        impl<T, De: DeserializeParam<T>> DeserializeParam<$ptr<T>> for SmartPtr<De> {
+            const EXPECTING: BasicTypes = De::EXPECTING;
+
+            fn describe(&self, description: &mut TypeDescription) {
+                self.0.describe(description);
+            }
+
+            fn deserialize_param(
+                &self,
+                ctx: DeserializeContext<'_>,
+                param: &'static ParamMetadata,
+            ) -> Result<$ptr<T>, ErrorWithOrigin> {
+                self.0.deserialize_param(ctx, param).map($ptr::new)
+            }
+
+            fn serialize_param(&self, param: &$ptr<T>) -> serde_json::Value {
+                self.0.serialize_param(param)
+            }
+        }
+
+        impl<T: WellKnown> WellKnown for $ptr<T> {
+            type Deserializer = SmartPtr<T::Deserializer>;
+            const DE: Self::Deserializer = SmartPtr(T::DE);
+        }
+
+        impl<T: WellKnownOption> WellKnownOption for $ptr<T> {}
+        )+
+    };
+}
+
+impl_smart_ptr!(Box, Arc);
+
+impl<De: DeserializeParam<String>> DeserializeParam<Arc<str>> for SmartPtr<De> {
+    const EXPECTING: BasicTypes = De::EXPECTING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        self.0.describe(description);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Arc<str>, ErrorWithOrigin> {
+        self.0.deserialize_param(ctx, param).map(Arc::from)
+    }
+
+    fn serialize_param(&self, param: &Arc<str>) -> serde_json::Value {
+        self.0.serialize_param(&param.to_string())
+    }
+}
@@ -0,0 +1,225 @@
+//! Origin-aware deserialization of filesystem paths.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use serde::de::Error as DeError;
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam},
+    error::ErrorWithOrigin,
+    fallback,
+    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    value::{Value, ValueOrigin},
+};
+
+/// Deserializer for [`PathBuf`]s that resolves relative paths against the directory of the config file
+/// the path is read from, rather than against the current working directory of the process.
+///
+/// - A leading `~` component is expanded to the home directory (as specified by the `HOME` env var,
+///   or determined in a platform-specific way if the var is not set).
+/// - If the resulting path is relative and the param value originates from a file (including after
+///   transforms like aliasing), the path is resolved against the file's parent directory.
+/// - Otherwise (e.g., for values provided via env vars), relative paths are left as-is.
+///
+/// This deserializer is not used by default, since it changes the semantics of relative paths.
+/// Use it via `#[config(with = _)]`; it can be applied to `Option<PathBuf>` params as well.
+///
+/// # Examples
+///
+/// ```
+/// # use std::path::{Path, PathBuf};
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+/// use smart_config::{de::ResolvedPath, Yaml};
+///
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = ResolvedPath)]
+///     data_dir: PathBuf,
+///     #[config(with = ResolvedPath)]
+///     log_file: Option<PathBuf>,
+/// }
+///
+/// let yaml = "data_dir: data\nlog_file: /var/log/app.log";
+/// let yaml = serde_yaml::from_str(yaml)?;
+/// let input = Yaml::new("/etc/app/config.yml", yaml)?;
+/// let config: TestConfig = testing::test(input)?;
+/// assert_eq!(config.data_dir, Path::new("/etc/app/data"));
+/// assert_eq!(config.log_file.unwrap(), Path::new("/var/log/app.log"));
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedPath;
+
+impl ResolvedPath {
+    fn home_dir() -> Option<PathBuf> {
+        fallback::Env("HOME")
+            .get_raw()
+            .filter(|home| !home.is_empty())
+            .map(PathBuf::from)
+            .or_else(env::home_dir)
+    }
+
+    fn expand_home(raw: &str) -> Result<PathBuf, serde_json::Error> {
+        let rest = if raw == "~" {
+            ""
+        } else if let Some(rest) = raw.strip_prefix("~/") {
+            rest
+        } else {
+            return Ok(raw.into());
+        };
+        let home = Self::home_dir()
+            .ok_or_else(|| DeError::custom("cannot expand `~`: home directory is unknown"))?;
+        Ok(home.join(rest))
+    }
+
+    /// Resolves a raw path string originating from the specified source.
+    pub(crate) fn resolve(raw: &str, origin: &ValueOrigin) -> Result<PathBuf, serde_json::Error> {
+        let path = Self::expand_home(raw)?;
+        if path.is_relative() {
+            let base_dir = Self::source_file(origin)
+                .and_then(|name| Path::new(name).parent())
+                .filter(|dir| !dir.as_os_str().is_empty());
+            if let Some(base_dir) = base_dir {
+                return Ok(base_dir.join(path));
+            }
+        }
+        Ok(path)
+    }
+
+    fn source_file(origin: &ValueOrigin) -> Option<&str> {
+        match origin {
+            ValueOrigin::File { name, .. } => Some(name),
+            ValueOrigin::Path { source, .. } | ValueOrigin::Synthetic { source, .. } => {
+                Self::source_file(source)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl DeserializeParam<PathBuf> for ResolvedPath {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details(
+            "filesystem path; relative paths are resolved against the directory of the containing \
+             config file, `~` is expanded to the home directory",
+        );
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<PathBuf, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("path string"));
+        };
+        Self::resolve(s.expose(), deserializer.origin()).map_err(|err| deserializer.enrich_err(err))
+    }
+
+    fn serialize_param(&self, param: &PathBuf) -> serde_json::Value {
+        serde_json::to_value(param).expect("failed serializing path")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::{
+        DescribeConfig, DeserializeConfig, Environment, Yaml,
+        testing::{self, Tester},
+    };
+
+    #[derive(Debug, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct ConfigWithPaths {
+        #[config(with = ResolvedPath, alias = "dir")]
+        data_dir: PathBuf,
+        #[config(with = ResolvedPath)]
+        log_file: Option<PathBuf>,
+    }
+
+    fn yaml(filename: &str, contents: &str) -> Yaml {
+        Yaml::new(filename, serde_yaml::from_str(contents).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn resolving_relative_paths() {
+        let input = yaml(
+            "/etc/app/config.yml",
+            "data_dir: ./data\nlog_file: ../logs/app.log",
+        );
+        let config: ConfigWithPaths = testing::test(input).unwrap();
+        assert_eq!(config.data_dir, Path::new("/etc/app/./data"));
+        assert_eq!(
+            config.log_file.unwrap(),
+            Path::new("/etc/app/../logs/app.log")
+        );
+
+        // Aliased value
+        let input = yaml("/etc/app/config.yml", "dir: data");
+        let config: ConfigWithPaths = testing::test(input).unwrap();
+        assert_eq!(config.data_dir, Path::new("/etc/app/data"));
+        assert_eq!(config.log_file, None);
+
+        // Absolute paths are unchanged
+        let input = yaml("/etc/app/config.yml", "data_dir: /var/lib/app");
+        let config: ConfigWithPaths = testing::test(input).unwrap();
+        assert_eq!(config.data_dir, Path::new("/var/lib/app"));
+
+        // File without a directory component
+        let input = yaml("config.yml", "data_dir: data");
+        let config: ConfigWithPaths = testing::test(input).unwrap();
+        assert_eq!(config.data_dir, Path::new("data"));
+    }
+
+    #[test]
+    fn relative_paths_from_env() {
+        let env = Environment::from_iter("APP_", [("APP_DATA_DIR", "data")]);
+        let config: ConfigWithPaths = testing::test(env).unwrap();
+        assert_eq!(config.data_dir, Path::new("data"));
+    }
+
+    #[test]
+    fn expanding_home_dir() {
+        let mut tester = Tester::default();
+        tester.set_env("HOME", "/home/app");
+
+        let input = yaml("/etc/app/config.yml", "data_dir: ~/data\nlog_file: '~'");
+        let config: ConfigWithPaths = tester.test(input).unwrap();
+        assert_eq!(config.data_dir, Path::new("/home/app/data"));
+        assert_eq!(config.log_file.unwrap(), Path::new("/home/app"));
+
+        // `~user` paths are not expanded
+        let input = yaml("/etc/app/config.yml", "data_dir: ~app/data");
+        let config: ConfigWithPaths = tester.test(input).unwrap();
+        assert_eq!(config.data_dir, Path::new("/etc/app/~app/data"));
+    }
+
+    #[test]
+    fn describing_resolved_path() {
+        let param = &ConfigWithPaths::DESCRIPTION.params[0];
+        let description = param.type_description();
+        let details = description.details().unwrap();
+        assert!(
+            details.contains("resolved against the directory"),
+            "{details}"
+        );
+    }
+
+    #[test]
+    fn path_type_error() {
+        let input = yaml("/etc/app/config.yml", "data_dir: 42");
+        let err = testing::test::<ConfigWithPaths>(input).unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "data_dir");
+        assert!(err.inner().to_string().contains("path string"), "{err}");
+    }
+}
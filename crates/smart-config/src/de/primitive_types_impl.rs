@@ -79,6 +79,9 @@ macro_rules! impl_well_known_uint {
 
 impl_well_known_uint!(U128, U256, U512);
 
+// Allows using 256-bit integers in `TokenAmount`s.
+crate::types::impl_token_int!(primitive_types::U256);
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -87,7 +90,7 @@ mod tests {
     use smart_config_derive::{DescribeConfig, DeserializeConfig};
 
     use crate::{
-        config,
+        TokenAmount, config,
         testing::{test, test_complete},
     };
 
@@ -142,4 +145,41 @@ mod tests {
             "{inner}"
         );
     }
+
+    #[test]
+    fn deserializing_u256_token_amount() {
+        #[derive(Debug, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct TokenConfig {
+            #[config(with = crate::de::TokenSymbol("tkn"))]
+            amount: TokenAmount<36, U256>,
+        }
+
+        // Amounts with more significant digits than supported by `Decimal` are parsed exactly
+        let json = config!("amount": "1_000_000_000_000_000_000_000.000_000_000_000_000_000_000_000_000_000_005 tkn");
+        let config = test::<TokenConfig>(json).unwrap();
+        assert_eq!(
+            config.amount,
+            TokenAmount(U256::exp10(57) + U256::from(5_000))
+        );
+
+        let json = config!("amount": "1e23");
+        let config = test::<TokenConfig>(json).unwrap();
+        assert_eq!(
+            config.amount,
+            TokenAmount(U256::from(10).pow(U256::from(59)))
+        );
+        let json = config!("amount": "1.000_000_000_000_000_005e23 tkn");
+        let config = test::<TokenConfig>(json).unwrap();
+        assert_eq!(
+            config.amount,
+            TokenAmount(U256::exp10(59) + U256::from(5) * U256::exp10(41))
+        );
+        assert_eq!(config.amount.to_string(), "100000000000000000500000");
+
+        let json = config!("amount": "1e42");
+        let err = test::<TokenConfig>(json).unwrap_err();
+        let err = err.first().inner().to_string();
+        assert!(err.contains("does not fit"), "{err}");
+    }
 }
@@ -0,0 +1,329 @@
+//! Deserialization of inclusive ranges.
+
+use std::{ops::RangeInclusive, sync::Arc};
+
+use serde::de::Error as DeError;
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam, WellKnown, WellKnownOption},
+    error::{ErrorWithOrigin, LowLevelError},
+    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    value::{Map, StrValue, Value, ValueOrigin, WithOrigin},
+};
+
+/// Deserializer for [`RangeInclusive`]s that delegates to the wrapped deserializer for range bounds.
+///
+/// This is the [well-known](WellKnown) deserializer for `RangeInclusive<T>` where `T` is well-known.
+/// A range can be specified as:
+///
+/// - A string with bounds separated by `..`, `..=` or `-`, e.g. `"1000..2000"`, `"30000-31000"` or `"100ms-2s"`.
+///   Whitespace around bounds is trimmed.
+/// - An object with `min` and `max` fields, e.g. `{ "min": 1000, "max": 2000 }`.
+///
+/// Each bound is deserialized using the wrapped deserializer; thus, bounds for unit-based types (e.g., [`Duration`](std::time::Duration)
+/// or [`ByteSize`](crate::ByteSize)) may include units. The start bound must not exceed the end bound.
+/// Ranges are serialized as objects.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{ops::RangeInclusive, time::Duration};
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct P2pConfig {
+///     ports: RangeInclusive<u16>,
+///     backoff: RangeInclusive<Duration>,
+/// }
+///
+/// let input = smart_config::config!(
+///     "ports": "30000-31000",
+///     "backoff": serde_json::json!({ "min": "100ms", "max": "2s" }),
+/// );
+/// let config: P2pConfig = testing::test(input)?;
+/// assert_eq!(config.ports, 30_000..=31_000);
+/// assert_eq!(
+///     config.backoff,
+///     Duration::from_millis(100)..=Duration::from_secs(2)
+/// );
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug)]
+pub struct InclusiveRange<De>(pub De);
+
+impl<De> InclusiveRange<De> {
+    const MIN_FIELD: &'static str = "min";
+    const MAX_FIELD: &'static str = "max";
+
+    /// Splits a string into range bounds.
+    fn split_bounds(s: &str) -> Option<(&str, &str)> {
+        if let Some((start, end)) = s.split_once("..") {
+            let end = end.strip_prefix('=').unwrap_or(end);
+            return Some((start.trim(), end.trim()));
+        }
+
+        // Look for `-` separator, skipping over the leading minus sign and exponents in numbers like `1e-3`.
+        let bytes = s.as_bytes();
+        let pos = (1..bytes.len()).find(|&i| {
+            bytes[i] == b'-'
+                && !(matches!(bytes[i - 1], b'e' | b'E')
+                    && i >= 2
+                    && matches!(bytes[i - 2], b'0'..=b'9' | b'.'))
+        })?;
+        Some((s[..pos].trim(), s[pos + 1..].trim()))
+    }
+
+    /// Transforms a string value into an object with the `min` and `max` fields.
+    fn string_to_object(s: &StrValue, origin: &Arc<ValueOrigin>) -> Option<WithOrigin> {
+        let (start, end) = Self::split_bounds(s.expose())?;
+        let object_origin = Arc::new(ValueOrigin::Synthetic {
+            source: origin.clone(),
+            transform: "range string".to_owned(),
+        });
+        let bound = |name: &str, value: &str| {
+            let value = if s.is_secret() {
+                StrValue::Secret(value.into())
+            } else {
+                StrValue::Plain(value.into())
+            };
+            let origin = ValueOrigin::Path {
+                source: object_origin.clone(),
+                path: name.to_owned(),
+            };
+            (
+                name.to_owned(),
+                WithOrigin::new(Value::String(value), Arc::new(origin)),
+            )
+        };
+        let object: Map = [bound(Self::MIN_FIELD, start), bound(Self::MAX_FIELD, end)]
+            .into_iter()
+            .collect();
+        Some(WithOrigin::new(Value::Object(object), object_origin))
+    }
+
+    fn deserialize_bound<T>(
+        &self,
+        ctx: &mut DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+        name: &str,
+        value: &WithOrigin,
+    ) -> Option<T>
+    where
+        De: DeserializeParam<T>,
+    {
+        let coerced = value.coerce_value_type(De::EXPECTING);
+        let mut child_ctx = ctx.child(name, ctx.location_in_config);
+        let mut child_ctx = child_ctx.patched(coerced.as_ref().unwrap_or(value));
+        match self.0.deserialize_param(child_ctx.borrow(), param) {
+            Ok(val) => Some(val),
+            Err(err) => {
+                child_ctx.push_error(err);
+                None
+            }
+        }
+    }
+}
+
+impl<T, De> DeserializeParam<RangeInclusive<T>> for InclusiveRange<De>
+where
+    T: 'static + PartialOrd,
+    De: DeserializeParam<T>,
+{
+    const EXPECTING: BasicTypes = BasicTypes::STRING.or(BasicTypes::OBJECT);
+
+    fn describe(&self, description: &mut TypeDescription) {
+        // Only inherit the unit of measurement and secrecy from bounds; e.g., inheriting type suffixes
+        // would lead to incorrect param value nesting.
+        let mut bound_description = TypeDescription::default();
+        self.0.describe(&mut bound_description);
+        if let Some(unit) = bound_description.unit() {
+            description.set_unit(unit);
+        }
+        if bound_description.is_secret {
+            description.set_secret();
+        }
+        description.set_details("inclusive range, e.g. '1..10', '1-10' or { min, max } object");
+    }
+
+    fn deserialize_param(
+        &self,
+        mut ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<RangeInclusive<T>, ErrorWithOrigin> {
+        let Some(current_value) = ctx.current_value() else {
+            return Err(DeError::missing_field(param.name));
+        };
+        let object_value = match &current_value.inner {
+            Value::String(s) => Self::string_to_object(s, &current_value.origin),
+            Value::Object(_) => None,
+            _ => return Err(current_value.invalid_type("range string or { min, max } object")),
+        };
+        let value = object_value.as_ref().unwrap_or(current_value);
+        let Value::Object(object) = &value.inner else {
+            let err = DeError::custom("range string must contain bounds separated by '..' or '-'");
+            return Err(ErrorWithOrigin::json(err, value.origin.clone()));
+        };
+
+        for field in object.keys() {
+            if field != Self::MIN_FIELD && field != Self::MAX_FIELD {
+                let err = DeError::unknown_field(field, &[Self::MIN_FIELD, Self::MAX_FIELD]);
+                return Err(ErrorWithOrigin::json(err, value.origin.clone()));
+            }
+        }
+        let bounds = [Self::MIN_FIELD, Self::MAX_FIELD].map(|name| {
+            object.get(name).ok_or_else(|| {
+                ErrorWithOrigin::json(DeError::missing_field(name), value.origin.clone())
+            })
+        });
+        let [start, end] = bounds;
+        let (start, end) = (start?, end?);
+
+        let start = self.deserialize_bound(&mut ctx, param, Self::MIN_FIELD, start);
+        let end = self.deserialize_bound(&mut ctx, param, Self::MAX_FIELD, end);
+        let (Some(start), Some(end)) = (start, end) else {
+            return Err(ErrorWithOrigin::new(
+                LowLevelError::InvalidObject,
+                value.origin.clone(),
+            ));
+        };
+        if start > end {
+            let err = DeError::custom("range start must not exceed its end");
+            return Err(ErrorWithOrigin::json(err, value.origin.clone()));
+        }
+        Ok(start..=end)
+    }
+
+    fn serialize_param(&self, param: &RangeInclusive<T>) -> serde_json::Value {
+        serde_json::json!({
+            Self::MIN_FIELD: self.0.serialize_param(param.start()),
+            Self::MAX_FIELD: self.0.serialize_param(param.end()),
+        })
+    }
+}
+
+impl<T: WellKnown + PartialOrd> WellKnown for RangeInclusive<T> {
+    type Deserializer = InclusiveRange<T::Deserializer>;
+    const DE: Self::Deserializer = InclusiveRange(T::DE);
+}
+
+impl<T: WellKnown + PartialOrd> WellKnownOption for RangeInclusive<T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        ByteSize, DescribeConfig, DeserializeConfig, Environment, config, metadata::SizeUnit,
+        testing::test, testonly::test_config_roundtrip,
+    };
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct ConfigWithRanges {
+        ports: RangeInclusive<u16>,
+        #[config(default_t = -5..=5)]
+        offsets: RangeInclusive<i32>,
+        latency: Option<RangeInclusive<Duration>>,
+        #[config(default_t = ByteSize(0)..=ByteSize(1 << 20))]
+        sizes: RangeInclusive<ByteSize>,
+    }
+
+    #[test]
+    fn splitting_range_bounds() {
+        let split = InclusiveRange::<()>::split_bounds;
+        assert_eq!(split("1..10"), Some(("1", "10")));
+        assert_eq!(split("1..=10"), Some(("1", "10")));
+        assert_eq!(split(" 1 .. 10 "), Some(("1", "10")));
+        assert_eq!(split("-10..-1"), Some(("-10", "-1")));
+        assert_eq!(split("30000-31000"), Some(("30000", "31000")));
+        assert_eq!(split("-10--1"), Some(("-10", "-1")));
+        assert_eq!(split("100ms-2s"), Some(("100ms", "2s")));
+        assert_eq!(
+            split("1 minute - 2 minutes"),
+            Some(("1 minute", "2 minutes"))
+        );
+        assert_eq!(split("1e-3-1.5e-2"), Some(("1e-3", "1.5e-2")));
+        assert_eq!(split("10"), None);
+        assert_eq!(split("-10"), None);
+    }
+
+    #[test]
+    fn deserializing_ranges() {
+        let config: ConfigWithRanges = test(config!(
+            "ports": "30000-31000",
+            "offsets": "-10..=-1",
+            "latency": "100ms..2s",
+            "sizes": serde_json::json!({ "min": "1 KiB", "max": "16 MiB" }),
+        ))
+        .unwrap();
+        assert_eq!(config.ports, 30_000..=31_000);
+        assert_eq!(config.offsets, -10..=-1);
+        assert_eq!(
+            config.latency,
+            Some(Duration::from_millis(100)..=Duration::from_secs(2))
+        );
+        assert_eq!(config.sizes, SizeUnit::KiB * 1..=SizeUnit::MiB * 16);
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(
+            json["ports"],
+            serde_json::json!({ "min": 30_000, "max": 31_000 })
+        );
+    }
+
+    #[test]
+    fn deserializing_ranges_from_env() {
+        let env = Environment::from_iter(
+            "",
+            [
+                ("PORTS", "8000..8010"),
+                ("LATENCY", "1s-5s"),
+                ("LATENCY_MS", "??"),
+            ],
+        );
+        let config: ConfigWithRanges = test(env).unwrap();
+        assert_eq!(config.ports, 8_000..=8_010);
+        assert_eq!(config.offsets, -5..=5);
+        assert_eq!(
+            config.latency,
+            Some(Duration::from_secs(1)..=Duration::from_secs(5))
+        );
+        assert_eq!(config.sizes, ByteSize(0)..=SizeUnit::MiB * 1);
+    }
+
+    #[test]
+    fn range_deserialization_errors() {
+        let err = test::<ConfigWithRanges>(config!("ports": "8000")).unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "ports");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("bounds separated by"), "{inner}");
+
+        let err = test::<ConfigWithRanges>(config!("ports": "8000..80000")).unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "ports.max");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("invalid value"), "{inner}");
+
+        let err = test::<ConfigWithRanges>(config!("ports": "8010..8000")).unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "ports");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("must not exceed"), "{inner}");
+
+        let err = test::<ConfigWithRanges>(config!(
+            "ports": serde_json::json!({ "min": 8000, "end": 8010 }),
+        ))
+        .unwrap_err();
+        let inner = err.first().inner().to_string();
+        assert!(inner.contains("unknown field `end`"), "{inner}");
+
+        let err = test::<ConfigWithRanges>(config!(
+            "ports": "1..2",
+            "latency": "100ms..2 lightyears",
+        ))
+        .unwrap_err();
+        let err = err.first();
+        assert_eq!(err.path(), "latency.max");
+    }
+}
@@ -0,0 +1,107 @@
+use serde::de::Error as DeError;
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam, WellKnown, WellKnownOption},
+    error::ErrorWithOrigin,
+    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    pat::Regex,
+    value::Value,
+};
+
+/// Deserializer for [`Regex`]es, which don't implement `serde` traits.
+#[derive(Debug)]
+pub struct RegexDeserializer;
+
+impl DeserializeParam<Regex> for RegexDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("regular expression");
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Regex, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("regular expression"));
+        };
+        Regex::new(s.expose()).map_err(|err| deserializer.enrich_err(DeError::custom(err)))
+    }
+
+    fn serialize_param(&self, param: &Regex) -> serde_json::Value {
+        param.as_str().into()
+    }
+}
+
+/// Accepts a regular expression conforming to the [syntax](regex#syntax) supported by the `regex` crate.
+/// The regex is compiled during deserialization, so that an invalid regex is reported as a param deserialization error.
+impl WellKnown for Regex {
+    type Deserializer = RegexDeserializer;
+    const DE: Self::Deserializer = RegexDeserializer;
+}
+
+impl WellKnownOption for Regex {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        DescribeConfig, DeserializeConfig, config,
+        de::{Delimited, DeserializeParam, WellKnown},
+        pat::Regex,
+        testing::test,
+    };
+
+    #[derive(Debug, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        filter: Regex,
+        #[config(default)]
+        exclude: Option<Regex>,
+        #[config(default, with = Delimited::new(","))]
+        allow: Vec<Regex>,
+    }
+
+    #[test]
+    fn describing_regexes() {
+        let param = &TestConfig::DESCRIPTION.params[0];
+        let description = param.type_description();
+        assert_eq!(description.details().unwrap(), "regular expression");
+    }
+
+    #[test]
+    fn deserializing_regexes() {
+        let json = config!(
+            "filter": r"^api\.v\d+$",
+            "exclude": "test",
+            "allow": r"^a+$,^b+$",
+        );
+        let config = test::<TestConfig>(json).unwrap();
+        assert!(config.filter.is_match("api.v2"));
+        assert!(!config.filter.is_match("api_v2"));
+        assert_eq!(config.exclude.unwrap().as_str(), "test");
+        let allow: Vec<_> = config.allow.iter().map(Regex::as_str).collect();
+        assert_eq!(allow, [r"^a+$", r"^b+$"]);
+
+        let serialized = Regex::DE.serialize_param(&config.filter);
+        assert_eq!(serialized, r"^api\.v\d+$");
+    }
+
+    #[test]
+    fn regex_errors() {
+        let json = config!("filter": "(unclosed");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "filter");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("unclosed group"), "{inner}");
+        assert!(
+            err.origin().to_string().contains("inline config"),
+            "{}",
+            err.origin()
+        );
+    }
+}
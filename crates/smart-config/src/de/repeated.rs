@@ -22,7 +22,8 @@ use crate::{
 
 /// Deserializer from JSON arrays.
 ///
-/// Supports deserializing to [`Vec`], arrays, [`HashSet`], [`BTreeSet`].
+/// Supports deserializing to [`Vec`], arrays, [`HashSet`], [`BTreeSet`], and `IndexSet` if the `indexmap` feature is enabled.
+/// The order of items in the array is preserved for ordered containers.
 #[derive(Debug)]
 pub struct Repeated<De>(pub De);
 
@@ -123,6 +124,23 @@ where
     impl_serialization_for_repeated!(HashSet<T, S>);
 }
 
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<T, S, De> DeserializeParam<indexmap::IndexSet<T, S>> for Repeated<De>
+where
+    T: 'static + Eq + Hash,
+    S: 'static + Default + BuildHasher,
+    De: DeserializeParam<T>,
+{
+    const EXPECTING: BasicTypes = BasicTypes::ARRAY;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("ordered set").set_items(&self.0);
+    }
+
+    impl_serialization_for_repeated!(indexmap::IndexSet<T, S>);
+}
+
 impl<T, De> DeserializeParam<BTreeSet<T>> for Repeated<De>
 where
     T: 'static + Eq + Ord,
@@ -182,8 +200,109 @@ impl<T: WellKnown, const N: usize> WellKnown for [T; N] {
 
 impl<T: WellKnown, const N: usize> WellKnownOption for [T; N] {}
 
-// Heterogeneous tuples don't look like a good idea to mark as well-known because they wouldn't look well-structured
-// (it'd be better to define either multiple params or a struct param).
+/// Deserializer for heterogeneous tuples from JSON arrays. Tuples with 2 to 4 elements are supported.
+///
+/// This is the [well-known](WellKnown) deserializer for tuples consisting of well-known types.
+/// The wrapped value is a tuple of deserializers for each tuple element. To deserialize a tuple
+/// from a delimited string, wrap the deserializer in [`Delimited`].
+///
+/// Tuples are best suited for short, self-describing values like `host:port` pairs; for more complex data,
+/// consider defining multiple params or a nested config instead.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{de::Delimited, testing, DescribeConfig, DeserializeConfig};
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     pair: (String, u16),
+///     #[config(with = Delimited::new(":"))]
+///     delimited: (String, u16, bool),
+/// }
+///
+/// let input = smart_config::config!(
+///     "pair": serde_json::json!(["localhost", 8080]),
+///     "delimited": "localhost:3000:true",
+/// );
+/// let config: TestConfig = testing::test(input)?;
+/// assert_eq!(config.pair, ("localhost".to_owned(), 8080));
+/// assert_eq!(config.delimited, ("localhost".to_owned(), 3000, true));
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug)]
+pub struct Tuple<De>(pub De);
+
+/// Briefly describes a tuple element, e.g. as `integer` or `time duration`.
+fn describe_element<T: 'static, De: DeserializeParam<T>>(de: &De) -> String {
+    let mut description = TypeDescription::default();
+    de.describe(&mut description);
+    match description.details() {
+        Some(details) => details.to_owned(),
+        None => De::EXPECTING.to_string(),
+    }
+}
+
+macro_rules! impl_tuple {
+    ($len:literal => $($t:ident: $de:ident = $var:ident . $idx:tt),+) => {
+        impl<$($t: 'static,)+ $($de: DeserializeParam<$t>,)+> DeserializeParam<($($t,)+)>
+            for Tuple<($($de,)+)>
+        {
+            const EXPECTING: BasicTypes = BasicTypes::ARRAY;
+
+            fn describe(&self, description: &mut TypeDescription) {
+                let elements = [$(describe_element::<$t, _>(&self.0.$idx),)+];
+                let details = format!(concat!($len, "-element tuple [{}]"), elements.join(", "));
+                description.set_details(details);
+            }
+
+            fn deserialize_param(
+                &self,
+                mut ctx: DeserializeContext<'_>,
+                param: &'static ParamMetadata,
+            ) -> Result<($($t,)+), ErrorWithOrigin> {
+                let deserializer = ctx.current_value_deserializer(param.name)?;
+                let Value::Array(items) = deserializer.value() else {
+                    return Err(deserializer.invalid_type(concat!($len, "-element array")));
+                };
+                if items.len() != $len {
+                    let err = DeError::invalid_length(items.len(), &stringify!($len));
+                    return Err(deserializer.enrich_err(err));
+                }
+
+                $(
+                let $var = parse_key_or_value::<$t, _>(
+                    &mut ctx,
+                    param,
+                    stringify!($idx),
+                    &self.0.$idx,
+                    &items[$idx],
+                );
+                )+
+                if let ($(Some($var),)+) = ($($var,)+) {
+                    Ok(($($var,)+))
+                } else {
+                    let origin = deserializer.origin().clone();
+                    Err(ErrorWithOrigin::new(LowLevelError::InvalidArray, origin))
+                }
+            }
+
+            fn serialize_param(&self, param: &($($t,)+)) -> serde_json::Value {
+                serde_json::Value::Array(vec![$(self.0.$idx.serialize_param(&param.$idx),)+])
+            }
+        }
+
+        impl<$($t: WellKnown,)+> WellKnown for ($($t,)+) {
+            type Deserializer = Tuple<($($t::Deserializer,)+)>;
+            const DE: Self::Deserializer = Tuple(($($t::DE,)+));
+        }
+
+        impl<$($t: WellKnown,)+> WellKnownOption for ($($t,)+) {}
+    };
+}
+
+impl_tuple!(2 => A: DeA = a.0, B: DeB = b.1);
+impl_tuple!(3 => A: DeA = a.0, B: DeB = b.1, C: DeC = c.2);
+impl_tuple!(4 => A: DeA = a.0, B: DeB = b.1, C: DeC = c.2, D: DeD = d.3);
 
 impl<T, S> WellKnown for HashSet<T, S>
 where
@@ -211,9 +330,29 @@ where
 
 impl<T> WellKnownOption for BTreeSet<T> where T: Eq + Ord + WellKnown {}
 
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<T, S> WellKnown for indexmap::IndexSet<T, S>
+where
+    T: Eq + Hash + WellKnown,
+    S: 'static + Default + BuildHasher,
+{
+    type Deserializer = Repeated<T::Deserializer>;
+    const DE: Self::Deserializer = Repeated(T::DE);
+}
+
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<T, S> WellKnownOption for indexmap::IndexSet<T, S>
+where
+    T: Eq + Hash + WellKnown,
+    S: 'static + Default + BuildHasher,
+{
+}
+
 /// Deserializer from JSON objects.
 ///
-/// Supports deserializing to [`HashMap`] and [`BTreeMap`].
+/// Supports deserializing to [`HashMap`], [`BTreeMap`], and `IndexMap` if the `indexmap` feature is enabled.
 pub struct Entries<K, V, DeK = <K as WellKnown>::Deserializer, DeV = <V as WellKnown>::Deserializer>
 {
     keys: DeK,
@@ -274,6 +413,11 @@ where
         }
     }
 
+    /// Converts this to an [`OrderedEntries`] instance.
+    pub const fn ordered(self) -> OrderedEntries<K, V, DeK, DeV> {
+        OrderedEntries { inner: self }
+    }
+
     /// Converts this to a [`DelimitedEntries`] instance.
     ///
     /// # Panics
@@ -473,6 +617,167 @@ where
 {
 }
 
+/// Uses [`OrderedEntries`], so entries must be specified as an array of `[key, value]` pairs; the entry order is preserved.
+/// Entries specified as an object are rejected since JSON objects in the [value model](crate::value::Map) are ordered by key,
+/// i.e., they don't preserve the order of entries in the source.
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<K, V, S> WellKnown for indexmap::IndexMap<K, V, S>
+where
+    K: 'static + Eq + Hash + WellKnown,
+    V: 'static + WellKnown,
+    S: 'static + Default + BuildHasher,
+{
+    type Deserializer = OrderedEntries<K, V, K::Deserializer, V::Deserializer>;
+    const DE: Self::Deserializer = Entries::new(K::DE, V::DE).ordered();
+}
+
+#[cfg(feature = "indexmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+impl<K, V, S> WellKnownOption for indexmap::IndexMap<K, V, S>
+where
+    K: 'static + Eq + Hash + WellKnown,
+    V: 'static + WellKnown,
+    S: 'static + Default + BuildHasher,
+{
+}
+
+/// Deserializer that supports an array of `[key, value]` pairs. Created using [`Entries::ordered()`].
+///
+/// Entries are deserialized in the order they are specified, and values are serialized as an array of pairs.
+/// Since JSON objects in the [value model](crate::value::Map) are ordered by key, maps are rejected with an error
+/// rather than silently losing the source order of entries. This is the [well-known](WellKnown) deserializer for `IndexMap` if the `indexmap` feature is enabled.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{de::Entries, testing, DescribeConfig, DeserializeConfig};
+/// #[derive(Debug, DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = Entries::WELL_KNOWN.ordered())]
+///     entries: Vec<(String, u64)>,
+/// }
+///
+/// let input = smart_config::config!(
+///     "entries": serde_json::json!([["z", 1], ["a", 2]]),
+/// );
+/// let config: TestConfig = testing::test(input)?;
+/// assert_eq!(config.entries, [("z".to_owned(), 1), ("a".to_owned(), 2)]);
+///
+/// // Maps are rejected since they don't preserve the entry order.
+/// let input = smart_config::config!(
+///     "entries": serde_json::json!({ "z": 1, "a": 2 }),
+/// );
+/// let err = testing::test::<TestConfig>(input).unwrap_err();
+/// let err = err.first().inner().to_string();
+/// assert!(err.contains("don't preserve entry order"), "{err}");
+/// # anyhow::Ok(())
+/// ```
+pub struct OrderedEntries<
+    K,
+    V,
+    DeK = <K as WellKnown>::Deserializer,
+    DeV = <V as WellKnown>::Deserializer,
+> {
+    inner: Entries<K, V, DeK, DeV>,
+}
+
+impl<K, V, DeK: fmt::Debug, DeV: fmt::Debug> fmt::Debug for OrderedEntries<K, V, DeK, DeV> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("OrderedEntries")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<K, V, DeK, DeV> OrderedEntries<K, V, DeK, DeV>
+where
+    DeK: DeserializeParam<K>,
+    DeV: DeserializeParam<V>,
+{
+    fn deserialize_pairs<C: FromIterator<(K, V)>>(
+        &self,
+        mut ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+        array: &[WithOrigin],
+        array_origin: &Arc<ValueOrigin>,
+    ) -> Result<C, ErrorWithOrigin> {
+        let mut has_errors = false;
+        let items = array.iter().enumerate().filter_map(|(i, entry)| {
+            let idx_str = i.to_string();
+            let pair = match &entry.inner {
+                Value::Array(pair) if pair.len() == 2 => pair,
+                _ => {
+                    let err = entry.invalid_type("[key, value] pair");
+                    ctx.child(&idx_str, ctx.location_in_config).push_error(err);
+                    has_errors = true;
+                    return None;
+                }
+            };
+
+            let parsed_key =
+                parse_key_or_value::<K, _>(&mut ctx, param, &idx_str, &self.inner.keys, &pair[0]);
+            let parsed_value =
+                parse_key_or_value::<V, _>(&mut ctx, param, &idx_str, &self.inner.values, &pair[1]);
+            has_errors |= parsed_key.is_none() || parsed_value.is_none();
+            Some((parsed_key?, parsed_value?)).filter(|_| !has_errors)
+        });
+        let items: C = items.collect();
+
+        if has_errors {
+            let origin = array_origin.clone();
+            Err(ErrorWithOrigin::new(LowLevelError::InvalidArray, origin))
+        } else {
+            Ok(items)
+        }
+    }
+}
+
+impl<K, V, DeK, DeV, C> DeserializeParam<C> for OrderedEntries<K, V, DeK, DeV>
+where
+    K: 'static,
+    V: 'static,
+    DeK: DeserializeParam<K>,
+    DeV: DeserializeParam<V>,
+    C: FromIterator<(K, V)> + ToEntries<K, V>,
+{
+    const EXPECTING: BasicTypes = BasicTypes::ARRAY;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("array of [key, value] pairs")
+            .set_entries(&self.inner.keys, &self.inner.values);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<C, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        match deserializer.value() {
+            Value::Array(array) => self.deserialize_pairs(ctx, param, array, deserializer.origin()),
+            Value::Object(_) => Err(deserializer.invalid_type(
+                "array of [key, value] pairs (objects are not supported since they don't preserve entry order)",
+            )),
+            _ => Err(deserializer.invalid_type("array of [key, value] pairs")),
+        }
+    }
+
+    fn serialize_param(&self, param: &C) -> serde_json::Value {
+        let pairs = param
+            .to_entries()
+            .map(|(key, value)| {
+                let key = self.inner.keys.serialize_param(key);
+                let value = self.inner.values.serialize_param(value);
+                serde_json::json!([key, value])
+            })
+            .collect();
+        serde_json::Value::Array(pairs)
+    }
+}
+
 /// Deserializer that supports either an array of values, or a string in which values are delimited
 /// by the specified separator.
 ///
@@ -2,6 +2,7 @@ use secrecy::{ExposeSecret, SecretString};
 
 use super::{DeserializeContext, DeserializeParam, WellKnown, WellKnownOption};
 use crate::{
+    SensitiveUrl,
     error::ErrorWithOrigin,
     metadata::{BasicTypes, ParamMetadata, TypeDescription},
     value::{StrValue, Value},
@@ -145,3 +146,42 @@ where
         self.0.serialize_param(param)
     }
 }
+
+/// Deserializer for [`SensitiveUrl`]s. Marks the param as [partially secret](TypeDescription::set_redaction()).
+#[derive(Debug)]
+pub struct SensitiveUrlDeserializer;
+
+impl DeserializeParam<SensitiveUrl> for SensitiveUrlDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("URL with secret credentials")
+            .set_redaction(SensitiveUrl::redact);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<SensitiveUrl, ErrorWithOrigin> {
+        let de = ctx.current_value_deserializer(param.name)?;
+        let s = match de.value() {
+            Value::String(StrValue::Secret(s)) => s.expose_secret(),
+            Value::String(StrValue::Plain(s)) => s.as_str(),
+            _ => return Err(de.invalid_type("URL string")),
+        };
+        s.parse().map_err(|err| de.enrich_err(err))
+    }
+
+    fn serialize_param(&self, param: &SensitiveUrl) -> serde_json::Value {
+        param.expose_str().into()
+    }
+}
+
+impl WellKnown for SensitiveUrl {
+    type Deserializer = SensitiveUrlDeserializer;
+    const DE: Self::Deserializer = SensitiveUrlDeserializer;
+}
+
+impl WellKnownOption for SensitiveUrl {}
@@ -0,0 +1,75 @@
+use semver::{Version, VersionReq};
+
+use crate::de::{Qualified, Serde, WellKnown, WellKnownOption};
+
+/// Accepts a [semantic version](https://semver.org/) string, e.g. `1.2.3` or `1.0.0-rc.1+build.5`.
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+impl WellKnown for Version {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = Qualified::new(Serde![str], "semantic version, e.g. '1.2.3'");
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+impl WellKnownOption for Version {}
+
+/// Accepts a Cargo-style semantic version requirement, e.g. `^1.2`, `>=1.0, <2` or `*`.
+/// A requirement can be matched against a [`Version`] in [validations](crate::validation).
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+impl WellKnown for VersionReq {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer =
+        Qualified::new(Serde![str], "semantic version requirement, e.g. '^1.2'");
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "semver")))]
+impl WellKnownOption for VersionReq {}
+
+#[cfg(test)]
+mod tests {
+    use semver::{Version, VersionReq};
+    use smart_config_derive::{DescribeConfig, DeserializeConfig};
+
+    use crate::{config, testing::test, testonly::test_config_roundtrip};
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        version: Version,
+        #[config(default)]
+        min_peer_version: Option<VersionReq>,
+    }
+
+    #[test]
+    fn deserializing_versions() {
+        let json = config!(
+            "version": "1.2.3-rc.1+build.5",
+            "min_peer_version": ">=1.0, <2",
+        );
+        let config = test::<TestConfig>(json).unwrap();
+        assert_eq!(config.version.minor, 2);
+        assert_eq!(config.version.pre.as_str(), "rc.1");
+        let req = config.min_peer_version.as_ref().unwrap();
+        assert!(req.matches(&Version::new(1, 5, 0)));
+        assert!(!req.matches(&Version::new(2, 0, 0)));
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["version"], "1.2.3-rc.1+build.5");
+        assert_eq!(json["min_peer_version"], ">=1.0, <2");
+    }
+
+    #[test]
+    fn version_errors() {
+        let json = config!("version": "1.2");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "version");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("unexpected end of input"), "{inner}");
+
+        let json = config!("version": "1.2.3", "min_peer_version": "~>1.0");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.first().path(), "min_peer_version");
+    }
+}
@@ -1,7 +1,9 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     net::{Ipv4Addr, Ipv6Addr},
     num::{NonZeroI128, NonZeroU128, NonZeroUsize},
+    sync::Arc,
     time::Duration,
 };
 
@@ -10,9 +12,10 @@ use serde::Deserialize;
 
 use super::deserializer::ValueDeserializer;
 use crate::{
-    ByteSize, DescribeConfig, Environment, ParseError, config,
-    de::DeserializerOptions,
+    ByteSize, DescribeConfig, DeserializeConfig, Environment, ParseError, config,
+    de::{Delimited, DeserializerOptions},
     metadata::{EtherUnit, SizeUnit},
+    testing,
     testonly::{
         ComposedConfig, CompoundConfig, ConfigWithComplexTypes, ConfigWithNesting,
         DefaultingConfig, DefaultingEnumConfig, EnumConfig, MapOrString, NestedConfig,
@@ -456,7 +459,7 @@ fn parsing_complex_types() {
             long_dur: Duration::from_mins(5),
             path: "/mnt".into(),
             memory_size_mb: Some(64 * SizeUnit::MiB),
-            disk_size: Some(4 * SizeUnit::GiB),
+            disk_size: Some(4 * SizeUnit::GB),
             paths: vec!["/usr/bin".into(), "/usr/local/bin".into(), "/bin".into()],
             map_or_string: MapOrString(HashMap::from([("test".into(), 1), ("other".into(), 2)])),
             delimited_map: HashMap::from([("test".into(), 1), ("other".into(), 2)]),
@@ -503,7 +506,7 @@ fn parsing_complex_types() {
             long_dur: Duration::from_millis(500),
             path: "./test".into(),
             memory_size_mb: None,
-            disk_size: Some(256 * SizeUnit::MiB),
+            disk_size: Some(256 * SizeUnit::MB),
             paths: vec!["/usr/bin".into(), "/mnt".into()],
             map_or_string: MapOrString(HashMap::from([("test".into(), 42), ("other".into(), 23)])),
             delimited_map: HashMap::from([("test".into(), 42), ("other".into(), 23)]),
@@ -764,6 +767,197 @@ fn errors_parsing_named_entries() {
     assert!(inner.contains("invalid type"), "{inner}");
 }
 
+#[test]
+fn deserializing_std_wrappers() {
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct WrappersConfig {
+        boxed_str: Box<str>,
+        arc_str: Arc<str>,
+        cow: Cow<'static, str>,
+        #[config(default_t = ',')]
+        separator: char,
+        boxed_duration: Box<Duration>,
+        arc_sizes: Option<Arc<Vec<ByteSize>>>,
+    }
+
+    let json = config!(
+        "boxed_str": "boxed",
+        "arc_str": "arc",
+        "cow": "cow",
+        "boxed_duration": "3 sec",
+        "arc_sizes": ["1 KiB", "2 MiB"],
+    );
+    let config: WrappersConfig = test_deserialize(json.inner()).unwrap();
+    assert_eq!(config.boxed_str.as_ref(), "boxed");
+    assert_eq!(config.arc_str.as_ref(), "arc");
+    assert_eq!(config.cow, "cow");
+    assert_eq!(config.separator, ',');
+    assert_eq!(*config.boxed_duration, Duration::from_secs(3));
+    assert_eq!(
+        config.arc_sizes.as_deref().unwrap(),
+        &[SizeUnit::KiB * 1, SizeUnit::MiB * 2]
+    );
+    let json = test_config_roundtrip(&config);
+    assert_eq!(json["arc_str"], "arc");
+    assert_eq!(json["boxed_duration"], "3s");
+
+    // Descriptions should be inherited from pointee types.
+    let param = WrappersConfig::DESCRIPTION
+        .params
+        .iter()
+        .find(|param| param.name == "boxed_duration")
+        .unwrap();
+    let description = param.type_description();
+    assert!(
+        description
+            .details()
+            .unwrap()
+            .starts_with("duration with unit"),
+        "{description:?}"
+    );
+    assert!(description.suffixes().is_some());
+
+    let json = config!(
+        "boxed_str": "boxed",
+        "arc_str": "arc",
+        "cow": "cow",
+        "separator": ";;",
+        "boxed_duration": "3 sec",
+    );
+    let err = test_deserialize::<WrappersConfig>(json.inner()).unwrap_err();
+    let err = err.first();
+    assert_eq!(err.path(), "separator");
+}
+
+#[test]
+fn deserializing_tuples() {
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TupleConfig {
+        pair: (String, u16),
+        #[config(with = Delimited::new(":"))]
+        delimited: (String, u16, bool),
+        #[config(default)]
+        routes: Vec<(String, Duration, ByteSize, bool)>,
+    }
+
+    let json = config!(
+        "pair": serde_json::json!(["localhost", 8080]),
+        "delimited": "localhost:3000:true",
+        "routes": serde_json::json!([["/", "1s", "1 KiB", false]]),
+    );
+    let config: TupleConfig = test_deserialize(json.inner()).unwrap();
+    assert_eq!(config.pair, ("localhost".to_owned(), 8080));
+    assert_eq!(config.delimited, ("localhost".to_owned(), 3000, true));
+    assert_eq!(
+        config.routes,
+        [(
+            "/".to_owned(),
+            Duration::from_secs(1),
+            SizeUnit::KiB * 1,
+            false
+        )]
+    );
+    let json = test_config_roundtrip(&config);
+    assert_eq!(
+        json["delimited"],
+        serde_json::json!(["localhost", 3000, true])
+    );
+
+    let mut env = Environment::from_iter(
+        "",
+        [("PAIR__JSON", "[\"local\",1]"), ("DELIMITED", "a:1:false")],
+    );
+    env.coerce_json().unwrap();
+    let config: TupleConfig = testing::test(env).unwrap();
+    assert_eq!(config.pair, ("local".to_owned(), 1));
+    assert_eq!(config.delimited, ("a".to_owned(), 1, false));
+
+    let json = config!("pair": ["localhost"], "delimited": "localhost:what:true");
+    let errors = test_deserialize::<TupleConfig>(json.inner()).unwrap_err();
+    let paths: HashSet<_> = errors.iter().map(ParseError::path).collect();
+    assert_eq!(paths, HashSet::from(["pair", "delimited.1"]));
+    let err = errors.iter().find(|err| err.path() == "pair").unwrap();
+    let err = err.inner().to_string();
+    assert!(err.contains("invalid length 1"), "{err}");
+}
+
+#[test]
+fn describing_tuples() {
+    #[derive(Debug, DescribeConfig)]
+    #[config(crate = crate)]
+    struct TupleConfig {
+        pair: (String, u16),
+    }
+
+    let param = &TupleConfig::DESCRIPTION.params[0];
+    let description = param.type_description();
+    assert_eq!(
+        description.details().unwrap(),
+        "2-element tuple [string, integer]"
+    );
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn deserializing_index_maps() {
+    use indexmap::{IndexMap, IndexSet};
+
+    use crate::de::Entries;
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct IndexConfig {
+        set: IndexSet<u64>,
+        #[config(with = Entries::WELL_KNOWN.delimited(",", "="))]
+        map: IndexMap<String, u64>,
+        #[config(default)]
+        ordered: IndexMap<String, u64>,
+    }
+
+    let json = config!(
+        "set": [3, 1, 2, 1],
+        "map": "z=1,a=2,m=3",
+        "ordered": serde_json::json!([["z", 1], ["a", "2"], ["m", 3]]),
+    );
+    let config: IndexConfig = test_deserialize(json.inner()).unwrap();
+    assert_eq!(config.set.iter().copied().collect::<Vec<_>>(), [3, 1, 2]);
+    let keys: Vec<_> = config.map.keys().map(String::as_str).collect();
+    assert_eq!(keys, ["z", "a", "m"]);
+    let ordered: Vec<_> = config
+        .ordered
+        .iter()
+        .map(|(k, &v)| (k.as_str(), v))
+        .collect();
+    assert_eq!(ordered, [("z", 1), ("a", 2), ("m", 3)]);
+    let json = test_config_roundtrip(&config);
+    assert_eq!(json["set"], serde_json::json!([3, 1, 2]));
+    assert_eq!(
+        json["ordered"],
+        serde_json::json!([["z", 1], ["a", 2], ["m", 3]])
+    );
+
+    let json = config!(
+        "set": [1],
+        "map": "a=1",
+        "ordered": serde_json::json!({ "z": 1, "a": 2 }),
+    );
+    let err = test_deserialize::<IndexConfig>(json.inner()).unwrap_err();
+    let err = err.first();
+    assert_eq!(err.path(), "ordered");
+    let inner = err.inner().to_string();
+    assert!(inner.starts_with("invalid type: map"), "{inner}");
+    assert!(inner.contains("don't preserve entry order"), "{inner}");
+
+    let json = config!("set": [1], "map": "a=1", "ordered": serde_json::json!([["z", 1, 2]]));
+    let err = test_deserialize::<IndexConfig>(json.inner()).unwrap_err();
+    let err = err.first();
+    assert_eq!(err.path(), "ordered.0");
+    let inner = err.inner().to_string();
+    assert!(inner.contains("[key, value] pair"), "{inner}");
+}
+
 #[test]
 fn error_parsing_array_from_string() {
     let json = config!("array": "4,what");
@@ -868,7 +1062,7 @@ fn multiple_errors_for_composed_deserializers() {
     let inner = err.inner().to_string();
     assert!(inner.contains("invalid digit"), "{inner}");
 
-    let json = config!("durations": serde_json::json!([5, "30us"]));
+    let json = config!("durations": serde_json::json!([5, "30 months"]));
     let errors = test_deserialize::<ComposedConfig>(json.inner()).unwrap_err();
     assert_eq!(errors.len(), 2);
     let err = errors
@@ -0,0 +1,324 @@
+use serde::{Deserialize, de::Error as DeError};
+use time::{
+    OffsetDateTime, Time, UtcOffset,
+    format_description::{BorrowedFormatItem, well_known::Rfc3339},
+    macros::format_description,
+};
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam, WellKnown, WellKnownOption},
+    error::ErrorWithOrigin,
+    metadata::{BasicTypes, ParamMetadata, TimeUnit, TypeDescription},
+    value::Value,
+};
+
+const TIME_FORMAT: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour]:[minute][optional [:[second][optional [.[subsecond]]]]]");
+const TIME_FORMAT_WITH_SUBSEC: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second].[subsecond]");
+const TIME_FORMAT_WITHOUT_SUBSEC: &[BorrowedFormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second]");
+const OFFSET_FORMAT: &[BorrowedFormatItem<'_>] = format_description!(
+    "[offset_hour sign:mandatory]:[offset_minute][optional [:[offset_second]]]"
+);
+const OFFSET_FORMAT_WITHOUT_SECONDS: &[BorrowedFormatItem<'_>] =
+    format_description!("[offset_hour sign:mandatory]:[offset_minute]");
+
+/// Type that has a canonical string presentation.
+trait TimeFormat: Sized {
+    const EXPECTING: &'static str;
+    const DETAILS: &'static str;
+
+    fn parse_str(s: &str) -> Result<Self, time::error::Parse>;
+
+    fn format_str(&self) -> String;
+}
+
+impl TimeFormat for OffsetDateTime {
+    const EXPECTING: &'static str = "RFC 3339 timestamp";
+    const DETAILS: &'static str = "RFC 3339 timestamp, e.g. '2025-01-01T12:00:00Z'";
+
+    fn parse_str(s: &str) -> Result<Self, time::error::Parse> {
+        Self::parse(s, &Rfc3339)
+    }
+
+    fn format_str(&self) -> String {
+        self.format(&Rfc3339)
+            .expect("timestamp is not representable in RFC 3339")
+    }
+}
+
+impl TimeFormat for Time {
+    const EXPECTING: &'static str = "time of day";
+    const DETAILS: &'static str = "time of day, e.g. '14:30:00'";
+
+    fn parse_str(s: &str) -> Result<Self, time::error::Parse> {
+        Self::parse(s, TIME_FORMAT)
+    }
+
+    fn format_str(&self) -> String {
+        let format = if self.nanosecond() == 0 {
+            TIME_FORMAT_WITHOUT_SUBSEC
+        } else {
+            TIME_FORMAT_WITH_SUBSEC
+        };
+        self.format(format).expect("failed formatting time")
+    }
+}
+
+impl TimeFormat for UtcOffset {
+    const EXPECTING: &'static str = "UTC offset";
+    const DETAILS: &'static str = "UTC offset, e.g. '+03:00' or 'Z'";
+
+    fn parse_str(s: &str) -> Result<Self, time::error::Parse> {
+        if s == "Z" || s == "z" {
+            return Ok(Self::UTC);
+        }
+        Self::parse(s, OFFSET_FORMAT)
+    }
+
+    fn format_str(&self) -> String {
+        let format = if self.seconds_past_minute() == 0 {
+            OFFSET_FORMAT_WITHOUT_SECONDS
+        } else {
+            OFFSET_FORMAT
+        };
+        self.format(format).expect("failed formatting UTC offset")
+    }
+}
+
+/// Deserializer for `time` types from their canonical string presentation.
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+#[derive(Debug)]
+pub struct TimeDeserializer;
+
+impl<T: TimeFormat> DeserializeParam<T> for TimeDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details(T::DETAILS);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<T, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type(T::EXPECTING));
+        };
+        T::parse_str(s.expose()).map_err(|err| deserializer.enrich_err(DeError::custom(err)))
+    }
+
+    fn serialize_param(&self, param: &T) -> serde_json::Value {
+        param.format_str().into()
+    }
+}
+
+/// Accepts an [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339) timestamp, e.g. `2025-01-01T12:00:00Z`
+/// or `2025-01-01T15:00:00+03:00`. The UTC offset is retained; the timestamp is serialized in the RFC 3339 format.
+///
+/// To deserialize a Unix timestamp instead, use [`TimeUnit`] as the deserializer.
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl WellKnown for OffsetDateTime {
+    type Deserializer = TimeDeserializer;
+    const DE: Self::Deserializer = TimeDeserializer;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl WellKnownOption for OffsetDateTime {}
+
+/// Accepts a time of day with optional seconds and fractional seconds, e.g. `14:30`, `14:30:00` or `14:30:00.250`.
+/// Serialized with seconds (and fractional seconds if they are non-zero).
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl WellKnown for Time {
+    type Deserializer = TimeDeserializer;
+    const DE: Self::Deserializer = TimeDeserializer;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl WellKnownOption for Time {}
+
+/// Accepts a UTC offset, e.g. `+03:00`, `-05:30` or `Z` (for UTC). Serialized in the `+03:00` format.
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl WellKnown for UtcOffset {
+    type Deserializer = TimeDeserializer;
+    const DE: Self::Deserializer = TimeDeserializer;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl WellKnownOption for UtcOffset {}
+
+/// Supports deserializing an [`OffsetDateTime`] from a Unix timestamp, with `self` being the unit of measurement
+/// (e.g., [`TimeUnit::Seconds`] or [`TimeUnit::Millis`]). Negative timestamps are supported.
+/// The deserialized timestamp has the UTC offset.
+///
+/// # Examples
+///
+/// ```
+/// # use time::{macros::datetime, OffsetDateTime};
+/// # use smart_config::{metadata::TimeUnit, testing, DescribeConfig, DeserializeConfig};
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = TimeUnit::Millis)]
+///     genesis_timestamp: OffsetDateTime,
+/// }
+///
+/// let source = smart_config::config!("genesis_timestamp": 1_735_732_800_000_i64);
+/// let config = testing::test::<TestConfig>(source)?;
+/// assert_eq!(config.genesis_timestamp, datetime!(2025-01-01 12:00 UTC));
+/// # anyhow::Ok(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl DeserializeParam<OffsetDateTime> for TimeUnit {
+    const EXPECTING: BasicTypes = BasicTypes::INTEGER;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("Unix timestamp")
+            .set_unit((*self).into());
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<OffsetDateTime, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let timestamp = i64::deserialize(deserializer)?;
+        let nanos = self.timestamp_to_nanos(timestamp);
+        OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|_| {
+            let plural = self.plural();
+            let err = DeError::custom(format!("timestamp {timestamp} {plural} is out of range"));
+            deserializer.enrich_err(err)
+        })
+    }
+
+    fn serialize_param(&self, param: &OffsetDateTime) -> serde_json::Value {
+        self.timestamp_from_nanos(param.unix_timestamp_nanos())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        OffsetDateTime, Time, UtcOffset,
+        macros::{datetime, offset, time},
+    };
+
+    use crate::{
+        DescribeConfig, DeserializeConfig, Environment, config,
+        metadata::{TimeUnit, UnitOfMeasurement},
+        testing::test,
+        testonly::test_config_roundtrip,
+    };
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        start: OffsetDateTime,
+        #[config(default_t = Time::MIDNIGHT)]
+        daily_at: Time,
+        #[config(default_t = UtcOffset::UTC)]
+        offset: UtcOffset,
+        #[config(default_t = OffsetDateTime::UNIX_EPOCH, with = TimeUnit::Seconds)]
+        genesis: OffsetDateTime,
+        #[config(default, with = TimeUnit::Millis)]
+        genesis_ms: Option<OffsetDateTime>,
+    }
+
+    #[test]
+    fn describing_timestamps() {
+        let params = TestConfig::DESCRIPTION.params;
+        let start = params.iter().find(|param| param.name == "start").unwrap();
+        let description = start.type_description();
+        assert_eq!(
+            description.details().unwrap(),
+            "RFC 3339 timestamp, e.g. '2025-01-01T12:00:00Z'"
+        );
+
+        let genesis = params
+            .iter()
+            .find(|param| param.name == "genesis_ms")
+            .unwrap();
+        let description = genesis.type_description();
+        assert_eq!(description.details().unwrap(), "Unix timestamp");
+        assert_eq!(
+            description.unit(),
+            Some(UnitOfMeasurement::Time(TimeUnit::Millis))
+        );
+    }
+
+    #[test]
+    fn deserializing_date_and_time() {
+        let json = config!(
+            "start": "2025-01-01T15:00:00+03:00",
+            "daily_at": "14:30",
+            "offset": "-05:30",
+            "genesis": 1_735_732_800,
+            "genesis_ms": -1_500,
+        );
+        let config = test::<TestConfig>(json).unwrap();
+        assert_eq!(config.start, datetime!(2025-01-01 15:00 +03:00));
+        assert_eq!(config.start.offset(), offset!(+03:00));
+        assert_eq!(config.daily_at, time!(14:30));
+        assert_eq!(config.offset, offset!(-05:30));
+        assert_eq!(config.genesis, datetime!(2025-01-01 12:00 UTC));
+        assert_eq!(
+            config.genesis_ms,
+            Some(datetime!(1969-12-31 23:59:58.5 UTC))
+        );
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["start"], "2025-01-01T15:00:00+03:00");
+        assert_eq!(json["daily_at"], "14:30:00");
+        assert_eq!(json["offset"], "-05:30");
+        assert_eq!(json["genesis"], 1_735_732_800);
+        assert_eq!(json["genesis_ms"], -1_500);
+    }
+
+    #[test]
+    fn deserializing_date_and_time_from_env() {
+        let env = Environment::from_iter(
+            "APP_",
+            [
+                ("APP_START", "2025-01-01T12:00:00Z"),
+                ("APP_DAILY_AT", "03:15:30.250"),
+                ("APP_OFFSET", "Z"),
+                ("APP_GENESIS", "1735732800"),
+            ],
+        );
+        let config = test::<TestConfig>(env).unwrap();
+        assert_eq!(config.start, config.genesis);
+        assert_eq!(config.daily_at, time!(03:15:30.250));
+        assert_eq!(config.offset, UtcOffset::UTC);
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["daily_at"], "03:15:30.25");
+    }
+
+    #[test]
+    fn date_and_time_errors() {
+        let json = config!("start": "2025-01-01");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        assert_eq!(err.first().path(), "start");
+
+        let json = config!("start": "2025-01-01T12:00:00Z", "daily_at": "25:00");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "daily_at");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("hour"), "{inner}");
+
+        let json = config!("start": "2025-01-01T12:00:00Z", "genesis": i64::MAX);
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "genesis");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("seconds is out of range"), "{inner}");
+    }
+}
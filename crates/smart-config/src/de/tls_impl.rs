@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam, WellKnown, WellKnownOption},
+    error::ErrorWithOrigin,
+    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    tls::{PemBlock, PemCertificates, PemInput, PemPrivateKey},
+    value::Value,
+};
+
+/// Deserializer for [`PemCertificates`] and [`PemPrivateKey`]. Accepts either inline PEM or a path to a PEM file.
+#[derive(Debug)]
+pub struct PemDeserializer;
+
+impl PemDeserializer {
+    fn read<T>(
+        ctx: &DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+        from_blocks: fn(Vec<PemBlock>, Option<PathBuf>) -> Result<T, serde_json::Error>,
+    ) -> Result<T, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("PEM string or path to a PEM file"));
+        };
+        let (input, blocks) = PemInput::read(s.expose(), deserializer.origin())
+            .map_err(|err| deserializer.enrich_err(err))?;
+        let path = match input {
+            PemInput::Inline => None,
+            PemInput::File(path) => Some(path),
+        };
+        from_blocks(blocks, path).map_err(|err| deserializer.enrich_err(err))
+    }
+
+    fn serialize_path(path: &Path) -> serde_json::Value {
+        serde_json::to_value(path).expect("failed serializing path")
+    }
+}
+
+impl DeserializeParam<PemCertificates> for PemDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("PEM-encoded certificates, or path to a PEM file");
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<PemCertificates, ErrorWithOrigin> {
+        Self::read(&ctx, param, PemCertificates::from_blocks)
+    }
+
+    fn serialize_param(&self, param: &PemCertificates) -> serde_json::Value {
+        match param.path() {
+            Some(path) => Self::serialize_path(path),
+            None => param.to_pem().into(),
+        }
+    }
+}
+
+impl DeserializeParam<PemPrivateKey> for PemDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("PEM-encoded private key, or path to a PEM file")
+            .set_secret();
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<PemPrivateKey, ErrorWithOrigin> {
+        Self::read(&ctx, param, PemPrivateKey::from_blocks)
+    }
+
+    fn serialize_param(&self, param: &PemPrivateKey) -> serde_json::Value {
+        match param.path() {
+            Some(path) => Self::serialize_path(path),
+            None => param.expose_pem().as_str().into(),
+        }
+    }
+}
+
+impl WellKnown for PemCertificates {
+    type Deserializer = PemDeserializer;
+    const DE: Self::Deserializer = PemDeserializer;
+}
+
+impl WellKnownOption for PemCertificates {}
+
+impl WellKnown for PemPrivateKey {
+    type Deserializer = PemDeserializer;
+    const DE: Self::Deserializer = PemDeserializer;
+}
+
+impl WellKnownOption for PemPrivateKey {}
@@ -0,0 +1,110 @@
+use serde::de::Error as DeError;
+use tracing_subscriber::EnvFilter;
+
+use crate::{
+    de::{DeserializeContext, DeserializeParam, WellKnown, WellKnownOption},
+    error::ErrorWithOrigin,
+    metadata::{BasicTypes, ParamMetadata, TypeDescription},
+    value::Value,
+};
+
+const DETAILS: &str = "tracing directives, e.g. 'info,my_crate=debug'";
+
+/// Deserializer for [`EnvFilter`]s. Directives are validated during deserialization.
+#[derive(Debug)]
+pub struct EnvFilterDeserializer;
+
+impl DeserializeParam<EnvFilter> for EnvFilterDeserializer {
+    const EXPECTING: BasicTypes = BasicTypes::STRING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details(DETAILS);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<EnvFilter, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let Value::String(s) = deserializer.value() else {
+            return Err(deserializer.invalid_type("tracing directives"));
+        };
+        EnvFilter::builder()
+            .parse(s.expose())
+            .map_err(|err| deserializer.enrich_err(DeError::custom(err)))
+    }
+
+    fn serialize_param(&self, param: &EnvFilter) -> serde_json::Value {
+        param.to_string().into()
+    }
+}
+
+/// Accepts comma-separated [filtering directives](tracing_subscriber::filter::EnvFilter#directives)
+/// in the `RUST_LOG` format, e.g. `info,my_crate=debug`. Directives are validated during deserialization,
+/// so that invalid directives are reported as a param deserialization error.
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing-subscriber")))]
+impl WellKnown for EnvFilter {
+    type Deserializer = EnvFilterDeserializer;
+    const DE: Self::Deserializer = EnvFilterDeserializer;
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "tracing-subscriber")))]
+impl WellKnownOption for EnvFilter {}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::EnvFilter;
+
+    use crate::{
+        DescribeConfig, DeserializeConfig, Environment, config,
+        de::{DeserializeParam, WellKnown},
+        testing::test,
+    };
+
+    #[derive(Debug, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        #[config(default)]
+        log_filter: Option<EnvFilter>,
+    }
+
+    #[test]
+    fn describing_filters() {
+        let param = &TestConfig::DESCRIPTION.params[0];
+        let description = param.type_description();
+        assert_eq!(
+            description.details().unwrap(),
+            "tracing directives, e.g. 'info,my_crate=debug'"
+        );
+    }
+
+    #[test]
+    fn deserializing_filters() {
+        let json = config!("log_filter": "warn,my_crate::api=debug");
+        let config = test::<TestConfig>(json).unwrap();
+        let filter = config.log_filter.unwrap();
+        let serialized = EnvFilter::DE.serialize_param(&filter);
+        let serialized = serialized.as_str().unwrap();
+        assert!(serialized.contains("my_crate::api=debug"), "{serialized}");
+        assert!(serialized.contains("warn"), "{serialized}");
+
+        let env = Environment::from_iter("", [("LOG_FILTER", "info")]);
+        let config = test::<TestConfig>(env).unwrap();
+        assert_eq!(config.log_filter.unwrap().to_string(), "info");
+
+        let config = test::<TestConfig>(config!()).unwrap();
+        assert!(config.log_filter.is_none());
+    }
+
+    #[test]
+    fn filter_errors() {
+        let json = config!("log_filter": "my_crate=loud");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "log_filter");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("error parsing level filter"), "{inner}");
+    }
+}
@@ -8,10 +8,16 @@ use serde::{
 };
 
 use crate::{
-    ByteSize, EtherAmount,
-    de::{CustomKnownOption, DeserializeContext, DeserializeParam, Optional, WellKnown},
+    Bandwidth, ByteSize, EtherAmount, Rate, Ratio, TokenAmount, TokenInt,
+    de::{
+        CustomKnownOption, DeserializeContext, DeserializeParam, Optional, WellKnown,
+        WellKnownOption,
+    },
     error::ErrorWithOrigin,
-    metadata::{BasicTypes, ParamMetadata, SizeUnit, TimeUnit, TypeDescription, TypeSuffixes},
+    metadata::{
+        BandwidthUnit, BasicTypes, ParamMetadata, RatioUnit, SizeUnit, TimeUnit, TokenUnit,
+        TypeDescription, TypeSuffixes, UnitOfMeasurement,
+    },
     utils::{Decimal, FromStrStart},
     value::Value,
 };
@@ -19,36 +25,51 @@ use crate::{
 impl TimeUnit {
     fn overflow_err(self, raw_val: Decimal) -> serde_json::Error {
         let plural = self.plural();
-        DeError::custom(format!(
-            "{raw_val} {plural} does not fit into `u64` when converted to milliseconds"
-        ))
+        DeError::custom(format!("{raw_val} {plural} does not fit into `Duration`"))
     }
 
+    /// Converts the value to a duration. The conversion is exact, except for sub-nanosecond parts,
+    /// which are rounded to the nearest nanosecond.
     fn into_duration(self, raw_value: Decimal) -> Result<Duration, serde_json::Error> {
-        let millis_in_unit = match self {
-            Self::Millis => Decimal::from(1),
-            Self::Seconds => Decimal::new(1, 3),
-            Self::Minutes => Decimal::new(60, 3),
-            Self::Hours => Decimal::new(3_600, 3),
-            Self::Days => Decimal::new(86_400, 3),
-            Self::Weeks => Decimal::new(7 * 86_400, 3),
+        const NANOS_IN_SEC: u128 = 1_000_000_000;
+
+        // Multiply in `u128` rather than using `Decimal::checked_mul()`, which rounds the product
+        // to `Decimal::MAX_PRECISION` significant digits. The product cannot overflow since `nanos_in_unit() < 2^64`.
+        let nanos = u128::from(raw_value.mantissa()) * u128::from(self.nanos_in_unit());
+        let exponent = raw_value.exponent();
+        let nanos = if exponent >= 0 {
+            10_u128
+                .checked_pow(exponent.unsigned_abs().into())
+                .and_then(|pow10| nanos.checked_mul(pow10))
+        } else {
+            Decimal::round_wide(nanos, exponent.unsigned_abs())
         };
-        let millis = raw_value
-            .checked_mul(millis_in_unit)
-            .ok_or_else(|| self.overflow_err(raw_value))?;
-        let millis = millis
-            .to_int()
-            .ok_or_else(|| self.overflow_err(raw_value))?;
-
-        u64::try_from(millis)
-            .map(Duration::from_millis)
-            .or_else(|_| {
-                // Try converting to seconds to cover a wider range of values. Losing precision
-                // in subsecond millis is not a concern for such large values.
-                let secs =
-                    u64::try_from(millis / 1_000).map_err(|_| self.overflow_err(raw_value))?;
-                Ok(Duration::from_secs(secs))
-            })
+        let nanos = nanos.ok_or_else(|| self.overflow_err(raw_value))?;
+        let secs = u64::try_from(nanos / NANOS_IN_SEC).map_err(|_| self.overflow_err(raw_value))?;
+        #[allow(clippy::cast_possible_truncation)]
+        // Cannot happen since the value is lesser than 10^9
+        let subsec_nanos = (nanos % NANOS_IN_SEC) as u32;
+        Ok(Duration::new(secs, subsec_nanos))
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl TimeUnit {
+    /// Converts a Unix timestamp measured in this unit to nanoseconds.
+    pub(crate) fn timestamp_to_nanos(self, timestamp: i64) -> i128 {
+        i128::from(timestamp) * i128::from(self.nanos_in_unit())
+    }
+
+    /// Converts a Unix timestamp in nanoseconds to this unit, rounding down.
+    ///
+    /// A timestamp not fitting into `i64` (e.g., a far-future timestamp in nanoseconds) is serialized as a string
+    /// so that serialization doesn't panic; deserializing such a value will return an out-of-range error.
+    pub(crate) fn timestamp_from_nanos(self, nanos: i128) -> serde_json::Value {
+        let timestamp = nanos.div_euclid(i128::from(self.nanos_in_unit()));
+        match i64::try_from(timestamp) {
+            Ok(timestamp) => timestamp.into(),
+            Err(_) => timestamp.to_string().into(),
+        }
     }
 }
 
@@ -94,6 +115,8 @@ impl DeserializeParam<Duration> for TimeUnit {
 
     fn serialize_param(&self, param: &Duration) -> serde_json::Value {
         match self {
+            Self::Nanos => serde_json::to_value(param.as_nanos()).unwrap(),
+            Self::Micros => serde_json::to_value(param.as_micros()).unwrap(),
             Self::Millis => serde_json::to_value(param.as_millis()).unwrap(),
             Self::Seconds => param.as_secs().into(),
             Self::Minutes => (param.as_secs() / 60).into(),
@@ -150,26 +173,37 @@ impl DeserializeParam<ByteSize> for SizeUnit {
     }
 
     fn serialize_param(&self, param: &ByteSize) -> serde_json::Value {
-        match self {
-            Self::Bytes => param.0.into(),
-            Self::KiB => (param.0 >> 10).into(),
-            Self::MiB => (param.0 >> 20).into(),
-            Self::GiB => (param.0 >> 30).into(),
-        }
+        (param.0 / self.value_in_unit()).into()
     }
 }
 
-/// Default deserializer for [`Duration`]s, [`ByteSize`]s and [`EtherAmount`]s.
+/// Default deserializer for [`Duration`]s, [`ByteSize`]s, [`EtherAmount`]s, [`Rate`]s, [`Bandwidth`]s and [`Ratio`]s.
 ///
 /// Values can be deserialized from 2 formats:
 ///
 /// - String consisting of a number, optional whitespace and a unit, such as "30 secs" or "500ms" (for `Duration`) /
 ///   "4 MiB" (for `ByteSize`). The unit must correspond to a [`TimeUnit`] / [`SizeUnit`] / [`EtherUnit`](crate::metadata::EtherUnit).
 ///   `Duration`s and `EtherAmount`s support decimal numbers, such as `3.5 sec` or `1.5e-5 ether`; `ByteSize`s only support integers.
+/// - For `Duration`s, additionally a compound string with units in descending order, such as "1h 30min" or "1s500ms",
+///   or an [ISO 8601 duration](https://en.wikipedia.org/wiki/ISO_8601#Durations), such as "PT1H30M" or "P1DT12H".
+///   ISO 8601 durations with years or months are not supported since these units have variable length.
 /// - Object with a single key and a numeric value, such as `{ "hours": 3 }` (for `Duration`) / `{ "kb": 512 }` (for `SizeUnit`).
 ///   To prevent precision loss, decimal values may be enclosed in a string (e.g., `{ "ether": "0.000123456" }`).
+/// - [`Rate`]s and [`Bandwidth`]s use a time unit after `/` or `per`, such as "500/s" or "10 MiB per second",
+///   and `per_` in the object form, such as `{ "per_min": 600 }` or `{ "mib_per_sec": 10 }`.
+/// - [`Ratio`]s can additionally be specified as a plain number (e.g., 0.75); supported units are
+///   percent (e.g., "75%" or `{ "percent": 75 }`) and basis points (e.g., "30 bps").
 ///
-/// Thanks to nesting of object params, the second approach automatically means that a duration can be parsed
+/// Byte sizes support binary units from kibibytes to pebibytes (e.g., "512 KiB" or "2 TiB") and decimal SI units
+/// from kilobytes to terabytes (e.g., "500 MB" = 500,000,000 bytes). Units are case-insensitive, so "mb" is
+/// always a decimal megabyte; use "mib" for a binary one. (Previously, "kb", "mb", "gb" and "tb" denoted binary units;
+/// this is a breaking change.)
+///
+/// Durations support units from nanoseconds to weeks and are parsed without precision loss, e.g. "1.5ms" or "250us".
+/// When serialized, durations are represented with a single unit (e.g., "90min" or "1500ms") or as a compound string
+/// (e.g., "1d 1ns"), whichever is shorter; both representations are exact.
+///
+/// Thanks to nesting of object params, the last approach automatically means that a duration can be parsed
 /// from a param name suffixed with a unit. For example, a value `latency_ms: 500` for parameter `latency`
 /// will be recognized as 500 ms.
 ///
@@ -197,10 +231,18 @@ impl DeserializeParam<ByteSize> for SizeUnit {
 /// assert_eq!(config.disk, ByteSize(256 << 20));
 /// assert_eq!(config.fee, EtherAmount(100_000_000_000));
 ///
+/// // Parsing compound and ISO 8601 durations
+/// let source = smart_config::config!("latency": "1h 30min", "disk": "1 GiB");
+/// let config: TestConfig = testing::test(source)?;
+/// assert_eq!(config.latency, Duration::from_mins(90));
+/// let source = smart_config::config!("latency": "PT1M30.5S", "disk": "1 GiB");
+/// let config: TestConfig = testing::test(source)?;
+/// assert_eq!(config.latency, Duration::from_millis(90_500));
+///
 /// // Parsing from an object
 /// let source = smart_config::config!(
 ///     "latency": serde_json::json!({ "hours": 3.5 }),
-///     "disk": serde_json::json!({ "gigabytes": 2 }),
+///     "disk": serde_json::json!({ "gibibytes": 2 }),
 ///     "fee": serde_json::json!({ "ether": "0.000125" }),
 /// );
 /// let config: TestConfig = testing::test(source)?;
@@ -216,7 +258,7 @@ impl DeserializeParam<ByteSize> for SizeUnit {
 /// ]);
 /// let config: TestConfig = testing::test(source)?;
 /// assert_eq!(config.latency, Duration::from_millis(1_500));
-/// assert_eq!(config.disk, ByteSize(10 << 30));
+/// assert_eq!(config.disk, ByteSize(10_000_000_000)); // decimal SI unit
 /// assert_eq!(config.fee, EtherAmount(15_000_000_000_000));
 /// # anyhow::Ok(())
 /// ```
@@ -283,7 +325,7 @@ trait EnumWithUnit: FromStr<Err = serde_json::Error> {
     const EXPECTING: &'static str;
     const VARIANTS: &'static [&'static str];
 
-    fn extract_variant(unit: &str) -> Option<fn(Self::Value) -> Self>;
+    fn extract_variant(unit: &str) -> Option<impl Fn(Self::Value) -> Self>;
 
     fn parse<E: de::Error>(unit: &str, value: Self::Value) -> Result<Self, E> {
         let variant_mapper = Self::extract_variant(unit)
@@ -360,19 +402,23 @@ impl<'v, T: EnumWithUnit> de::Visitor<'v> for EnumVisitor<Option<T>> {
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
 enum RawDuration {
+    Nanos(Decimal),
+    Micros(Decimal),
     Millis(Decimal),
     Seconds(Decimal),
     Minutes(Decimal),
     Hours(Decimal),
     Days(Decimal),
     Weeks(Decimal),
+    /// Compound or ISO 8601 duration.
+    Exact(Duration),
 }
 
 macro_rules! impl_enum_with_unit {
     ($($($name:tt)|+ => $func:expr,)+) => {
         const VARIANTS: &'static [&'static str] = &[$($($name,)+)+];
 
-        fn extract_variant(unit: &str) -> Option<fn(Self::Value) -> Self> {
+        fn extract_variant(unit: &str) -> Option<impl Fn(Self::Value) -> Self> {
             Some(match unit {
                 $($($name )|+ => $func,)+
                 _ => return None,
@@ -387,6 +433,8 @@ impl EnumWithUnit for RawDuration {
     const EXPECTING: &'static str = "value with unit, like '10 ms'";
 
     impl_enum_with_unit!(
+        "nanoseconds" | "nanos" | "ns" => Self::Nanos,
+        "microseconds" | "micros" | "us" | "\u{b5}s" | "\u{3bc}s" => Self::Micros,
         "milliseconds" | "millis" | "ms" => Self::Millis,
         "seconds" | "second" | "secs" | "sec" | "s" => Self::Seconds,
         "minutes" | "minute" | "mins" | "min" | "m" => Self::Minutes,
@@ -396,11 +444,148 @@ impl EnumWithUnit for RawDuration {
     );
 }
 
+impl RawDuration {
+    const COMPOUND_ORDER_ERR: &'static str =
+        "units in a compound duration must be in descending order without repetitions";
+
+    fn into_parts(self) -> Result<(TimeUnit, Decimal), Duration> {
+        Ok(match self {
+            Self::Nanos(val) => (TimeUnit::Nanos, val),
+            Self::Micros(val) => (TimeUnit::Micros, val),
+            Self::Millis(val) => (TimeUnit::Millis, val),
+            Self::Seconds(val) => (TimeUnit::Seconds, val),
+            Self::Minutes(val) => (TimeUnit::Minutes, val),
+            Self::Hours(val) => (TimeUnit::Hours, val),
+            Self::Days(val) => (TimeUnit::Days, val),
+            Self::Weeks(val) => (TimeUnit::Weeks, val),
+            Self::Exact(duration) => return Err(duration),
+        })
+    }
+
+    /// Parses a sum of durations with units, like `1h 30min` or `1d2h`. Units must be in descending order.
+    fn parse_compound(s: &str) -> Result<Self, serde_json::Error> {
+        let invalid_type = || DeError::invalid_type(de::Unexpected::Str(s), &Self::EXPECTING);
+
+        let mut segments = vec![];
+        let mut rem = s;
+        while !rem.is_empty() {
+            let (value, unit_and_rem) = Decimal::from_str_start(rem)?;
+            let value = value.ok_or_else(invalid_type)?;
+            let unit_len = unit_and_rem
+                .find(|ch: char| ch.is_ascii_digit() || ch == '.')
+                .unwrap_or(unit_and_rem.len());
+            let unit = unit_and_rem[..unit_len].trim();
+            if unit.is_empty() {
+                return Err(invalid_type());
+            }
+            segments.push((value, unit));
+            rem = &unit_and_rem[unit_len..];
+        }
+
+        match segments.as_slice() {
+            [] => Err(invalid_type()),
+            [(value, unit)] => Self::parse(unit, *value),
+            _ => {
+                let mut total = Duration::ZERO;
+                let mut prev_unit = None::<TimeUnit>;
+                for (value, unit) in segments {
+                    let (unit, value) = Self::parse::<serde_json::Error>(unit, value)?
+                        .into_parts()
+                        .expect("parsed from unit");
+                    if prev_unit.is_some_and(|prev| Duration::from(prev) <= Duration::from(unit)) {
+                        return Err(DeError::custom(Self::COMPOUND_ORDER_ERR));
+                    }
+                    prev_unit = Some(unit);
+                    total = total
+                        .checked_add(unit.into_duration(value)?)
+                        .ok_or_else(|| {
+                            DeError::custom(format!("{s} does not fit into `Duration`"))
+                        })?;
+                }
+                Ok(Self::Exact(total))
+            }
+        }
+    }
+
+    /// Parses an ISO 8601 duration, like `PT1H30M` or `P1DT12H`. Years and months are not supported
+    /// because they have variable length.
+    fn parse_iso8601(s: &str) -> Result<Self, serde_json::Error> {
+        let invalid_value =
+            || DeError::invalid_value(de::Unexpected::Str(s), &"ISO 8601 duration, like 'PT1H30M'");
+
+        let mut rem = s.strip_prefix('P').ok_or_else(invalid_value)?;
+        let mut in_time_part = false;
+        let mut has_components = false;
+        let mut total = Duration::ZERO;
+        let mut prev_unit = None::<TimeUnit>;
+        while !rem.is_empty() {
+            if let Some(time_rem) = rem.strip_prefix('T') {
+                if in_time_part || time_rem.is_empty() {
+                    return Err(invalid_value());
+                }
+                in_time_part = true;
+                rem = time_rem;
+                continue;
+            }
+
+            let (value, designator_and_rem) = Decimal::from_str_start(rem)?;
+            let value = value.ok_or_else(invalid_value)?;
+            let designator = designator_and_rem
+                .chars()
+                .next()
+                .ok_or_else(invalid_value)?;
+            let unit = match (in_time_part, designator) {
+                (false, 'W') => TimeUnit::Weeks,
+                (false, 'D') => TimeUnit::Days,
+                (true, 'H') => TimeUnit::Hours,
+                (true, 'M') => TimeUnit::Minutes,
+                (true, 'S') => TimeUnit::Seconds,
+                (false, 'Y' | 'M') => {
+                    return Err(DeError::custom(
+                        "years and months are not supported in ISO 8601 durations because they have variable length",
+                    ));
+                }
+                _ => return Err(invalid_value()),
+            };
+            if prev_unit.is_some_and(|prev| Duration::from(prev) <= Duration::from(unit)) {
+                return Err(invalid_value());
+            }
+            prev_unit = Some(unit);
+            has_components = true;
+            total = total
+                .checked_add(unit.into_duration(value)?)
+                .ok_or_else(|| DeError::custom(format!("{s} does not fit into `Duration`")))?;
+            rem = &designator_and_rem[designator.len_utf8()..];
+        }
+
+        if has_components {
+            Ok(Self::Exact(total))
+        } else {
+            Err(invalid_value())
+        }
+    }
+}
+
 impl FromStr for RawDuration {
     type Err = serde_json::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::from_unit_str(s, false)
+        if s.starts_with('P') {
+            Self::parse_iso8601(s)
+        } else {
+            Self::parse_compound(s)
+        }
+    }
+}
+
+impl TimeUnit {
+    /// Parses a time unit from its name, e.g. `sec` or `hours`. Accepts the same names as the duration parser.
+    fn from_unit_name(unit: &str) -> Option<Self> {
+        let variant = RawDuration::extract_variant(unit)?;
+        variant(Decimal::ZERO)
+            .into_parts()
+            .ok()
+            .map(|(unit, _)| unit)
     }
 }
 
@@ -408,15 +593,57 @@ impl TryFrom<RawDuration> for Duration {
     type Error = serde_json::Error;
 
     fn try_from(value: RawDuration) -> Result<Self, Self::Error> {
-        let (unit, raw_value) = match value {
-            RawDuration::Millis(val) => (TimeUnit::Millis, val),
-            RawDuration::Seconds(val) => (TimeUnit::Seconds, val),
-            RawDuration::Minutes(val) => (TimeUnit::Minutes, val),
-            RawDuration::Hours(val) => (TimeUnit::Hours, val),
-            RawDuration::Days(val) => (TimeUnit::Days, val),
-            RawDuration::Weeks(val) => (TimeUnit::Weeks, val),
-        };
-        unit.into_duration(raw_value)
+        match value.into_parts() {
+            Ok((unit, raw_value)) => unit.into_duration(raw_value),
+            Err(duration) => Ok(duration),
+        }
+    }
+}
+
+impl WithUnit {
+    /// Units used for serializing durations, in descending order.
+    const SERIALIZED_TIME_UNITS: [TimeUnit; 8] = [
+        TimeUnit::Weeks,
+        TimeUnit::Days,
+        TimeUnit::Hours,
+        TimeUnit::Minutes,
+        TimeUnit::Seconds,
+        TimeUnit::Millis,
+        TimeUnit::Micros,
+        TimeUnit::Nanos,
+    ];
+
+    /// Serializes a non-zero duration either with a single unit (e.g., `90min`), or as a compound duration (e.g., `1d 1ns`),
+    /// whichever is shorter. Both forms are exact and can be parsed back.
+    fn serialize_duration(duration: Duration) -> String {
+        let nanos = duration.as_nanos();
+        let single_unit = Self::SERIALIZED_TIME_UNITS
+            .into_iter()
+            .find(|unit| nanos.is_multiple_of(unit.nanos_in_unit().into()))
+            .unwrap(); // `TimeUnit::Nanos` always matches
+        let single_unit_value = nanos / u128::from(single_unit.nanos_in_unit());
+
+        let mut rem = nanos;
+        let mut compound_parts = vec![];
+        for unit in Self::SERIALIZED_TIME_UNITS {
+            let nanos_in_unit = u128::from(unit.nanos_in_unit());
+            if rem >= nanos_in_unit {
+                compound_parts.push(format!("{}{}", rem / nanos_in_unit, unit.suffix()));
+                rem %= nanos_in_unit;
+            }
+        }
+        let compound_string = compound_parts.join(" ");
+
+        // Large values in a single unit (> u64::MAX) cannot be parsed back without precision loss.
+        if u64::try_from(single_unit_value).is_err() {
+            return compound_string;
+        }
+        let single_unit_string = format!("{single_unit_value}{}", single_unit.suffix());
+        if compound_string.len() < single_unit_string.len() {
+            compound_string
+        } else {
+            single_unit_string
+        }
     }
 }
 
@@ -424,7 +651,9 @@ impl DeserializeParam<Duration> for WithUnit {
     const EXPECTING: BasicTypes = Self::EXPECTED_TYPES;
 
     fn describe(&self, description: &mut TypeDescription) {
-        description.set_details("duration with unit, or object with single unit key");
+        description.set_details(
+            "duration with unit, compound or ISO 8601 duration, or object with single unit key",
+        );
         description.set_suffixes(TypeSuffixes::DurationUnits);
     }
 
@@ -441,24 +670,7 @@ impl DeserializeParam<Duration> for WithUnit {
             // Special case to produce a more "expected" string.
             return "0s".into();
         }
-
-        let duration_string = if param.subsec_millis() != 0 {
-            format!("{}ms", param.as_millis())
-        } else {
-            let seconds = param.as_secs();
-            if !seconds.is_multiple_of(60) {
-                format!("{seconds}s")
-            } else if !seconds.is_multiple_of(3_600) {
-                format!("{}min", seconds / 60)
-            } else if !seconds.is_multiple_of(86_400) {
-                format!("{}h", seconds / 3_600)
-            } else if !seconds.is_multiple_of(86_400 * 7) {
-                format!("{}d", seconds / 86_400)
-            } else {
-                format!("{}w", seconds / (86_400 * 7))
-            }
-        };
-        duration_string.into()
+        Self::serialize_duration(*param).into()
     }
 }
 
@@ -511,21 +723,33 @@ impl_well_known_with_unit!(Duration);
 #[cfg_attr(test, derive(PartialEq))]
 enum RawByteSize {
     Bytes(u64),
+    Kibibytes(u64),
+    Mebibytes(u64),
+    Gibibytes(u64),
+    Tebibytes(u64),
+    Pebibytes(u64),
     Kilobytes(u64),
     Megabytes(u64),
     Gigabytes(u64),
+    Terabytes(u64),
 }
 
 impl EnumWithUnit for RawByteSize {
     type Value = u64;
 
-    const EXPECTING: &'static str = "value with unit, like '32 MB'";
+    const EXPECTING: &'static str = "value with unit, like '32 MiB' or '500 MB'";
 
     impl_enum_with_unit!(
         "bytes" | "b" => Self::Bytes,
-        "kilobytes" | "kb" | "kib" => Self::Kilobytes,
-        "megabytes" | "mb" | "mib" => Self::Megabytes,
-        "gigabytes" | "gb" | "gib" => Self::Gigabytes,
+        "kibibytes" | "kib" => Self::Kibibytes,
+        "mebibytes" | "mib" => Self::Mebibytes,
+        "gibibytes" | "gib" => Self::Gibibytes,
+        "tebibytes" | "tib" => Self::Tebibytes,
+        "pebibytes" | "pib" => Self::Pebibytes,
+        "kilobytes" | "kb" => Self::Kilobytes,
+        "megabytes" | "mb" => Self::Megabytes,
+        "gigabytes" | "gb" => Self::Gigabytes,
+        "terabytes" | "tb" => Self::Terabytes,
     );
 }
 
@@ -535,9 +759,15 @@ impl TryFrom<RawByteSize> for ByteSize {
     fn try_from(value: RawByteSize) -> Result<Self, Self::Error> {
         let (unit, raw_value) = match value {
             RawByteSize::Bytes(val) => (SizeUnit::Bytes, val),
-            RawByteSize::Kilobytes(val) => (SizeUnit::KiB, val),
-            RawByteSize::Megabytes(val) => (SizeUnit::MiB, val),
-            RawByteSize::Gigabytes(val) => (SizeUnit::GiB, val),
+            RawByteSize::Kibibytes(val) => (SizeUnit::KiB, val),
+            RawByteSize::Mebibytes(val) => (SizeUnit::MiB, val),
+            RawByteSize::Gibibytes(val) => (SizeUnit::GiB, val),
+            RawByteSize::Tebibytes(val) => (SizeUnit::TiB, val),
+            RawByteSize::Pebibytes(val) => (SizeUnit::PiB, val),
+            RawByteSize::Kilobytes(val) => (SizeUnit::KB, val),
+            RawByteSize::Megabytes(val) => (SizeUnit::MB, val),
+            RawByteSize::Gigabytes(val) => (SizeUnit::GB, val),
+            RawByteSize::Terabytes(val) => (SizeUnit::TB, val),
         };
         ByteSize::checked(raw_value, unit).ok_or_else(|| {
             DeError::custom(format!(
@@ -621,6 +851,12 @@ impl TypeSuffixes {
                 let suffix = suffix.strip_prefix("in_").unwrap_or(suffix);
                 RawEtherAmount::VARIANTS.contains(&suffix)
             }
+            Self::RateUnits => RawRate::extract_variant(suffix).is_some(),
+            Self::BandwidthUnits => RawBandwidth::extract_variant(suffix).is_some(),
+            Self::RatioUnits => {
+                let suffix = suffix.strip_prefix("in_").unwrap_or(suffix);
+                RawRatio::VARIANTS.contains(&suffix)
+            }
         }
     }
 }
@@ -654,7 +890,7 @@ impl TryFrom<RawEtherAmount> for EtherAmount {
             RawEtherAmount::Gwei(val) => (9, val),
             RawEtherAmount::Ether(val) => (18, val),
         };
-        let value = raw_value.scale(scale)?;
+        let value = raw_value.scale_to_int(scale)?;
         Ok(Self(value))
     }
 }
@@ -663,6 +899,529 @@ impl_deserialize_param!(EtherAmount, raw: RawEtherAmount, name: "amount", units:
 impl_deserialize_opt_param!(EtherAmount => RawEtherAmount);
 impl_well_known_with_unit!(EtherAmount);
 
+/// Supports deserializing a [`Rate`] from a number, with `self` being the time unit.
+///
+/// Strings with a unit (e.g., `500/s`) are supported as well. On serialization, a rate is output as a number in `self` unit
+/// if this is exact, and as a string with a unit otherwise (e.g., `500/s` cannot be represented as an integer count per millisecond).
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{metadata::TimeUnit, DescribeConfig, DeserializeConfig, Rate};
+/// use smart_config::testing;
+///
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = TimeUnit::Seconds)]
+///     requests_per_sec: Rate,
+/// }
+///
+/// let source = smart_config::config!("requests_per_sec": 500);
+/// let config = testing::test::<TestConfig>(source)?;
+/// assert_eq!(config.requests_per_sec, Rate::new(500, TimeUnit::Seconds));
+/// # anyhow::Ok(())
+/// ```
+impl DeserializeParam<Rate> for TimeUnit {
+    const EXPECTING: BasicTypes = BasicTypes::INTEGER.or(BasicTypes::STRING);
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("rate")
+            .set_unit(UnitOfMeasurement::Rate(*self));
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Rate, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        if let Value::String(_) = deserializer.value() {
+            return WithUnit::deserialize::<RawRate, _>(&ctx, param);
+        }
+        let count = u64::deserialize(deserializer)?;
+        Ok(Rate::new(count, *self))
+    }
+
+    fn serialize_param(&self, param: &Rate) -> serde_json::Value {
+        let scaled_count = u128::from(param.count) * u128::from(self.nanos_in_unit());
+        let per_nanos = u128::from(param.per.nanos_in_unit());
+        if scaled_count.is_multiple_of(per_nanos) {
+            serde_json::to_value(scaled_count / per_nanos).unwrap()
+        } else {
+            param.to_string().into()
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+struct RawRate {
+    count: u64,
+    per: TimeUnit,
+}
+
+impl RawRate {
+    /// Strips the `per_` / `per ` / `/` prefix from a rate unit, e.g. `per_sec` or `/s`.
+    fn strip_per(unit: &str) -> Option<&str> {
+        let time_unit = if let Some(rest) = unit.strip_prefix('/') {
+            rest
+        } else {
+            let rest = unit.strip_prefix("per")?;
+            rest.strip_prefix(['_', ' '])?
+        };
+        Some(time_unit.trim_start())
+    }
+}
+
+impl EnumWithUnit for RawRate {
+    type Value = u64;
+
+    const EXPECTING: &'static str = "value with unit, like '500 per second' or '500/s'";
+    // Representative variants; the time unit may use any name supported for durations.
+    const VARIANTS: &'static [&'static str] = &[
+        "per_ns", "per_us", "per_ms", "per_sec", "per_min", "per_hour", "per_day", "per_week",
+    ];
+
+    fn extract_variant(unit: &str) -> Option<impl Fn(Self::Value) -> Self> {
+        let per = TimeUnit::from_unit_name(Self::strip_per(unit)?)?;
+        Some(move |count| Self { count, per })
+    }
+}
+
+impl TryFrom<RawRate> for Rate {
+    type Error = serde_json::Error;
+
+    fn try_from(value: RawRate) -> Result<Self, Self::Error> {
+        Ok(Self::new(value.count, value.per))
+    }
+}
+
+impl_deserialize_param!(Rate, raw: RawRate, name: "rate", units: RateUnits);
+impl_deserialize_opt_param!(Rate => RawRate);
+impl_well_known_with_unit!(Rate);
+
+/// Supports deserializing a [`Bandwidth`] from a number, with `self` being the unit of measurement.
+///
+/// Similar to [`Rate`]s, strings with a unit (e.g., `10 MiB/s`) are supported as well, and a bandwidth is serialized
+/// as a string with a unit if it cannot be exactly represented as a number in `self` unit.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{metadata::{BandwidthUnit, SizeUnit, TimeUnit}, DescribeConfig, DeserializeConfig, Bandwidth};
+/// use smart_config::testing;
+///
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = BandwidthUnit::new(SizeUnit::MiB, TimeUnit::Seconds))]
+///     max_upload: Bandwidth,
+/// }
+///
+/// let source = smart_config::config!("max_upload": 10);
+/// let config = testing::test::<TestConfig>(source)?;
+/// assert_eq!(config.max_upload, Bandwidth::new(10 * SizeUnit::MiB, TimeUnit::Seconds));
+/// # anyhow::Ok(())
+/// ```
+impl DeserializeParam<Bandwidth> for BandwidthUnit {
+    const EXPECTING: BasicTypes = BasicTypes::INTEGER.or(BasicTypes::STRING);
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description
+            .set_details("bandwidth")
+            .set_unit((*self).into());
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Bandwidth, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        if let Value::String(_) = deserializer.value() {
+            return WithUnit::deserialize::<RawBandwidth, _>(&ctx, param);
+        }
+        let raw_value = u64::deserialize(deserializer)?;
+        let size = ByteSize::checked(raw_value, self.size).ok_or_else(|| {
+            let err = DeError::custom(format!(
+                "{raw_value} {unit} does not fit into `u64`",
+                unit = self.size.as_str()
+            ));
+            deserializer.enrich_err(err)
+        })?;
+        Ok(Bandwidth::new(size, self.per))
+    }
+
+    fn serialize_param(&self, param: &Bandwidth) -> serde_json::Value {
+        let scaled_size = u128::from(param.size.0) * u128::from(self.per.nanos_in_unit());
+        let divisor = u128::from(param.per.nanos_in_unit()) * u128::from(self.size.value_in_unit());
+        if scaled_size.is_multiple_of(divisor) {
+            serde_json::to_value(scaled_size / divisor).unwrap()
+        } else {
+            param.to_string().into()
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+struct RawBandwidth {
+    size: RawByteSize,
+    per: TimeUnit,
+}
+
+impl EnumWithUnit for RawBandwidth {
+    type Value = u64;
+
+    const EXPECTING: &'static str = "value with unit, like '10 MiB/s' or '10 MiB per second'";
+    // Representative variants; the size and time units may use any names supported for byte sizes and durations.
+    const VARIANTS: &'static [&'static str] = &[
+        "b_per_sec",
+        "kib_per_sec",
+        "mib_per_sec",
+        "gib_per_sec",
+        "mib_per_min",
+    ];
+
+    fn extract_variant(unit: &str) -> Option<impl Fn(Self::Value) -> Self> {
+        let (size_unit, time_unit) = ["/", " per ", "_per_"]
+            .into_iter()
+            .find_map(|separator| unit.split_once(separator))?;
+        let size_variant = RawByteSize::extract_variant(size_unit.trim_end())?;
+        let per = TimeUnit::from_unit_name(time_unit.trim_start())?;
+        Some(move |value| Self {
+            size: size_variant(value),
+            per,
+        })
+    }
+}
+
+impl TryFrom<RawBandwidth> for Bandwidth {
+    type Error = serde_json::Error;
+
+    fn try_from(value: RawBandwidth) -> Result<Self, Self::Error> {
+        Ok(Self::new(value.size.try_into()?, value.per))
+    }
+}
+
+impl_deserialize_param!(Bandwidth, raw: RawBandwidth, name: "bandwidth", units: BandwidthUnits);
+impl_deserialize_opt_param!(Bandwidth => RawBandwidth);
+impl_well_known_with_unit!(Bandwidth);
+
+/// Supports deserializing a [`Ratio`] from a number, with `self` being the unit of measurement.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{metadata::RatioUnit, DescribeConfig, DeserializeConfig, Ratio};
+/// use smart_config::testing;
+///
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = RatioUnit::Percent)]
+///     sample_percent: Ratio,
+/// }
+///
+/// let source = smart_config::config!("sample_percent": 12.5);
+/// let config = testing::test::<TestConfig>(source)?;
+/// assert_eq!(config.sample_percent, Ratio::from_basis_points(1_250));
+/// # anyhow::Ok(())
+/// ```
+impl DeserializeParam<Ratio> for RatioUnit {
+    const EXPECTING: BasicTypes = BasicTypes::INTEGER.or(BasicTypes::FLOAT);
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("ratio").set_unit((*self).into());
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Ratio, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let raw_value = Decimal::deserialize(deserializer)?;
+        self.into_ratio(raw_value)
+            .map_err(|err| deserializer.enrich_err(err))
+    }
+
+    fn serialize_param(&self, param: &Ratio) -> serde_json::Value {
+        // Dividing by a power of 10 may only fail on exponent overflow; fall back to a string in this case.
+        match param.0.checked_mul(Decimal::new(1, -self.exponent())) {
+            Some(value) => serde_json::to_value(value).unwrap(),
+            None => param.to_string().into(),
+        }
+    }
+}
+
+impl RatioUnit {
+    fn into_ratio(self, raw_value: Decimal) -> Result<Ratio, serde_json::Error> {
+        raw_value
+            .checked_mul(Decimal::new(1, self.exponent()))
+            .map(Ratio)
+            .ok_or_else(|| DeError::custom(format!("{raw_value} {self} does not fit into a ratio")))
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(test, derive(PartialEq))]
+enum RawRatio {
+    Ratio(Decimal),
+    Percent(Decimal),
+    BasisPoints(Decimal),
+}
+
+impl EnumWithUnit for RawRatio {
+    type Value = Decimal;
+
+    const EXPECTING: &'static str = "ratio, or value with unit, like '75%' or '30 bps'";
+
+    impl_enum_with_unit!(
+        "ratio" => Self::Ratio,
+        "percent" | "pct" | "%" => Self::Percent,
+        "basis_points" | "bps" => Self::BasisPoints,
+    );
+}
+
+impl<'de> Deserialize<'de> for RawRatio {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_enum("RawRatio", Self::VARIANTS, EnumVisitor(PhantomData::<Self>))
+    }
+}
+
+impl FromStr for RawRatio {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse::<Decimal>() {
+            return Ok(Self::Ratio(value));
+        }
+        Self::from_unit_str(s, true)
+    }
+}
+
+impl TryFrom<RawRatio> for Ratio {
+    type Error = serde_json::Error;
+
+    fn try_from(value: RawRatio) -> Result<Self, Self::Error> {
+        let (unit, raw_value) = match value {
+            RawRatio::Ratio(val) => (RatioUnit::Ratio, val),
+            RawRatio::Percent(val) => (RatioUnit::Percent, val),
+            RawRatio::BasisPoints(val) => (RatioUnit::BasisPoints, val),
+        };
+        unit.into_ratio(raw_value)
+    }
+}
+
+impl FromStr for Ratio {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<RawRatio>()?.try_into()
+    }
+}
+
+impl WithUnit {
+    /// Unlike other types, ratios can be specified as plain numbers.
+    fn deserialize_ratio(
+        ctx: &DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Option<Ratio>, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        Ok(if let Value::Number(_) = deserializer.value() {
+            Some(Ratio(Decimal::deserialize(deserializer)?))
+        } else {
+            None
+        })
+    }
+}
+
+impl DeserializeParam<Ratio> for WithUnit {
+    const EXPECTING: BasicTypes = Self::EXPECTED_TYPES
+        .or(BasicTypes::INTEGER)
+        .or(BasicTypes::FLOAT);
+
+    fn describe(&self, description: &mut TypeDescription) {
+        description.set_details("ratio, value with unit, or object with single unit key");
+        description.set_suffixes(TypeSuffixes::RatioUnits);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Ratio, ErrorWithOrigin> {
+        if let Some(ratio) = Self::deserialize_ratio(&ctx, param)? {
+            return Ok(ratio);
+        }
+        Self::deserialize::<RawRatio, _>(&ctx, param)
+    }
+
+    fn serialize_param(&self, param: &Ratio) -> serde_json::Value {
+        param.to_string().into()
+    }
+}
+
+impl DeserializeParam<Option<Ratio>> for WithUnit {
+    const EXPECTING: BasicTypes = <Self as DeserializeParam<Ratio>>::EXPECTING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        <Self as DeserializeParam<Ratio>>::describe(self, description);
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<Option<Ratio>, ErrorWithOrigin> {
+        if let Some(ratio) = Self::deserialize_ratio(&ctx, param)? {
+            return Ok(Some(ratio));
+        }
+        Self::deserialize_opt::<RawRatio, _>(&ctx, param)
+    }
+
+    fn serialize_param(&self, param: &Option<Ratio>) -> serde_json::Value {
+        match param {
+            Some(val) => self.serialize_param(val),
+            None => serde_json::Value::Null,
+        }
+    }
+}
+
+impl_well_known_with_unit!(Ratio);
+
+impl WithUnit {
+    fn deserialize_token_amount<const DECIMALS: u8, Int: TokenInt>(
+        ctx: &DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+        expected_symbol: Option<&str>,
+    ) -> Result<TokenAmount<DECIMALS, Int>, ErrorWithOrigin> {
+        let deserializer = ctx.current_value_deserializer(param.name)?;
+        let (amount, symbol) = match deserializer.value() {
+            Value::Number(_) => {
+                let value = Decimal::deserialize(deserializer)?;
+                (TokenAmount::from_decimal(value), None)
+            }
+            Value::String(s) => match TokenAmount::parse_with_symbol(s.expose()) {
+                Ok((amount, symbol)) => (Ok(amount), symbol),
+                Err(err) => (Err(err), None),
+            },
+            _ => return Err(deserializer.invalid_type("token amount")),
+        };
+        let amount = amount.map_err(|err| deserializer.enrich_err(err))?;
+
+        let Some(symbol) = symbol else {
+            return Ok(amount);
+        };
+        let err = match expected_symbol {
+            Some(expected) if symbol.eq_ignore_ascii_case(expected) => return Ok(amount),
+            Some(expected) => DeError::invalid_value(
+                de::Unexpected::Str(symbol),
+                &format!("token symbol '{expected}'").as_str(),
+            ),
+            None => TokenAmount::<DECIMALS, Int>::unexpected_symbol_err(symbol),
+        };
+        Err(deserializer.enrich_err(err))
+    }
+}
+
+/// Default deserializer for [`TokenAmount`]s. Accepts a number or a decimal string; token symbols are rejected
+/// since the deserializer has no way to check them. Use [`TokenSymbol`] to accept a specific symbol.
+/// See [`TokenAmount`] docs for details.
+impl<const DECIMALS: u8, Int: TokenInt> DeserializeParam<TokenAmount<DECIMALS, Int>> for WithUnit {
+    const EXPECTING: BasicTypes = BasicTypes::INTEGER
+        .or(BasicTypes::FLOAT)
+        .or(BasicTypes::STRING);
+
+    fn describe(&self, description: &mut TypeDescription) {
+        let unit = TokenUnit {
+            symbol: None,
+            decimals: DECIMALS,
+        };
+        description
+            .set_details("token amount without token symbol")
+            .set_unit(unit.into());
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<TokenAmount<DECIMALS, Int>, ErrorWithOrigin> {
+        Self::deserialize_token_amount(&ctx, param, None)
+    }
+
+    fn serialize_param(&self, param: &TokenAmount<DECIMALS, Int>) -> serde_json::Value {
+        // Use a string to prevent precision loss
+        param.to_string().into()
+    }
+}
+
+impl<const DECIMALS: u8, Int: TokenInt> WellKnown for TokenAmount<DECIMALS, Int> {
+    type Deserializer = WithUnit;
+    const DE: Self::Deserializer = WithUnit;
+}
+
+impl<const DECIMALS: u8, Int: TokenInt> WellKnownOption for TokenAmount<DECIMALS, Int> {}
+
+/// Deserializer for [`TokenAmount`]s that only accepts the specified token symbol (case-insensitive)
+/// after the amount. The amount without a symbol is accepted as well. The symbol is documented
+/// as a part of the param unit and is appended to the amount on serialization.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig, TokenAmount};
+/// use smart_config::de::TokenSymbol;
+///
+/// #[derive(Debug, DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(with = TokenSymbol("usdc"))]
+///     fee_cap: TokenAmount<6>,
+/// }
+///
+/// let source = smart_config::config!("fee_cap": "2.5 USDC");
+/// let config: TestConfig = testing::test(source)?;
+/// assert_eq!(config.fee_cap, TokenAmount(2_500_000));
+///
+/// let source = smart_config::config!("fee_cap": "2.5 usdt");
+/// let err = testing::test::<TestConfig>(source).unwrap_err();
+/// let err = err.first().inner().to_string();
+/// assert!(err.contains("expected token symbol 'usdc'"), "{err}");
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TokenSymbol(pub &'static str);
+
+impl<const DECIMALS: u8, Int: TokenInt> DeserializeParam<TokenAmount<DECIMALS, Int>>
+    for TokenSymbol
+{
+    const EXPECTING: BasicTypes =
+        <WithUnit as DeserializeParam<TokenAmount<DECIMALS, Int>>>::EXPECTING;
+
+    fn describe(&self, description: &mut TypeDescription) {
+        let unit = TokenUnit {
+            symbol: Some(self.0),
+            decimals: DECIMALS,
+        };
+        description
+            .set_details("token amount")
+            .set_unit(unit.into());
+    }
+
+    fn deserialize_param(
+        &self,
+        ctx: DeserializeContext<'_>,
+        param: &'static ParamMetadata,
+    ) -> Result<TokenAmount<DECIMALS, Int>, ErrorWithOrigin> {
+        WithUnit::deserialize_token_amount(&ctx, param, Some(self.0))
+    }
+
+    fn serialize_param(&self, param: &TokenAmount<DECIMALS, Int>) -> serde_json::Value {
+        format!("{param} {}", self.0).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -693,6 +1452,89 @@ mod tests {
         assert_eq!(duration, RawDuration::Weeks(3.into()));
     }
 
+    #[test]
+    fn parsing_sub_millisecond_time_string() {
+        let duration: RawDuration = "250us".parse().unwrap();
+        assert_eq!(duration, RawDuration::Micros(250.into()));
+        let duration: RawDuration = "250 \u{b5}s".parse().unwrap();
+        assert_eq!(duration, RawDuration::Micros(250.into()));
+        let duration: RawDuration = "100ns".parse().unwrap();
+        assert_eq!(duration, RawDuration::Nanos(100.into()));
+
+        let duration = Duration::try_from("1.5ms".parse::<RawDuration>().unwrap()).unwrap();
+        assert_eq!(duration, Duration::from_micros(1_500));
+        let duration = Duration::try_from("2.5e-7s".parse::<RawDuration>().unwrap()).unwrap();
+        assert_eq!(duration, Duration::from_nanos(250));
+        let duration = Duration::try_from("0.4ns".parse::<RawDuration>().unwrap()).unwrap();
+        assert_eq!(duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn time_string_with_many_significant_digits() {
+        // The number of nanoseconds has more than `Decimal::MAX_PRECISION` significant digits.
+        let raw: RawDuration = "12345678901.234567891s".parse().unwrap();
+        let duration = Duration::try_from(raw).unwrap();
+        assert_eq!(duration, Duration::new(12_345_678_901, 234_567_891));
+        let raw: RawDuration = "1.234567890123456789 weeks".parse().unwrap();
+        let duration = Duration::try_from(raw).unwrap();
+        assert_eq!(duration, Duration::new(746_666, 659_946_667));
+
+        let raw: RawDuration = "1234567890123456789 min".parse().unwrap();
+        let err = Duration::try_from(raw).unwrap_err();
+        assert!(err.to_string().contains("does not fit"), "{err}");
+    }
+
+    #[test]
+    fn parsing_compound_time_string() {
+        let duration: RawDuration = "1h 30m".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::from_mins(90)));
+        let duration: RawDuration = "1h30min".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::from_mins(90)));
+        let duration: RawDuration = "1 day 2 hours 3 min 4.5 sec".parse().unwrap();
+        let expected = Duration::from_secs(86_400 + 7_200 + 180 + 4) + Duration::from_millis(500);
+        assert_eq!(duration, RawDuration::Exact(expected));
+        let duration: RawDuration = "1s 500ms 250us 1ns".parse().unwrap();
+        let expected = Duration::new(1, 500_250_001);
+        assert_eq!(duration, RawDuration::Exact(expected));
+
+        let err = "30m 1h".parse::<RawDuration>().unwrap_err().to_string();
+        assert!(err.contains("descending order"), "{err}");
+        let err = "1m 1m".parse::<RawDuration>().unwrap_err().to_string();
+        assert!(err.contains("descending order"), "{err}");
+        let err = "1h 30".parse::<RawDuration>().unwrap_err().to_string();
+        assert!(err.starts_with("invalid type"), "{err}");
+        let err = "1h 30 months"
+            .parse::<RawDuration>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("unknown variant"), "{err}");
+    }
+
+    #[test]
+    fn parsing_iso8601_duration() {
+        let duration: RawDuration = "PT1H30M".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::from_mins(90)));
+        let duration: RawDuration = "P1DT12H".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::from_hours(36)));
+        let duration: RawDuration = "P2W".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::from_hours(24 * 14)));
+        let duration: RawDuration = "PT0.25S".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::from_millis(250)));
+        let duration: RawDuration = "PT0S".parse().unwrap();
+        assert_eq!(duration, RawDuration::Exact(Duration::ZERO));
+
+        for invalid in [
+            "P", "PT", "P1H", "PT1D", "P1DT", "PT30M1H", "PT1H1H", "P1D1W", "PT1.5",
+        ] {
+            let err = invalid.parse::<RawDuration>().unwrap_err().to_string();
+            assert!(err.starts_with("invalid value"), "{invalid}: {err}");
+        }
+        let err = "P1Y".parse::<RawDuration>().unwrap_err().to_string();
+        assert!(err.contains("years and months are not supported"), "{err}");
+        let err = "P1M".parse::<RawDuration>().unwrap_err().to_string();
+        assert!(err.contains("years and months are not supported"), "{err}");
+    }
+
     #[test]
     fn parsing_fractional_time_string() {
         let duration: RawDuration = "10.0ms".parse().unwrap();
@@ -731,13 +1573,40 @@ mod tests {
         let size: RawByteSize = "16bytes".parse().unwrap();
         assert_eq!(size, RawByteSize::Bytes(16));
         let size: RawByteSize = "128    KiB".parse().unwrap();
-        assert_eq!(size, RawByteSize::Kilobytes(128));
+        assert_eq!(size, RawByteSize::Kibibytes(128));
         let size: RawByteSize = "16 kb".parse().unwrap();
         assert_eq!(size, RawByteSize::Kilobytes(16));
         let size: RawByteSize = "4MB".parse().unwrap();
         assert_eq!(size, RawByteSize::Megabytes(4));
+        let size: RawByteSize = "4 mib".parse().unwrap();
+        assert_eq!(size, RawByteSize::Mebibytes(4));
         let size: RawByteSize = "1 GB".parse().unwrap();
         assert_eq!(size, RawByteSize::Gigabytes(1));
+        let size: RawByteSize = "2 TiB".parse().unwrap();
+        assert_eq!(size, RawByteSize::Tebibytes(2));
+        let size: RawByteSize = "3 terabytes".parse().unwrap();
+        assert_eq!(size, RawByteSize::Terabytes(3));
+        let size: RawByteSize = "1 PiB".parse().unwrap();
+        assert_eq!(size, RawByteSize::Pebibytes(1));
+    }
+
+    #[test]
+    fn converting_byte_sizes() {
+        let size = ByteSize::try_from("500 MB".parse::<RawByteSize>().unwrap()).unwrap();
+        assert_eq!(size, ByteSize(500_000_000));
+        let size = ByteSize::try_from("500 MiB".parse::<RawByteSize>().unwrap()).unwrap();
+        assert_eq!(size, ByteSize(500 << 20));
+        let size = ByteSize::try_from("2 TB".parse::<RawByteSize>().unwrap()).unwrap();
+        assert_eq!(size, ByteSize(2_000_000_000_000));
+        let size = ByteSize::try_from("2 TiB".parse::<RawByteSize>().unwrap()).unwrap();
+        assert_eq!(size, ByteSize(2 << 40));
+
+        let err = ByteSize::try_from("20000 PiB".parse::<RawByteSize>().unwrap()).unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("20000 pebibytes does not fit"), "{err}");
+        let err = ByteSize::try_from("20000000 TB".parse::<RawByteSize>().unwrap()).unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("20000000 terabytes does not fit"), "{err}");
     }
 
     #[test]
@@ -784,6 +1653,10 @@ mod tests {
         assert_eq!(val, 0_u32);
         let val = TimeUnit::Minutes.serialize_param(&Duration::from_mins(2));
         assert_eq!(val, 2_u32);
+        let val = TimeUnit::Micros.serialize_param(&Duration::from_millis(2));
+        assert_eq!(val, 2_000_u32);
+        let val = TimeUnit::Nanos.serialize_param(&Duration::from_micros(2));
+        assert_eq!(val, 2_000_u32);
     }
 
     #[test]
@@ -798,6 +1671,12 @@ mod tests {
         assert_eq!(val, 0_u32);
         let val = SizeUnit::MiB.serialize_param(&ByteSize::new(3, SizeUnit::MiB));
         assert_eq!(val, 3_u32);
+        let val = SizeUnit::MB.serialize_param(&ByteSize::new(3, SizeUnit::MiB));
+        assert_eq!(val, 3_u32);
+        let val = SizeUnit::TB.serialize_param(&ByteSize::new(3, SizeUnit::TiB));
+        assert_eq!(val, 3_u32);
+        let val = SizeUnit::TiB.serialize_param(&ByteSize::new(3, SizeUnit::TB));
+        assert_eq!(val, 2_u32);
     }
 
     #[test]
@@ -816,6 +1695,34 @@ mod tests {
         assert_eq!(val, "2h");
         let val = WithUnit.serialize_param(&Duration::from_hours(24));
         assert_eq!(val, "1d");
+        let val = WithUnit.serialize_param(&Duration::from_mins(90));
+        assert_eq!(val, "90min");
+        let val = WithUnit.serialize_param(&Duration::from_micros(250));
+        assert_eq!(val, "250us");
+        let val = WithUnit.serialize_param(&Duration::from_nanos(1_500));
+        assert_eq!(val, "1500ns");
+        let val = WithUnit.serialize_param(&Duration::new(86_400, 1));
+        assert_eq!(val, "1d 1ns");
+        let val = WithUnit.serialize_param(&Duration::new(7 * 86_400 + 3_600, 1_000));
+        assert_eq!(val, "1w 1h 1us");
+    }
+
+    #[test]
+    fn serialized_durations_roundtrip() {
+        let durations = [
+            Duration::from_millis(10),
+            Duration::from_millis(5_050),
+            Duration::new(3_601, 0),
+            Duration::new(86_400, 1),
+            Duration::new(123_456, 789_012),
+            Duration::new(u64::MAX, 999_999_999),
+        ];
+        for duration in durations {
+            let serialized = WithUnit.serialize_param(&duration);
+            let serialized = serialized.as_str().unwrap();
+            let raw: RawDuration = serialized.parse().unwrap();
+            assert_eq!(Duration::try_from(raw).unwrap(), duration, "{serialized}");
+        }
     }
 
     #[test]
@@ -828,5 +1735,311 @@ mod tests {
         assert_eq!(val, "32 KiB");
         let val = WithUnit.serialize_param(&ByteSize(3 << 20));
         assert_eq!(val, "3 MiB");
+        let val = WithUnit.serialize_param(&ByteSize(2 << 40));
+        assert_eq!(val, "2 TiB");
+        let val = WithUnit.serialize_param(&ByteSize(3 << 50));
+        assert_eq!(val, "3 PiB");
+        let val = WithUnit.serialize_param(&ByteSize(500_000_000));
+        assert_eq!(val, "500 MB");
+        let val = WithUnit.serialize_param(&ByteSize(1_500));
+        assert_eq!(val, "1500 B");
+        let val = WithUnit.serialize_param(&ByteSize(4_000_000_000_000));
+        assert_eq!(val, "4 TB");
+    }
+
+    #[test]
+    fn serialized_byte_sizes_roundtrip() {
+        let sizes = [
+            1,
+            1_000,
+            1_024,
+            1_024_000,
+            3 << 50,
+            5_000_000_000_000,
+            u64::MAX,
+        ];
+        for size in sizes.map(ByteSize) {
+            let serialized = WithUnit.serialize_param(&size);
+            let serialized = serialized.as_str().unwrap();
+            let parsed: ByteSize = serialized.parse().unwrap();
+            assert_eq!(parsed, size, "{serialized}");
+        }
+    }
+
+    #[test]
+    fn parsing_rate_string() {
+        for input in [
+            "500/s",
+            "500 / sec",
+            "500 per second",
+            "500 per_sec",
+            "500 Per Second",
+        ] {
+            let rate: RawRate = input.parse().unwrap();
+            assert_eq!(
+                rate,
+                RawRate {
+                    count: 500,
+                    per: TimeUnit::Seconds
+                },
+                "{input}"
+            );
+        }
+        let rate: Rate = "10 per minute".parse().unwrap();
+        assert_eq!(rate, Rate::new(10, TimeUnit::Minutes));
+        assert_eq!(rate, Rate::new(600, TimeUnit::Hours));
+        assert!(rate < Rate::new(1, TimeUnit::Seconds));
+
+        let err = "500 s".parse::<Rate>().unwrap_err().to_string();
+        assert!(err.starts_with("unknown variant"), "{err}");
+        let err = "500 per month".parse::<Rate>().unwrap_err().to_string();
+        assert!(err.starts_with("unknown variant"), "{err}");
+        let err = "1.5/s".parse::<Rate>().unwrap_err().to_string();
+        assert!(err.starts_with("unknown variant"), "{err}");
+    }
+
+    #[test]
+    fn parsing_bandwidth_string() {
+        for input in [
+            "10 MiB/s",
+            "10MiB / sec",
+            "10 mib per second",
+            "10 mib_per_sec",
+        ] {
+            let bandwidth: RawBandwidth = input.parse().unwrap();
+            assert_eq!(
+                bandwidth,
+                RawBandwidth {
+                    size: RawByteSize::Mebibytes(10),
+                    per: TimeUnit::Seconds
+                },
+                "{input}"
+            );
+        }
+        let bandwidth: Bandwidth = "512 KiB per minute".parse().unwrap();
+        assert_eq!(
+            bandwidth,
+            Bandwidth::new(512 * SizeUnit::KiB, TimeUnit::Minutes)
+        );
+        assert_eq!(
+            bandwidth,
+            Bandwidth::new(30 * SizeUnit::MiB, TimeUnit::Hours)
+        );
+
+        let err = "10 MiB".parse::<Bandwidth>().unwrap_err().to_string();
+        assert!(err.starts_with("unknown variant"), "{err}");
+        let err = "100000000000 GiB/s"
+            .parse::<Bandwidth>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("does not fit"), "{err}");
+    }
+
+    #[test]
+    fn parsing_ratio_string() {
+        let ratio: RawRatio = "0.75".parse().unwrap();
+        assert_eq!(ratio, RawRatio::Ratio(Decimal::new(75, -2)));
+        let ratio: RawRatio = "75%".parse().unwrap();
+        assert_eq!(ratio, RawRatio::Percent(75.into()));
+        let ratio: RawRatio = "12.5 percent".parse().unwrap();
+        assert_eq!(ratio, RawRatio::Percent(Decimal::new(125, -1)));
+        let ratio: RawRatio = "30 bps".parse().unwrap();
+        assert_eq!(ratio, RawRatio::BasisPoints(30.into()));
+
+        for input in ["0.75", "75%", "75 pct", "7500bps", "0.75 ratio"] {
+            let ratio: Ratio = input.parse().unwrap();
+            assert_eq!(ratio, Ratio::from_percent(75), "{input}");
+        }
+
+        let err = "75 permille".parse::<Ratio>().unwrap_err().to_string();
+        assert!(err.starts_with("unknown variant"), "{err}");
+        let err = "%".parse::<Ratio>().unwrap_err().to_string();
+        assert!(err.starts_with("invalid type"), "{err}");
+    }
+
+    #[test]
+    fn serializing_rates_and_bandwidths() {
+        let rate = Rate::new(500, TimeUnit::Seconds);
+        assert_eq!(WithUnit.serialize_param(&rate), "500/s");
+        assert_eq!(TimeUnit::Minutes.serialize_param(&rate), 30_000_u32);
+        assert_eq!(TimeUnit::Millis.serialize_param(&rate), "500/s");
+
+        let bandwidth = Bandwidth::new(10 * SizeUnit::MiB, TimeUnit::Seconds);
+        assert_eq!(WithUnit.serialize_param(&bandwidth), "10 MiB/s");
+        let unit = BandwidthUnit::new(SizeUnit::KiB, TimeUnit::Minutes);
+        assert_eq!(unit.serialize_param(&bandwidth), 10 * 1_024 * 60);
+
+        for input in ["500/s", "3/min", "10 MiB/s", "1 B/ns"] {
+            if let Ok(rate) = input.parse::<Rate>() {
+                assert_eq!(WithUnit.serialize_param(&rate), input);
+            } else {
+                let bandwidth: Bandwidth = input.parse().unwrap();
+                assert_eq!(WithUnit.serialize_param(&bandwidth), input);
+            }
+        }
+    }
+
+    #[test]
+    fn serializing_ratios() {
+        let ratio = Ratio::from_percent(75);
+        assert_eq!(WithUnit.serialize_param(&ratio), "75%");
+        assert_eq!(RatioUnit::Ratio.serialize_param(&ratio), 0.75);
+        assert_eq!(RatioUnit::Percent.serialize_param(&ratio), 75_u32);
+        assert_eq!(RatioUnit::BasisPoints.serialize_param(&ratio), 7_500_u32);
+
+        for ratio in [
+            Ratio::default(),
+            Ratio::from_percent(150),
+            Ratio::from_basis_points(3),
+            Ratio(Decimal::new(1, -20)),
+        ] {
+            let serialized = WithUnit.serialize_param(&ratio);
+            let parsed: Ratio = serialized.as_str().unwrap().parse().unwrap();
+            assert_eq!(parsed, ratio, "{serialized}");
+        }
+    }
+
+    #[test]
+    fn parsing_token_amounts() {
+        type UsdcAmount = TokenAmount<6>;
+
+        for input in [
+            "1.5",
+            "1.5 usdc",
+            "1.5USDC",
+            "1_500_000e-6",
+            "0.0015e3 usdc",
+        ] {
+            let (amount, _) = UsdcAmount::parse_with_symbol(input).unwrap();
+            assert_eq!(amount, TokenAmount(1_500_000), "{input}");
+        }
+        let (_, symbol) = UsdcAmount::parse_with_symbol("1.5 USDC").unwrap();
+        assert_eq!(symbol, Some("USDC"));
+        let (_, symbol) = UsdcAmount::parse_with_symbol("1.5").unwrap();
+        assert_eq!(symbol, None);
+
+        let amount: TokenAmount<0, u64> = "123".parse().unwrap();
+        assert_eq!(amount, TokenAmount(123));
+        let amount: TokenAmount<18> = "1.000000000000000001".parse().unwrap();
+        assert_eq!(amount, TokenAmount(1_000_000_000_000_000_001));
+        let amount: TokenAmount<36> = "1e-36".parse().unwrap();
+        assert_eq!(amount, TokenAmount(1));
+        let amount: UsdcAmount = "0.0000000".parse().unwrap();
+        assert_eq!(amount, TokenAmount(0));
+
+        // Significant digits aren't limited by `Decimal` precision.
+        let amount: TokenAmount<18> = "123456789012.345678901234567891".parse().unwrap();
+        assert_eq!(amount, TokenAmount(123_456_789_012_345_678_901_234_567_891));
+        let (amount, _) =
+            TokenAmount::<18>::parse_with_symbol("1_234_567_890_123_456_789_012_345e-6 eth")
+                .unwrap();
+        assert_eq!(
+            amount,
+            TokenAmount(1_234_567_890_123_456_789_012_345_000_000_000_000)
+        );
+        let (amount, symbol) = TokenAmount::<6>::parse_with_symbol("1.5e+2eth").unwrap();
+        assert_eq!((amount, symbol), (TokenAmount(150_000_000), Some("eth")));
+        let (amount, symbol) = UsdcAmount::parse_with_symbol("2ether").unwrap();
+        assert_eq!((amount, symbol), (TokenAmount(2_000_000), Some("ether")));
+    }
+
+    #[test]
+    fn token_amount_parsing_errors() {
+        let err = "0.0000001".parse::<TokenAmount<6>>().unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("more than 6 decimal places"), "{err}");
+        let err = "1.5".parse::<TokenAmount<0>>().unwrap_err().to_string();
+        assert!(err.contains("more than 0 decimal places"), "{err}");
+
+        let err = "20".parse::<TokenAmount<18, u64>>().unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("does not fit"), "{err}");
+        let err = "1e21".parse::<TokenAmount<18>>().unwrap_err().to_string();
+        assert!(err.contains("does not fit"), "{err}");
+
+        let err = "usdc".parse::<TokenAmount<6>>().unwrap_err().to_string();
+        assert!(err.starts_with("invalid type"), "{err}");
+        let err = "1.5 usdc!"
+            .parse::<TokenAmount<6>>()
+            .unwrap_err()
+            .to_string();
+        assert!(err.starts_with("invalid value"), "{err}");
+        let err = "1.5 -3".parse::<TokenAmount<6>>().unwrap_err().to_string();
+        assert!(err.starts_with("invalid value"), "{err}");
+        let err = "1.5 eth".parse::<TokenAmount<6>>().unwrap_err().to_string();
+        assert!(err.contains("string \"eth\""), "{err}");
+        assert!(err.contains("without token symbol"), "{err}");
+    }
+
+    #[test]
+    fn displaying_token_amounts() {
+        assert_eq!(TokenAmount::<6>(1_500_000).to_string(), "1.5");
+        assert_eq!(TokenAmount::<6>(1).to_string(), "0.000001");
+        assert_eq!(TokenAmount::<6>(0).to_string(), "0");
+        assert_eq!(TokenAmount::<6>(42_000_000).to_string(), "42");
+        assert_eq!(TokenAmount::<0>(42).to_string(), "42");
+        assert_eq!(
+            TokenAmount::<18>(u128::MAX).to_string(),
+            "340282366920938463463.374607431768211455"
+        );
+
+        for amount in [0, 1, 1_500_000, 123_456_789] {
+            let amount = TokenAmount::<6>(amount);
+            let serialized = WithUnit.serialize_param(&amount);
+            let parsed: TokenAmount<6> = serialized.as_str().unwrap().parse().unwrap();
+            assert_eq!(parsed, amount);
+            let serialized = TokenSymbol("usdc").serialize_param(&amount);
+            let parsed = TokenAmount::<6>::parse_with_symbol(serialized.as_str().unwrap()).unwrap();
+            assert_eq!(parsed, (amount, Some("usdc")));
+        }
+    }
+
+    #[test]
+    fn deserializing_token_amounts() {
+        use crate::{DescribeConfig, DeserializeConfig, testing};
+
+        #[derive(Debug, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct TokenConfig {
+            threshold: TokenAmount<6>,
+            #[config(with = TokenSymbol("usdc"))]
+            fee_cap: Option<TokenAmount<6>>,
+        }
+
+        let json = crate::config!("threshold": 1.25, "fee_cap": "0.5 Usdc");
+        let config: TokenConfig = testing::test(json).unwrap();
+        assert_eq!(config.threshold, TokenAmount(1_250_000));
+        assert_eq!(config.fee_cap, Some(TokenAmount(500_000)));
+
+        let json = crate::config!("threshold": "100");
+        let config: TokenConfig = testing::test(json).unwrap();
+        assert_eq!(config.threshold, TokenAmount(100_000_000));
+        assert_eq!(config.fee_cap, None);
+
+        let json = crate::config!("threshold": "0.1234567", "fee_cap": "1 usdt");
+        let errors = testing::test::<TokenConfig>(json).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        let threshold_err = errors.iter().find(|err| err.path() == "threshold").unwrap();
+        let threshold_err = threshold_err.inner().to_string();
+        assert!(threshold_err.contains("decimal places"), "{threshold_err}");
+        let fee_err = errors.iter().find(|err| err.path() == "fee_cap").unwrap();
+        let fee_err = fee_err.inner().to_string();
+        assert!(fee_err.contains("token symbol 'usdc'"), "{fee_err}");
+
+        // Symbols are rejected by the default deserializer.
+        let json = crate::config!("threshold": "1.5 eth");
+        let errors = testing::test::<TokenConfig>(json).unwrap_err();
+        let err = errors.first();
+        assert_eq!(err.path(), "threshold");
+        let err = err.inner().to_string();
+        assert!(err.contains("without token symbol"), "{err}");
+
+        let [threshold, fee_cap] = &TokenConfig::DESCRIPTION.params else {
+            unreachable!();
+        };
+        let unit = threshold.type_description().unit().unwrap();
+        assert_eq!(unit.to_string(), "token with 6 decimals");
+        let unit = fee_cap.type_description().unit().unwrap();
+        assert_eq!(unit.to_string(), "usdc with 6 decimals");
     }
 }
@@ -0,0 +1,77 @@
+use url::Url;
+
+use crate::de::{Qualified, Serde, WellKnown, WellKnownOption};
+
+/// Accepts an absolute URL string, e.g. `https://example.com/api`. The URL is normalized during parsing
+/// (e.g., the scheme and host are lowercased, and an empty path is replaced with `/`).
+///
+/// Additional restrictions (e.g., on the URL scheme) can be enforced using [validations](crate::validation),
+/// such as [`UrlScheme`](crate::validation::UrlScheme).
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl WellKnown for Url {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = Qualified::new(Serde![str], "absolute URL");
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "url")))]
+impl WellKnownOption for Url {}
+
+#[cfg(test)]
+mod tests {
+    use url::Url;
+
+    use crate::{
+        DescribeConfig, DeserializeConfig, config,
+        metadata::BasicTypes,
+        testing::{test, test_complete},
+    };
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        url: Url,
+        #[config(default)]
+        fallback_url: Option<Url>,
+    }
+
+    #[test]
+    fn describing_urls() {
+        let param = TestConfig::DESCRIPTION
+            .params
+            .iter()
+            .find(|param| param.name == "url")
+            .unwrap();
+        assert_eq!(param.expecting, BasicTypes::STRING);
+        let description = param.type_description();
+        assert_eq!(description.details().unwrap(), "absolute URL");
+    }
+
+    #[test]
+    fn deserializing_urls() {
+        let json = config!(
+            "url": "HTTPS://Example.com",
+            "fallback_url": "http://127.0.0.1:8080/api?v=1",
+        );
+        let config = test_complete::<TestConfig>(json).unwrap();
+        assert_eq!(config.url.as_str(), "https://example.com/");
+        let fallback_url = config.fallback_url.unwrap();
+        assert_eq!(fallback_url.port(), Some(8080));
+        assert_eq!(fallback_url.path(), "/api");
+
+        let json = config!("url": "wss://example.com/ws", "fallback_url": ());
+        let config = test::<TestConfig>(json).unwrap();
+        assert_eq!(config.url.scheme(), "wss");
+        assert_eq!(config.fallback_url, None);
+    }
+
+    #[test]
+    fn url_errors() {
+        let json = config!("url": "/relative/path");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "url");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("relative URL without a base"), "{inner}");
+    }
+}
@@ -0,0 +1,69 @@
+use uuid::Uuid;
+
+use crate::de::{Qualified, Serde, WellKnown, WellKnownOption};
+
+/// Accepts a UUID string in any format supported by [`Uuid::parse_str()`], e.g. hyphenated
+/// (`67e55044-10b1-426f-9247-bb680e5fe0c8`), simple (no hyphens) or braced. UUIDs are serialized in the hyphenated format.
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+impl WellKnown for Uuid {
+    type Deserializer = Qualified<Serde![str]>;
+    const DE: Self::Deserializer = Qualified::new(Serde![str], "UUID");
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
+impl WellKnownOption for Uuid {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use smart_config_derive::{DescribeConfig, DeserializeConfig};
+    use uuid::Uuid;
+
+    use crate::{config, testing::test, testonly::test_config_roundtrip};
+
+    #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        id: Uuid,
+        #[config(default)]
+        peers: HashSet<Uuid>,
+    }
+
+    #[test]
+    fn deserializing_uuids() {
+        let json = config!(
+            "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "peers": [
+                "67E5504410B1426F9247BB680E5FE0C9",
+                "{67e55044-10b1-426f-9247-bb680e5fe0ca}",
+            ],
+        );
+        let config = test::<TestConfig>(json).unwrap();
+        assert_eq!(
+            config.id,
+            Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8)
+        );
+        assert_eq!(
+            config.peers,
+            HashSet::from([
+                Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c9),
+                Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0ca),
+            ])
+        );
+
+        let json = test_config_roundtrip(&config);
+        assert_eq!(json["id"], "67e55044-10b1-426f-9247-bb680e5fe0c8");
+    }
+
+    #[test]
+    fn uuid_errors() {
+        let json = config!("id": "67e55044-10b1-426f");
+        let err = test::<TestConfig>(json).unwrap_err();
+        assert_eq!(err.len(), 1);
+        let err = err.first();
+        assert_eq!(err.path(), "id");
+        let inner = err.inner().to_string();
+        assert!(inner.contains("UUID parsing failed"), "{inner}");
+    }
+}
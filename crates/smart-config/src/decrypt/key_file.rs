@@ -0,0 +1,255 @@
+//! Built-in decryptor based on a local key file.
+
+use std::{fmt, fs, path::PathBuf};
+
+use aes_gcm::{AeadInPlace, Aes256Gcm, AesGcm, KeyInit, aead::consts::U32, aes::Aes256};
+use anyhow::Context as _;
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use secrecy::{ExposeSecret, ExposeSecretMut, SecretBox, SecretString};
+
+use super::{Decryptor, EncryptedValue};
+
+/// `AES256_GCM` with a 32-byte nonce, as used by `sops`.
+type Aes256GcmLongNonce = AesGcm<Aes256, U32>;
+
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+
+/// Decryptor for `AES256_GCM` values using a 256-bit data key stored in a local file.
+///
+/// The key file must contain 32 bytes encoded either as a hex string (with an optional `0x` prefix)
+/// or as a base64 string; leading / trailing whitespace is ignored. The key is kept in memory in a zero-on-drop wrapper.
+///
+/// Encrypted values must have the `sops` layout: `ENC[AES256_GCM,data:$data,iv:$iv,tag:$tag,type:$type]`,
+/// where `data`, `iv` and `tag` are base64-encoded. The nonce (`iv`) must be 12 or 32 bytes long (the latter is used by `sops`).
+/// The additional authenticated data is computed like in `sops`, i.e. as the [key path](EncryptedValue::key_path())
+/// of the value with each key followed by `:`. The `type` field is ignored; decrypted values are always
+/// provided as strings.
+///
+/// # Examples
+///
+/// ```no_run
+/// use smart_config::{decrypt::KeyFile, ConfigRepository, ConfigSchema, Yaml};
+///
+/// # fn test(schema: &ConfigSchema, yaml: Yaml) -> anyhow::Result<()> {
+/// let key_file = KeyFile::new("/etc/app/config.key")?;
+/// let repo = ConfigRepository::new(schema).with_decryptor(key_file).with(yaml);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+pub struct KeyFile {
+    path: PathBuf,
+    key: SecretBox<[u8; KEY_LEN]>,
+}
+
+impl fmt::Debug for KeyFile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("KeyFile")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for KeyFile {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "key file '{}'", self.path.display())
+    }
+}
+
+impl KeyFile {
+    /// Reads the key from the specified file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, or it doesn't contain a valid key.
+    pub fn new(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("failed reading key file '{}'", path.display()))?;
+        let contents = SecretString::from(contents);
+        let key = Self::parse_key(contents.expose_secret().trim())
+            .with_context(|| format!("invalid key in key file '{}'", path.display()))?;
+        Ok(Self { path, key })
+    }
+
+    fn parse_key(encoded: &str) -> anyhow::Result<SecretBox<[u8; KEY_LEN]>> {
+        let mut key = SecretBox::new(Box::new([0_u8; KEY_LEN]));
+        let hex = encoded.strip_prefix("0x").unwrap_or(encoded);
+        if hex.len() == 2 * KEY_LEN && hex.bytes().all(|ch| ch.is_ascii_hexdigit()) {
+            for (dest, chunk) in key
+                .expose_secret_mut()
+                .iter_mut()
+                .zip(hex.as_bytes().chunks(2))
+            {
+                // `unwrap()`s are safe: we've checked that all chars are hex digits
+                let chunk = std::str::from_utf8(chunk).unwrap();
+                *dest = u8::from_str_radix(chunk, 16).unwrap();
+            }
+        } else {
+            let decoded = SecretBox::new(
+                BASE64
+                    .decode(encoded)
+                    .context("key is neither a hex nor a base64 string")?
+                    .into_boxed_slice(),
+            );
+            let decoded = decoded.expose_secret();
+            anyhow::ensure!(
+                decoded.len() == KEY_LEN,
+                "unexpected key length: expected {KEY_LEN} bytes, got {}",
+                decoded.len()
+            );
+            key.expose_secret_mut().copy_from_slice(decoded);
+        }
+        Ok(key)
+    }
+
+    fn decrypt_detached<C: KeyInit + AeadInPlace>(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &[u8],
+    ) -> Result<(), aes_gcm::Error> {
+        // `unwrap()` is safe: the key has the correct length by construction
+        let cipher = C::new_from_slice(self.key.expose_secret()).unwrap();
+        cipher.decrypt_in_place_detached(nonce.into(), aad, buffer, tag.into())
+    }
+}
+
+impl Decryptor for KeyFile {
+    fn decrypt(&self, value: &EncryptedValue<'_>) -> anyhow::Result<SecretString> {
+        anyhow::ensure!(
+            value.algorithm() == "AES256_GCM",
+            "unsupported algorithm; only AES256_GCM is supported"
+        );
+        let decode_field = |name: &str| {
+            let field = value
+                .field(name)
+                .with_context(|| format!("missing `{name}` field"))?;
+            BASE64
+                .decode(field)
+                .with_context(|| format!("`{name}` field is not valid base64"))
+        };
+
+        let mut data = decode_field("data")?;
+        let nonce = decode_field("iv")?;
+        let tag = decode_field("tag")?;
+        anyhow::ensure!(
+            tag.len() == TAG_LEN,
+            "unexpected tag length: expected {TAG_LEN} bytes, got {}",
+            tag.len()
+        );
+
+        let aad: String = value
+            .key_path()
+            .iter()
+            .flat_map(|key| [key.as_str(), ":"])
+            .collect();
+        let result = match nonce.len() {
+            12 => self.decrypt_detached::<Aes256Gcm>(&nonce, aad.as_bytes(), &mut data, &tag),
+            32 => {
+                self.decrypt_detached::<Aes256GcmLongNonce>(&nonce, aad.as_bytes(), &mut data, &tag)
+            }
+            len => anyhow::bail!("unsupported nonce length: expected 12 or 32 bytes, got {len}"),
+        };
+        result
+            .map_err(|_| anyhow::anyhow!("authentication failed; the key or value is invalid"))?;
+
+        let decrypted = String::from_utf8(data).map_err(|err| {
+            // Zeroize the decrypted bytes.
+            drop(SecretBox::new(err.into_bytes().into_boxed_slice()));
+            anyhow::anyhow!("decrypted value is not valid UTF-8")
+        })?;
+        Ok(decrypted.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    const KEY: [u8; KEY_LEN] = [7; KEY_LEN];
+
+    fn encrypt_detached<C: KeyInit + AeadInPlace>(
+        nonce: &[u8],
+        aad: &[u8],
+        buffer: &mut [u8],
+    ) -> Vec<u8> {
+        let cipher = C::new_from_slice(&KEY).unwrap();
+        let tag = cipher
+            .encrypt_in_place_detached(nonce.into(), aad, buffer)
+            .unwrap();
+        tag.to_vec()
+    }
+
+    fn encrypt(plaintext: &str, key_path: &str, nonce_len: usize) -> String {
+        let nonce = vec![1_u8; nonce_len];
+        let mut buffer = plaintext.as_bytes().to_vec();
+        let tag = if nonce_len == 12 {
+            encrypt_detached::<Aes256Gcm>(&nonce, key_path.as_bytes(), &mut buffer)
+        } else {
+            encrypt_detached::<Aes256GcmLongNonce>(&nonce, key_path.as_bytes(), &mut buffer)
+        };
+        format!(
+            "ENC[AES256_GCM,data:{},iv:{},tag:{},type:str]",
+            BASE64.encode(&buffer),
+            BASE64.encode(&nonce),
+            BASE64.encode(tag)
+        )
+    }
+
+    fn create_key_file(contents: &str, name: &str) -> anyhow::Result<KeyFile> {
+        let path = env::temp_dir().join(format!("smart-config-{}-{name}.key", process::id()));
+        fs::write(&path, contents).unwrap();
+        let key_file = KeyFile::new(&path);
+        fs::remove_file(&path).unwrap();
+        key_file
+    }
+
+    #[test]
+    fn reading_key_file() {
+        let hex_key = "07".repeat(KEY_LEN);
+        let key_file = create_key_file(&format!("0x{hex_key}\n"), "hex").unwrap();
+        assert_eq!(*key_file.key.expose_secret(), KEY);
+        assert!(!format!("{key_file:?}").contains(&hex_key));
+
+        let key_file = create_key_file(&BASE64.encode(KEY), "base64").unwrap();
+        assert_eq!(*key_file.key.expose_secret(), KEY);
+
+        let err = create_key_file("??", "invalid").unwrap_err();
+        let err = format!("{err:#}");
+        assert!(err.contains("neither a hex nor a base64"), "{err}");
+        let err = create_key_file(&BASE64.encode([1; 16]), "short").unwrap_err();
+        let err = format!("{err:#}");
+        assert!(err.contains("unexpected key length"), "{err}");
+    }
+
+    #[test]
+    fn decrypting_values() {
+        let key_file = create_key_file(&BASE64.encode(KEY), "decrypt").unwrap();
+        let key_path = ["api".to_owned(), "password".to_owned()];
+        for nonce_len in [12, 32] {
+            let encrypted = encrypt("correct horse", "api:password:", nonce_len);
+            let value = EncryptedValue::parse_with_path(&encrypted, &key_path).unwrap();
+            let decrypted = key_file.decrypt(&value).unwrap();
+            assert_eq!(decrypted.expose_secret(), "correct horse");
+        }
+
+        let encrypted = encrypt("correct horse", "api:password:", 12);
+        let value = EncryptedValue::parse_with_path(&encrypted, &key_path[..1]).unwrap();
+        let err = key_file.decrypt(&value).unwrap_err().to_string();
+        assert!(err.contains("authentication failed"), "{err}");
+
+        let value = EncryptedValue::parse("ENC[AES256_GCM,data:AAAA,iv:AAAA,tag:AAAA]").unwrap();
+        let err = key_file.decrypt(&value).unwrap_err().to_string();
+        assert!(err.contains("unexpected tag length"), "{err}");
+
+        let value = EncryptedValue::parse("ENC[PGP,data:AAAA]").unwrap();
+        let err = key_file.decrypt(&value).unwrap_err().to_string();
+        assert!(err.contains("unsupported algorithm"), "{err}");
+    }
+}
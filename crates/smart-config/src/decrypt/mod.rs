@@ -0,0 +1,408 @@
+//! Decryption of encrypted values in configuration sources.
+//!
+//! # Motivation
+//!
+//! Committing secrets to a repository in plaintext is a bad idea, but committing them in an *encrypted* form
+//! is a common practice (e.g., using [`sops`](https://github.com/getsops/sops)). This module allows a [`ConfigRepository`](crate::ConfigRepository)
+//! to transparently decrypt such values when sources are added to it.
+//!
+//! # Format
+//!
+//! Encrypted values are strings in the `sops`-like format `ENC[$algorithm,$key:$value,...]`, for example:
+//!
+//! ```text
+//! ENC[AES256_GCM,data:...,iv:...,tag:...,type:str]
+//! ```
+//!
+//! The algorithm and the fields are interpreted by the [`Decryptor`] supplied to the repository
+//! via [`ConfigRepository::with_decryptor()`](crate::ConfigRepository::with_decryptor()); see [`EncryptedValue`] for the parsed representation.
+//! Strings not matching this format are left as-is.
+//!
+//! # Processing
+//!
+//! - Decryption happens when a source is [added](crate::ConfigRepository::with()) to the repository, before any other preprocessing.
+//!   Thus, decryptors must be set before adding sources.
+//! - Decrypted values are always strings. They are [coerced](crate::ConfigRepository#type-coercion) to the expected param type
+//!   (e.g., integer) as usual.
+//! - Decrypted values are always treated as secrets, regardless of the param type.
+//! - Decrypted values get the origin of the encrypted value amended with a `decrypted from ...` transform.
+//! - Decryption failures do not abort loading the source. Instead, they are reported as errors for the param(s)
+//!   containing the value when the corresponding configs are parsed.
+//!
+//! # Examples
+//!
+//! ```
+//! use smart_config::{
+//!     decrypt::{Decryptor, EncryptedValue},
+//!     value::SecretString,
+//!     ConfigRepository, ConfigSchema, DescribeConfig, DeserializeConfig, Yaml,
+//! };
+//!
+//! /// Toy decryptor reversing the `data` field of encrypted values.
+//! #[derive(Debug)]
+//! struct Reverse;
+//!
+//! impl std::fmt::Display for Reverse {
+//!     fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+//!         formatter.write_str("reversing decryptor")
+//!     }
+//! }
+//!
+//! impl Decryptor for Reverse {
+//!     fn decrypt(&self, value: &EncryptedValue<'_>) -> anyhow::Result<SecretString> {
+//!         anyhow::ensure!(value.algorithm() == "REVERSE", "unsupported algorithm");
+//!         let data = value.field("data").ok_or_else(|| anyhow::anyhow!("no data"))?;
+//!         Ok(data.chars().rev().collect::<String>().into())
+//!     }
+//! }
+//!
+//! #[derive(DescribeConfig, DeserializeConfig)]
+//! struct TestConfig {
+//!     port: u16,
+//!     password: String,
+//! }
+//!
+//! let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "");
+//! let yaml = r"
+//! port: ENC[REVERSE,data:0808]
+//! password: ENC[REVERSE,data:terces]
+//! ";
+//! let yaml = Yaml::new("test.yml", serde_yaml::from_str(yaml)?)?;
+//! let repo = ConfigRepository::new(&schema).with_decryptor(Reverse).with(yaml);
+//! let config: TestConfig = repo.single()?.parse()?;
+//! assert_eq!(config.port, 8_080);
+//! assert_eq!(config.password, "secret");
+//! # anyhow::Ok(())
+//! ```
+
+use std::{fmt, sync::Arc};
+
+#[cfg(feature = "encryption")]
+pub use self::key_file::KeyFile;
+use crate::value::{Map, SecretString, StrValue, Value, ValueOrigin, WithOrigin};
+
+#[cfg(feature = "encryption")]
+mod key_file;
+
+/// Encrypted value in the `ENC[$algorithm,$key:$value,...]` format.
+#[derive(Debug, Clone)]
+pub struct EncryptedValue<'a> {
+    algorithm: &'a str,
+    fields: Vec<(&'a str, &'a str)>,
+    key_path: &'a [String],
+}
+
+impl<'a> EncryptedValue<'a> {
+    /// Parses an encrypted value from a string. Returns `None` if the string doesn't have the expected format.
+    pub fn parse(s: &'a str) -> Option<Self> {
+        Self::parse_with_path(s, &[])
+    }
+
+    fn parse_with_path(s: &'a str, key_path: &'a [String]) -> Option<Self> {
+        let contents = s.strip_prefix("ENC[")?.strip_suffix(']')?;
+        let mut parts = contents.split(',');
+        let algorithm = parts.next().filter(|alg| !alg.is_empty())?;
+        let fields = parts
+            .map(|part| part.split_once(':'))
+            .collect::<Option<_>>()?;
+        Some(Self {
+            algorithm,
+            fields,
+            key_path,
+        })
+    }
+
+    /// Returns the encryption algorithm, e.g. `AES256_GCM`.
+    pub fn algorithm(&self) -> &'a str {
+        self.algorithm
+    }
+
+    /// Gets a field with the specified name (e.g., `data` or `iv`).
+    pub fn field(&self, name: &str) -> Option<&'a str> {
+        self.fields
+            .iter()
+            .find_map(|&(key, value)| (key == name).then_some(value))
+    }
+
+    /// Returns the path to the value in its source as a list of object keys. Array indices are skipped,
+    /// which is consistent with how `sops` computes additional authenticated data for values.
+    pub fn key_path(&self) -> &'a [String] {
+        self.key_path
+    }
+}
+
+/// Decryptor of [encrypted values](EncryptedValue) in configuration sources.
+///
+/// The [`Display`](fmt::Display) implementation is used to describe the decryptor in value origins.
+pub trait Decryptor: 'static + Send + Sync + fmt::Debug + fmt::Display {
+    /// Decrypts the provided value.
+    ///
+    /// # Errors
+    ///
+    /// Should return an error if the value cannot be decrypted, e.g. because of an unsupported algorithm,
+    /// missing / malformed fields, or failed authentication.
+    fn decrypt(&self, value: &EncryptedValue<'_>) -> anyhow::Result<SecretString>;
+}
+
+/// Information about a value that has failed decryption.
+#[derive(Debug, Clone)]
+pub(crate) struct DecryptionFailure {
+    /// Origin of the value. Unique for each failure, so it's used to identify the failed value.
+    pub(crate) origin: Arc<ValueOrigin>,
+    pub(crate) message: String,
+}
+
+impl WithOrigin<Map> {
+    /// Decrypts all encrypted values in this source. Returns the number of successfully decrypted values.
+    pub(crate) fn decrypt_values(
+        &mut self,
+        decryptor: &dyn Decryptor,
+        failures: &mut Vec<DecryptionFailure>,
+    ) -> usize {
+        let mut key_path = vec![];
+        self.inner
+            .iter_mut()
+            .map(|(key, value)| {
+                key_path.push(key.clone());
+                let count = value.decrypt_values(decryptor, &mut key_path, failures);
+                key_path.pop();
+                count
+            })
+            .sum()
+    }
+}
+
+impl WithOrigin {
+    fn decrypt_values(
+        &mut self,
+        decryptor: &dyn Decryptor,
+        key_path: &mut Vec<String>,
+        failures: &mut Vec<DecryptionFailure>,
+    ) -> usize {
+        match &mut self.inner {
+            Value::String(StrValue::Plain(s)) => {
+                let Some(encrypted) = EncryptedValue::parse_with_path(s, key_path) else {
+                    return 0;
+                };
+                let algorithm = encrypted.algorithm;
+                let outcome = decryptor.decrypt(&encrypted).map_err(|err| {
+                    format!("failed decrypting {algorithm} value using {decryptor}: {err:#}")
+                });
+                let transform = match &outcome {
+                    Ok(_) => format!("decrypted from {algorithm} using {decryptor}"),
+                    Err(_) => "failed decryption".to_owned(),
+                };
+                self.origin = Arc::new(ValueOrigin::Synthetic {
+                    source: self.origin.clone(),
+                    transform,
+                });
+
+                match outcome {
+                    Ok(decrypted) => {
+                        tracing::trace!(origin = %self.origin, "decrypted value");
+                        self.inner = Value::String(StrValue::Secret(decrypted));
+                        1
+                    }
+                    Err(message) => {
+                        tracing::warn!(origin = %self.origin, "{message}");
+                        failures.push(DecryptionFailure {
+                            origin: self.origin.clone(),
+                            message,
+                        });
+                        0
+                    }
+                }
+            }
+            Value::Array(items) => items
+                .iter_mut()
+                .map(|item| item.decrypt_values(decryptor, key_path, failures))
+                .sum(),
+            Value::Object(map) => map
+                .iter_mut()
+                .map(|(key, value)| {
+                    key_path.push(key.clone());
+                    let count = value.decrypt_values(decryptor, key_path, failures);
+                    key_path.pop();
+                    count
+                })
+                .sum(),
+            _ => 0,
+        }
+    }
+
+    /// Finds a value that has failed decryption among this value and its descendants.
+    pub(crate) fn find_decryption_failure<'f>(
+        &self,
+        failures: &'f [DecryptionFailure],
+    ) -> Option<&'f DecryptionFailure> {
+        match &self.inner {
+            Value::String(StrValue::Plain(_)) => failures
+                .iter()
+                .find(|failure| Arc::ptr_eq(&failure.origin, &self.origin)),
+            Value::Array(items) => items
+                .iter()
+                .find_map(|item| item.find_decryption_failure(failures)),
+            Value::Object(map) => map
+                .values()
+                .find_map(|value| value.find_decryption_failure(failures)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use secrecy::ExposeSecret;
+
+    use super::*;
+    use crate::{
+        ConfigRepository, ConfigSchema, DescribeConfig, DeserializeConfig, Environment, config,
+    };
+
+    #[derive(Debug)]
+    struct Reverse;
+
+    impl fmt::Display for Reverse {
+        fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("reverse")
+        }
+    }
+
+    impl Decryptor for Reverse {
+        fn decrypt(&self, value: &EncryptedValue<'_>) -> anyhow::Result<SecretString> {
+            anyhow::ensure!(value.algorithm() == "REV", "unsupported algorithm");
+            let data = value
+                .field("data")
+                .ok_or_else(|| anyhow::anyhow!("missing data"))?;
+            let mut decrypted: String = data.chars().rev().collect();
+            if value.field("path") == Some("true") {
+                decrypted = format!("{}:{decrypted}", value.key_path().join(":"));
+            }
+            Ok(decrypted.into())
+        }
+    }
+
+    #[derive(Debug, DescribeConfig, DeserializeConfig)]
+    #[config(crate = crate)]
+    struct TestConfig {
+        #[config(default)]
+        port: u16,
+        #[config(default)]
+        name: String,
+        #[config(default)]
+        values: Vec<String>,
+    }
+
+    #[test]
+    fn parsing_encrypted_value() {
+        let value =
+            EncryptedValue::parse("ENC[AES256_GCM,data:AbC=,iv:123,tag:456,type:str]").unwrap();
+        assert_eq!(value.algorithm(), "AES256_GCM");
+        assert_eq!(value.field("data"), Some("AbC="));
+        assert_eq!(value.field("type"), Some("str"));
+        assert_eq!(value.field("other"), None);
+
+        assert!(EncryptedValue::parse("ENC[]").is_none());
+        assert!(EncryptedValue::parse("ENC[AES256_GCM,data]").is_none());
+        assert!(EncryptedValue::parse("ENC[AES256_GCM").is_none());
+        assert!(EncryptedValue::parse("plain").is_none());
+    }
+
+    #[test]
+    fn decrypting_values() {
+        let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
+        let json = config!(
+            "test.port": "ENC[REV,data:0808]",
+            "test.name": "ENC[REV,data:terces,path:true]",
+            "test.values": ["plain", "ENC[REV,data:cba,path:true]"],
+        );
+        let repo = ConfigRepository::new(&schema)
+            .with_decryptor(Reverse)
+            .with(json);
+
+        let name = repo.merged().pointer("test.name").unwrap();
+        assert_matches!(
+            &name.inner,
+            Value::String(StrValue::Secret(s)) if s.expose_secret() == "test:name:secret"
+        );
+        assert_matches!(
+            name.origin.as_ref(),
+            ValueOrigin::Synthetic { transform, .. } if transform == "decrypted from REV using reverse"
+        );
+
+        let config: TestConfig = repo.single().unwrap().parse().unwrap();
+        assert_eq!(config.port, 8_080);
+        assert_eq!(config.name, "test:name:secret");
+        assert_eq!(config.values, ["plain", "test:values:abc"]);
+    }
+
+    #[test]
+    fn decrypting_env_vars() {
+        let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
+        let env =
+            Environment::from_iter("APP_", [("APP_TEST_NAME", "ENC[REV,data:eman,path:true]")]);
+        let repo = ConfigRepository::new(&schema)
+            .with_decryptor(Reverse)
+            .with(env);
+        let config: TestConfig = repo.single().unwrap().parse().unwrap();
+        assert_eq!(config.name, "test_name:name");
+    }
+
+    #[test]
+    fn decryption_failures_are_reported_per_param() {
+        let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
+        let json = config!(
+            "test.port": "ENC[REV,data:0808]",
+            "test.name": "ENC[AES256_GCM,data:terces]",
+            "test.values": ["ENC[REV,iv:123]"],
+        );
+        let repo = ConfigRepository::new(&schema)
+            .with_decryptor(Reverse)
+            .with(json);
+        let errors = repo.single::<TestConfig>().unwrap().parse().unwrap_err();
+        assert_eq!(errors.len(), 2, "{errors:#?}");
+
+        let name_err = errors.iter().find(|err| err.path() == "test.name").unwrap();
+        let inner = name_err.inner().to_string();
+        assert!(
+            inner.contains("failed decrypting AES256_GCM value using reverse")
+                && inner.contains("unsupported algorithm"),
+            "{inner}"
+        );
+        assert_eq!(name_err.param().unwrap().name, "name");
+        assert_matches!(
+            name_err.origin(),
+            ValueOrigin::Synthetic { transform, .. } if transform == "failed decryption"
+        );
+
+        let values_err = errors
+            .iter()
+            .find(|err| err.path() == "test.values")
+            .unwrap();
+        let inner = values_err.inner().to_string();
+        assert!(inner.contains("missing data"), "{inner}");
+    }
+
+    #[test]
+    fn overriding_failed_value() {
+        let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
+        let json = config!("test.name": "ENC[AES256_GCM,data:terces]");
+        let overrides = config!("test.name": "ok");
+        let repo = ConfigRepository::new(&schema)
+            .with_decryptor(Reverse)
+            .with(json)
+            .with(overrides);
+        let config: TestConfig = repo.single().unwrap().parse().unwrap();
+        assert_eq!(config.name, "ok");
+    }
+
+    #[test]
+    fn values_are_not_decrypted_without_decryptor() {
+        let schema = ConfigSchema::new(&TestConfig::DESCRIPTION, "test");
+        let json = config!("test.name": "ENC[REV,data:terces]");
+        let repo = ConfigRepository::new(&schema).with(json);
+        let config: TestConfig = repo.single().unwrap().parse().unwrap();
+        assert_eq!(config.name, "ENC[REV,data:terces]");
+    }
+}
@@ -6,6 +6,7 @@ use serde::{de, de::Error};
 
 use crate::{
     metadata::{ConfigMetadata, ParamMetadata},
+    validation::Severity,
     value::{ValueOrigin, WithOrigin},
 };
 
@@ -33,6 +34,8 @@ pub enum ParseErrorCategory {
     Generic,
     /// Missing field (parameter / config) error.
     MissingField,
+    /// Usage of a deprecated param, nested config or enum variant.
+    Deprecated,
 }
 
 /// Low-level deserialization error.
@@ -126,6 +129,7 @@ pub struct ParseError {
     pub(crate) config: &'static ConfigMetadata,
     pub(crate) location_in_config: Option<LocationInConfig>,
     pub(crate) validation: Option<String>,
+    pub(crate) severity: Severity,
 }
 
 impl fmt::Debug for ParseError {
@@ -138,6 +142,7 @@ impl fmt::Debug for ParseError {
             .field("config.ty", &self.config.ty)
             .field("location_in_config", &self.location_in_config)
             .field("validation", &self.validation)
+            .field("severity", &self.severity)
             .finish_non_exhaustive()
     }
 }
@@ -166,9 +171,15 @@ impl fmt::Display for ParseError {
             "parsing".to_owned()
         };
 
+        let label = match self.severity {
+            Severity::Info => "info",
+            Severity::Warn => "warning",
+            _ => "error",
+        };
+
         write!(
             formatter,
-            "error {failed_action} {field}`{config}` at `{path}`{origin}: {err}",
+            "{label} {failed_action} {field}`{config}` at `{path}`{origin}: {err}",
             err = self.inner,
             config = self.config.ty.name_in_code(),
             path = self.path
@@ -192,6 +203,7 @@ impl ParseError {
             config,
             location_in_config: None,
             validation: None,
+            severity: Severity::Deny,
         }
     }
 
@@ -220,6 +232,12 @@ impl ParseError {
         self.validation.as_deref()
     }
 
+    /// Returns the severity of this error. Only [`Severity::Deny`] errors are fatal; other errors are produced by failed
+    /// non-fatal validations and are reported as [warnings](ParseErrors::warnings()).
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
     /// Returns metadata for the failing config.
     pub fn config(&self) -> &'static ConfigMetadata {
         self.config
@@ -237,11 +255,27 @@ impl ParseError {
 #[derive(Debug, Default)]
 pub struct ParseErrors {
     errors: Vec<ParseError>,
+    warnings: Vec<ParseError>,
 }
 
 impl ParseErrors {
     pub(crate) fn push(&mut self, err: ParseError) {
-        self.errors.push(err);
+        if err.severity.is_fatal() {
+            self.errors.push(err);
+        } else {
+            self.warnings.push(err);
+        }
+    }
+
+    /// Removes non-fatal errors from this collection and returns them.
+    pub fn take_warnings(&mut self) -> Vec<ParseError> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Iterates over non-fatal errors (i.e., ones produced by validations with [`Severity::Warn`]
+    /// or [`Severity::Info`]) encountered during parsing.
+    pub fn warnings(&self) -> impl Iterator<Item = &ParseError> + '_ {
+        self.warnings.iter()
     }
 
     /// Iterates over the contained errors.
@@ -292,7 +326,10 @@ impl FromIterator<ParseError> for Result<(), ParseErrors> {
         if errors.is_empty() {
             Ok(())
         } else {
-            Err(ParseErrors { errors })
+            Err(ParseErrors {
+                errors,
+                warnings: vec![],
+            })
         }
     }
 }
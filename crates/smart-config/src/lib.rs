@@ -44,6 +44,7 @@
 //!   `overrides/` dir in the alphabetic order + env vars).
 //! - Rich and complete deserialization errors including locations and value origins.
 //! - [Built-in support for secret params](de#secrets).
+//! - [Decryption of encrypted values](decrypt) in config sources, so that secrets can be committed in an encrypted form.
 //!
 //! # Crate features
 //!
@@ -53,6 +54,7 @@
 //!
 //! Implements deserialization for basic Ethereum types like [`H256`](primitive_types::H256) (32-byte hash)
 //! and [`U256`](primitive_types::U256) (256-bit unsigned integer).
+//! `U256` can also be used as a backing integer for [`TokenAmount`]s.
 //!
 //! ## `alloy`
 //!
@@ -60,6 +62,82 @@
 //!
 //! Implements deserialization for basic alloy primitive types like [`B256`](alloy::primitives::B256) (32-byte hash)
 //! and [`U256`](alloy::primitives::U256) (256-bit unsigned integer).
+//! Also supports [`Bytes`](alloy::primitives::Bytes), generic [`FixedBytes`](alloy::primitives::FixedBytes),
+//! and opt-in [EIP-55](https://eips.ethereum.org/EIPS/eip-55) checksum validation for addresses via [`ChecksummedAddress`](de::ChecksummedAddress).
+//! Provides [`ChainId`] for EVM chain IDs.
+//! `U256` can also be used as a backing integer for [`TokenAmount`]s.
+//!
+//! ## `url`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for [`Url`](url::Url).
+//!
+//! ## `uuid`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for [`Uuid`](uuid::Uuid).
+//!
+//! ## `semver`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for semantic versions ([`Version`](semver::Version))
+//! and version requirements ([`VersionReq`](semver::VersionReq)).
+//!
+//! ## `ipnet`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for IP networks in the CIDR notation ([`IpNet`](ipnet::IpNet),
+//! [`Ipv4Net`](ipnet::Ipv4Net) and [`Ipv6Net`](ipnet::Ipv6Net)).
+//!
+//! ## `indexmap`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for [`IndexMap`](indexmap::IndexMap) and [`IndexSet`](indexmap::IndexSet),
+//! which preserve the order of entries in arrays and delimited strings. Since objects are ordered by key, `IndexMap`s
+//! must be specified as arrays of `[key, value]` pairs rather than objects.
+//!
+//! ## `chrono`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for RFC 3339 timestamps ([`DateTime`](chrono::DateTime) with [`Utc`](chrono::Utc)
+//! or [`FixedOffset`](chrono::FixedOffset) timezone), times of day ([`NaiveTime`](chrono::NaiveTime))
+//! and UTC offsets ([`FixedOffset`](chrono::FixedOffset)). Unix timestamps can be deserialized
+//! using a [`TimeUnit`](metadata::TimeUnit) as the param deserializer.
+//!
+//! ## `time`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for RFC 3339 timestamps ([`OffsetDateTime`](time::OffsetDateTime)),
+//! times of day ([`Time`](time::Time)) and UTC offsets ([`UtcOffset`](time::UtcOffset)).
+//! Unix timestamps can be deserialized using a [`TimeUnit`](metadata::TimeUnit) as the param deserializer.
+//!
+//! ## `tracing-subscriber`
+//!
+//! *(Off by default)*
+//!
+//! Implements deserialization for [`EnvFilter`](tracing_subscriber::EnvFilter), i.e. `RUST_LOG`-style
+//! filtering directives. Directives are validated during deserialization.
+//!
+//! ## `encryption`
+//!
+//! *(Off by default)*
+//!
+//! Provides the [`KeyFile`](decrypt::KeyFile) decryptor for `sops`-style `AES256_GCM` [encrypted values](decrypt)
+//! using a data key stored in a local file.
+//!
+//! ## `tls`
+//!
+//! *(Off by default)*
+//!
+//! Provides the [`tls`] module with PEM-encoded [certificates](tls::PemCertificates) and [private keys](tls::PemPrivateKey),
+//! which can be specified either inline or as a path to a PEM file.
 //!
 //! # Examples
 //!
@@ -153,13 +231,20 @@ pub use self::{
         ConfigParser, ConfigRepository, ConfigSource, ConfigSourceKind, ConfigSources, Environment,
         Flat, Hierarchical, Json, Prefixed, SerializerOptions, SourceInfo, Yaml,
     },
-    types::{ByteSize, EtherAmount},
+    types::{
+        Bandwidth, ByteSize, EtherAmount, HostPort, Rate, Ratio, SensitiveUrl, TokenAmount,
+        TokenInt,
+    },
+    utils::Decimal,
 };
 use self::{metadata::ConfigMetadata, visit::VisitConfig};
+#[cfg(feature = "alloy")]
+pub use crate::types::ChainId;
 
 #[cfg(feature = "_docs")]
 pub mod _docs;
 pub mod de;
+pub mod decrypt;
 mod error;
 pub mod fallback;
 pub mod metadata;
@@ -169,6 +254,9 @@ mod source;
 pub mod testing;
 #[cfg(test)]
 mod testonly;
+#[cfg(feature = "tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+pub mod tls;
 mod types;
 mod utils;
 pub mod validation;
@@ -4,11 +4,13 @@ use std::{any, marker::PhantomData};
 
 use compile_fmt::{Ascii, CompileArgs, clip, clip_ascii, compile_args, compile_panic};
 
-use super::{ConfigMetadata, NestedConfigMetadata, ParamMetadata};
+use super::{
+    ConfigMetadata, ConfigVariant, NestedConfigMetadata, ParamMetadata, TagRepresentation,
+};
 use crate::{
     DeserializeConfig, DeserializeConfigError,
     de::DeserializeContext,
-    utils::const_eq,
+    utils::{EnumVariant, const_eq},
     visit::{ConfigVisitor, VisitConfig},
 };
 
@@ -59,11 +61,21 @@ const fn is_valid_name_char(ch: u8) -> bool {
     ch == b'_' || ch.is_ascii_lowercase() || ch.is_ascii_digit()
 }
 
+const fn is_valid_start_env_var_char(ch: u8) -> bool {
+    ch == b'_' || ch.is_ascii_uppercase()
+}
+
+const fn is_valid_env_var_char(ch: u8) -> bool {
+    ch == b'_' || ch.is_ascii_uppercase() || ch.is_ascii_digit()
+}
+
 #[derive(Debug, Clone, Copy)]
 enum AllowedChars {
     NameStart,
     Name,
     Path,
+    EnvVarStart,
+    EnvVar,
 }
 
 impl AllowedChars {
@@ -72,6 +84,8 @@ impl AllowedChars {
             Self::NameStart => "[_a-z]",
             Self::Name => "[_a-z0-9]",
             Self::Path => "[_a-z0-9.]",
+            Self::EnvVarStart => "[_A-Z]",
+            Self::EnvVar => "[_A-Z0-9]",
         })
     }
 }
@@ -140,6 +154,36 @@ const fn validate_name(name: &str) -> Result<(), ValidationError> {
     Ok(())
 }
 
+const fn validate_env_var_name(name: &str) -> Result<(), ValidationError> {
+    if name.is_empty() {
+        return Err(ValidationError::Empty);
+    }
+
+    let name_bytes = name.as_bytes();
+    let mut pos = 0;
+    while pos < name.len() {
+        if name_bytes[pos] > 127 {
+            return Err(ValidationError::NonAscii { pos });
+        }
+        let ch = name_bytes[pos];
+        let is_disallowed =
+            (pos == 0 && !is_valid_start_env_var_char(ch)) || !is_valid_env_var_char(ch);
+        if is_disallowed {
+            return Err(ValidationError::DisallowedChar {
+                pos,
+                ch: ch as char,
+                allowed: if pos == 0 {
+                    AllowedChars::EnvVarStart
+                } else {
+                    AllowedChars::EnvVar
+                },
+            });
+        }
+        pos += 1;
+    }
+    Ok(())
+}
+
 /// Checks that a param name is valid.
 #[track_caller]
 pub const fn assert_param_name(name: &str) {
@@ -151,6 +195,98 @@ pub const fn assert_param_name(name: &str) {
     }
 }
 
+/// Checks that an explicit env var name for a param is valid.
+#[track_caller]
+pub const fn assert_env_var_name(name: &str) {
+    if let Err(err) = validate_env_var_name(name) {
+        compile_panic!(
+            "Env var name `", name => clip(32, "…"), "` is invalid: ",
+            &err.fmt() => compile_fmt::fmt::<&ErrorArgs>()
+        );
+    }
+}
+
+/// Checks that an application version in a deprecation attribute is valid, i.e. can be parsed
+/// by [`Deprecation::is_removed_in()`](super::Deprecation::is_removed_in()).
+#[track_caller]
+pub const fn assert_version(version: &str) {
+    if !is_valid_version(version.as_bytes()) {
+        compile_panic!(
+            "Version `", version => clip(32, "…"),
+            "` is invalid; expected a semantic version with optional minor / patch components, e.g. `1.0` or `0.9.3-rc.1`"
+        );
+    }
+}
+
+/// Mirrors `semver` validation for an optionally shortened version.
+const fn is_valid_version(bytes: &[u8]) -> bool {
+    let mut pos = 0;
+    if !bytes.is_empty() && bytes[0] == b'v' {
+        pos += 1;
+    }
+
+    // Parse 1-3 numeric components without leading zeros.
+    let mut component_count = 0;
+    loop {
+        let start = pos;
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        if pos == start || (bytes[start] == b'0' && pos - start > 1) {
+            return false;
+        }
+        component_count += 1;
+        if pos < bytes.len() && bytes[pos] == b'.' && component_count < 3 {
+            pos += 1;
+        } else {
+            break;
+        }
+    }
+
+    if pos < bytes.len() && bytes[pos] == b'-' {
+        pos = match skip_version_identifiers(bytes, pos + 1, true) {
+            Some(pos) => pos,
+            None => return false,
+        };
+    }
+    if pos < bytes.len() && bytes[pos] == b'+' {
+        pos = match skip_version_identifiers(bytes, pos + 1, false) {
+            Some(pos) => pos,
+            None => return false,
+        };
+    }
+    pos == bytes.len()
+}
+
+/// Skips dot-separated pre-release or build identifiers, returning the position after them.
+const fn skip_version_identifiers(
+    bytes: &[u8],
+    mut pos: usize,
+    is_pre_release: bool,
+) -> Option<usize> {
+    loop {
+        let start = pos;
+        let mut is_numeric = true;
+        while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'-') {
+            is_numeric = is_numeric && bytes[pos].is_ascii_digit();
+            pos += 1;
+        }
+        if pos == start {
+            return None;
+        }
+        // Numeric pre-release identifiers cannot have leading zeros.
+        if is_pre_release && is_numeric && bytes[start] == b'0' && pos - start > 1 {
+            return None;
+        }
+
+        if pos < bytes.len() && bytes[pos] == b'.' {
+            pos += 1;
+        } else {
+            return Some(pos);
+        }
+    }
+}
+
 #[track_caller]
 pub const fn assert_param_alias(name: &str) {
     let mut path_start = None;
@@ -268,7 +404,7 @@ const fn assert_param_name_is_not_a_config(
             // Flattened config; recurse.
             assert_param_name_is_not_a_config(param_parent, param, nested.meta);
         } else {
-            assert_param_against_config(param_parent, param, config.ty.name_in_code(), nested);
+            assert_param_against_config(param_parent, param, config.ty.declared_name(), nested);
         }
         config_i += 1;
     }
@@ -284,7 +420,7 @@ const fn assert_config_name_is_not_a_param(
     while config_i < configs.len() {
         let flattened = &configs[config_i];
         if flattened.name.is_empty() {
-            let param_parent = flattened.meta.ty.name_in_code();
+            let param_parent = flattened.meta.ty.declared_name();
             let params = flattened.meta.params;
             let mut param_i = 0;
             while param_i < params.len() {
@@ -299,6 +435,22 @@ const fn assert_config_name_is_not_a_param(
     }
 }
 
+#[track_caller]
+const fn assert_external_variant_key(
+    config_name: &'static str,
+    variant: &ConfigVariant,
+    name: &'static str,
+) {
+    if !EnumVariant::is_valid(name) {
+        compile_panic!(
+            "Name / alias `", name => clip(32, "…"), "` of variant `",
+            config_name => clip(32, "…"), "::", variant.rust_name => clip(32, "…"),
+            "` cannot be converted to an object key for an externally tagged enum; \
+            use ASCII alphanumeric names in a single case (e.g., `snake_case`, `kebab-case` or `CamelCase`)"
+        );
+    }
+}
+
 impl ConfigMetadata {
     #[track_caller]
     pub const fn assert_valid(&self) {
@@ -307,13 +459,41 @@ impl ConfigMetadata {
         // doesn't logically make sense, and accounting for it would make merging / deserialization logic unreasonably complex.
         self.assert_params_are_not_configs();
         self.assert_configs_are_not_params();
+        self.assert_external_variant_keys();
+    }
+
+    /// Checks that variant names and aliases of an externally tagged enum can be converted to object keys.
+    #[track_caller]
+    const fn assert_external_variant_keys(&self) {
+        let Some(tag) = &self.tag else {
+            return;
+        };
+        if !matches!(tag.representation, TagRepresentation::External) {
+            return;
+        }
+
+        let mut variant_i = 0;
+        while variant_i < tag.variants.len() {
+            let variant = &tag.variants[variant_i];
+            assert_external_variant_key(self.ty.declared_name(), variant, variant.name);
+            let mut alias_i = 0;
+            while alias_i < variant.aliases.len() {
+                assert_external_variant_key(
+                    self.ty.declared_name(),
+                    variant,
+                    variant.aliases[alias_i],
+                );
+                alias_i += 1;
+            }
+            variant_i += 1;
+        }
     }
 
     #[track_caller]
     const fn assert_params_are_not_configs(&self) {
         let mut param_i = 0;
         while param_i < self.params.len() {
-            assert_param_name_is_not_a_config(self.ty.name_in_code(), &self.params[param_i], self);
+            assert_param_name_is_not_a_config(self.ty.declared_name(), &self.params[param_i], self);
             param_i += 1;
         }
     }
@@ -325,7 +505,7 @@ impl ConfigMetadata {
             let config = &self.nested_configs[config_i];
             if !config.name.is_empty() {
                 assert_config_name_is_not_a_param(
-                    self.ty.name_in_code(),
+                    self.ty.declared_name(),
                     config,
                     self.nested_configs,
                 );
@@ -434,6 +614,7 @@ mod tests {
     use assert_matches::assert_matches;
 
     use super::*;
+    use crate::utils::parse_version;
 
     #[test]
     fn validating_paths() {
@@ -454,6 +635,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validating_env_var_names() {
+        validate_env_var_name("LEGACY_PORT").unwrap();
+        validate_env_var_name("_APP_PORT2").unwrap();
+
+        assert_matches!(
+            validate_env_var_name("").unwrap_err(),
+            ValidationError::Empty
+        );
+        assert_matches!(
+            validate_env_var_name("legacy_port").unwrap_err(),
+            ValidationError::DisallowedChar {
+                pos: 0,
+                ch: 'l',
+                ..
+            }
+        );
+        assert_matches!(
+            validate_env_var_name("2PORT").unwrap_err(),
+            ValidationError::DisallowedChar { pos: 0, .. }
+        );
+        assert_matches!(
+            validate_env_var_name("APP.PORT").unwrap_err(),
+            ValidationError::DisallowedChar {
+                pos: 3,
+                ch: '.',
+                ..
+            }
+        );
+    }
+
+    #[test]
+    fn validating_versions() {
+        let versions = [
+            "1",
+            "1.0",
+            "0.9.3",
+            "v1.2.0",
+            "1.0-rc.1",
+            "1.0.0-rc.1+build.5",
+            "1.0+01",
+            "1.0.0-x-y.0",
+            "",
+            "v",
+            "1.",
+            "1..0",
+            "01.0",
+            "1.0.0.1",
+            "1.0.x",
+            "1.0-",
+            "1.0-rc..1",
+            "1.0-01",
+            "1.0+",
+            "V1.0",
+        ];
+        for version in versions {
+            let is_valid = parse_version(version).is_ok();
+            assert_eq!(is_valid_version(version.as_bytes()), is_valid, "{version}");
+        }
+    }
+
     #[test]
     fn checking_prefix_relations() {
         assert!(have_prefix_relation("test", "test.path"));
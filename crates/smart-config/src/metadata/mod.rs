@@ -1,13 +1,17 @@
 //! Configuration metadata.
 
-use std::{any, borrow::Cow, fmt, ops, time::Duration};
+use std::{any, borrow::Cow, fmt, iter, ops, time::Duration};
+
+use anyhow::Context as _;
 
 use self::_private::{BoxedDeserializer, BoxedVisitor};
 use crate::{
     de::{_private::ErasedDeserializer, DeserializeParam},
     fallback::FallbackSource,
     pat::PatternDisplay,
-    validation::Validate,
+    utils::{EnumVariant, parse_version},
+    validation::{Severity, Validate},
+    value::{StrValue, Value, WithOrigin},
 };
 
 #[doc(hidden)] // used in the derive macros
@@ -54,6 +58,154 @@ impl AliasOptions {
     }
 }
 
+/// Deprecation info for a param, nested config or enum config variant.
+///
+/// Usages of deprecated items are logged and reported as [warnings](crate::ParseErrors::warnings()) during deserialization.
+/// If the item is removed in an application version (see [`DeserializerOptions::set_app_version()`](crate::de::DeserializerOptions::set_app_version())),
+/// usages are reported as errors instead.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+#[non_exhaustive]
+pub struct Deprecation {
+    /// Human-readable note, e.g. a hint on the replacement.
+    pub note: Option<&'static str>,
+    /// Application version since which the item is deprecated.
+    pub since: Option<&'static str>,
+    /// Application version in which the item is removed.
+    pub remove_in: Option<&'static str>,
+}
+
+impl Deprecation {
+    /// Creates deprecation info without any details.
+    pub const fn new() -> Self {
+        Self {
+            note: None,
+            since: None,
+            remove_in: None,
+        }
+    }
+
+    /// Sets the human-readable note.
+    #[must_use]
+    pub const fn with_note(mut self, note: &'static str) -> Self {
+        self.note = Some(note);
+        self
+    }
+
+    /// Sets the version since which the item is deprecated.
+    #[must_use]
+    pub const fn since(mut self, version: &'static str) -> Self {
+        self.since = Some(version);
+        self
+    }
+
+    /// Sets the version in which the item is removed.
+    #[must_use]
+    pub const fn remove_in(mut self, version: &'static str) -> Self {
+        self.remove_in = Some(version);
+        self
+    }
+
+    /// Checks whether the item is removed in the specified application version, i.e., whether the version
+    /// is greater or equal to [`Self::remove_in`]. Versions are compared according to [semantic versioning](https://semver.org/),
+    /// e.g. a pre-release precedes the corresponding release. Unlike with semantic versions, a version may have a `v` prefix,
+    /// and minor / patch components may be omitted (e.g., `1.0`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either of the versions cannot be parsed.
+    pub fn is_removed_in(&self, app_version: &str) -> anyhow::Result<bool> {
+        let app_version = parse_version(app_version)
+            .with_context(|| format!("invalid app version `{app_version}`"))?;
+        self.is_removed_in_version(&app_version)
+    }
+
+    pub(crate) fn is_removed_in_version(
+        &self,
+        app_version: &semver::Version,
+    ) -> anyhow::Result<bool> {
+        let Some(remove_in) = self.remove_in else {
+            return Ok(false);
+        };
+        let remove_in = parse_version(remove_in)
+            .with_context(|| format!("invalid removal version `{remove_in}`"))?;
+        // Build metadata is ignored when comparing versions.
+        let app_version = (
+            app_version.major,
+            app_version.minor,
+            app_version.patch,
+            &app_version.pre,
+        );
+        let remove_in = (
+            remove_in.major,
+            remove_in.minor,
+            remove_in.patch,
+            &remove_in.pre,
+        );
+        Ok(app_version >= remove_in)
+    }
+}
+
+impl fmt::Display for Deprecation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("deprecated")?;
+        if let Some(since) = self.since {
+            write!(formatter, " since {since}")?;
+        }
+        if let Some(remove_in) = self.remove_in {
+            write!(formatter, ", removed in {remove_in}")?;
+        }
+        if let Some(note) = self.note {
+            write!(formatter, "; {note}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Default value of a param computed from other params of the same config. Specified via the `default_from` attribute
+/// in the [derive macro](macro@crate::DescribeConfig).
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct ComputedDefault {
+    /// Canonical names of params in the same config the default value is computed from.
+    pub dependencies: &'static [&'static str],
+    /// Human-readable description of the computation, e.g. ``"`port` + 1"``.
+    pub description: Option<&'static str>,
+}
+
+impl ComputedDefault {
+    /// Creates a computed default with the specified dependencies.
+    pub const fn new(dependencies: &'static [&'static str]) -> Self {
+        Self {
+            dependencies,
+            description: None,
+        }
+    }
+
+    /// Sets the human-readable description of the computation.
+    #[must_use]
+    pub const fn with_description(mut self, description: &'static str) -> Self {
+        self.description = Some(description);
+        self
+    }
+}
+
+impl fmt::Display for ComputedDefault {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(description) = self.description {
+            return write!(formatter, "computed as {description}");
+        }
+        formatter.write_str("computed from ")?;
+        for (i, dep) in self.dependencies.iter().enumerate() {
+            write!(formatter, "`{dep}`")?;
+            if i + 1 < self.dependencies.len() {
+                formatter.write_str(", ")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Metadata for a configuration (i.e., a group of related parameters).
 #[derive(Debug, Clone)]
 pub struct ConfigMetadata {
@@ -67,6 +219,8 @@ pub struct ConfigMetadata {
     pub tag: Option<ConfigTag>,
     /// Nested configs included in the config.
     pub nested_configs: &'static [NestedConfigMetadata],
+    /// Constraints on the presence of params in the config.
+    pub constraints: &'static [ParamConstraint],
     #[doc(hidden)] // implementation detail
     pub deserializer: BoxedDeserializer,
     #[doc(hidden)] // implementation detail
@@ -75,6 +229,45 @@ pub struct ConfigMetadata {
     pub validations: &'static [&'static dyn Validate<dyn any::Any>],
 }
 
+impl ConfigMetadata {
+    /// Returns canonical names of params and nested configs specific to the `variant` of this enum config.
+    /// Params of flattened configs are included recursively. This is used to detect the variant
+    /// of [untagged](TagRepresentation::Untagged) enum configs.
+    pub fn variant_keys(&self, variant: &ConfigVariant) -> Vec<&'static str> {
+        let is_variant = |tag_variant: Option<&ConfigVariant>| {
+            tag_variant.is_some_and(|tag_variant| tag_variant.rust_name == variant.rust_name)
+        };
+        let params = self
+            .params
+            .iter()
+            .filter(|param| is_variant(param.tag_variant))
+            .map(|param| param.name);
+        let mut keys: Vec<_> = params.collect();
+        for nested in self.nested_configs {
+            if !is_variant(nested.tag_variant) {
+                continue;
+            }
+            if nested.name.is_empty() {
+                nested.meta.collect_flattened_keys(&mut keys);
+            } else {
+                keys.push(nested.name);
+            }
+        }
+        keys
+    }
+
+    fn collect_flattened_keys(&self, keys: &mut Vec<&'static str>) {
+        keys.extend(self.params.iter().map(|param| param.name));
+        for nested in self.nested_configs {
+            if nested.name.is_empty() {
+                nested.meta.collect_flattened_keys(keys);
+            } else {
+                keys.push(nested.name);
+            }
+        }
+    }
+}
+
 /// Information about a config tag.
 #[derive(Debug, Clone, Copy)]
 pub struct ConfigTag {
@@ -84,6 +277,21 @@ pub struct ConfigTag {
     pub variants: &'static [ConfigVariant],
     /// Default variant, if any.
     pub default_variant: Option<&'static ConfigVariant>,
+    /// Representation of the enum config in config sources.
+    pub representation: TagRepresentation,
+}
+
+/// Representation of an enum config in config sources, similar to enum representations in `serde`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TagRepresentation {
+    /// Variant is specified by a tag param in the config object, e.g. `{ "type": "S3", "bucket": "..." }`.
+    Internal,
+    /// Variant is specified by the key of the config object holding variant params, e.g. `{ "s3": { "bucket": "..." } }`.
+    /// Variant keys are listed by [`ConfigVariant::external_keys()`].
+    External,
+    /// Variant is detected by which params are present in the config object, e.g. `{ "bucket": "..." }`.
+    Untagged,
 }
 
 /// Variant of a [`ConfigTag`].
@@ -97,6 +305,144 @@ pub struct ConfigVariant {
     pub rust_name: &'static str,
     /// Human-readable param help parsed from the doc comment.
     pub help: &'static str,
+    /// Deprecation info for the variant.
+    pub deprecation: Option<Deprecation>,
+}
+
+impl ConfigVariant {
+    /// Returns keys corresponding to this variant in the externally tagged representation (i.e., the variant name
+    /// and its aliases converted to `snake_case`). The keys are used for [`TagRepresentation::External`] tags,
+    /// and for coercing `serde`-like enums if [enabled](crate::ConfigSchema::coerce_serde_enums()) in the schema.
+    pub fn external_keys(&self) -> impl Iterator<Item = String> + Clone + '_ {
+        iter::once(self.name)
+            .chain(self.aliases.iter().copied())
+            .filter_map(|name| Some(EnumVariant::new(name)?.to_snake_case()))
+    }
+}
+
+/// Constraint on the presence of params in a config, such as params that must be set together or conflicting params.
+///
+/// Constraints are declared using `required_with`, `required_if`, `conflicts_with` and `one_of_required` attributes
+/// of the [`DescribeConfig`](macro@crate::DescribeConfig) derive macro. A param is considered *set* if its value
+/// is not `None` after deserialization. Constraints are checked after the config is deserialized.
+#[derive(Debug, Clone, Copy)]
+pub struct ParamConstraint {
+    /// Kind of the constraint together with the participating params.
+    pub kind: ParamConstraintKind,
+    #[doc(hidden)] // implementation detail
+    pub is_satisfied: fn(&dyn any::Any) -> bool,
+}
+
+impl fmt::Display for ParamConstraint {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParamConstraintKind::RequiredWith { param, other } => {
+                write!(
+                    formatter,
+                    "`{}` is required if `{}` is set",
+                    param.name, other.name
+                )
+            }
+            ParamConstraintKind::RequiredIf { param, condition } => {
+                write!(formatter, "`{}` is required if {condition}", param.name)
+            }
+            ParamConstraintKind::ConflictsWith { param, other } => {
+                write!(
+                    formatter,
+                    "`{}` conflicts with `{}`",
+                    param.name, other.name
+                )
+            }
+            ParamConstraintKind::OneOfRequired { params } => {
+                formatter.write_str("at least one of ")?;
+                write_param_names(formatter, params)?;
+                formatter.write_str(" is required")
+            }
+        }
+    }
+}
+
+fn write_param_names(formatter: &mut fmt::Formatter<'_>, params: &[&ParamMetadata]) -> fmt::Result {
+    for (i, param) in params.iter().enumerate() {
+        if i > 0 {
+            formatter.write_str(", ")?;
+        }
+        write!(formatter, "`{}`", param.name)?;
+    }
+    Ok(())
+}
+
+impl ParamConstraint {
+    /// Returns params participating in this constraint.
+    pub fn params(&self) -> Vec<&'static ParamMetadata> {
+        match self.kind {
+            ParamConstraintKind::RequiredWith { param, other }
+            | ParamConstraintKind::ConflictsWith { param, other } => vec![param, other],
+            ParamConstraintKind::RequiredIf { param, .. } => vec![param],
+            ParamConstraintKind::OneOfRequired { params } => params.to_vec(),
+        }
+    }
+
+    /// Describes a violation of this constraint.
+    pub(crate) fn violation(&self) -> String {
+        struct Names(&'static [&'static ParamMetadata]);
+
+        impl fmt::Display for Names {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write_param_names(formatter, self.0)
+            }
+        }
+
+        match self.kind {
+            ParamConstraintKind::RequiredWith { param, other } => {
+                format!("`{}` is set, but `{}` is not", other.name, param.name)
+            }
+            ParamConstraintKind::RequiredIf { param, condition } => {
+                format!("`{}` is not set although {condition}", param.name)
+            }
+            ParamConstraintKind::ConflictsWith { param, other } => {
+                format!(
+                    "`{}` and `{}` cannot be set simultaneously",
+                    param.name, other.name
+                )
+            }
+            ParamConstraintKind::OneOfRequired { params } => {
+                format!("none of {} is set", Names(params))
+            }
+        }
+    }
+}
+
+/// Kind of [`ParamConstraint`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub enum ParamConstraintKind {
+    /// `param` must be set if `other` is set.
+    RequiredWith {
+        /// Constrained param.
+        param: &'static ParamMetadata,
+        /// Param triggering the requirement.
+        other: &'static ParamMetadata,
+    },
+    /// `param` must be set if a condition on the config holds.
+    RequiredIf {
+        /// Constrained param.
+        param: &'static ParamMetadata,
+        /// Human-readable description of the condition.
+        condition: &'static str,
+    },
+    /// `param` and `other` must not be set simultaneously.
+    ConflictsWith {
+        /// Constrained param.
+        param: &'static ParamMetadata,
+        /// Conflicting param.
+        other: &'static ParamMetadata,
+    },
+    /// At least one of `params` must be set.
+    OneOfRequired {
+        /// Params in the group.
+        params: &'static [&'static ParamMetadata],
+    },
 }
 
 /// Metadata for a specific configuration parameter.
@@ -114,12 +460,19 @@ pub struct ParamMetadata {
     pub rust_type: RustType,
     /// Basic type(s) expected by the param deserializer.
     pub expecting: BasicTypes,
+    /// Explicit env var names bound to this param regardless of its location and env var prefix, in the decreasing priority order.
+    /// Specified via `env` / `env_alias` attributes in the [derive macro](macro@crate::DescribeConfig).
+    pub env_vars: &'static [&'static str],
     /// Tag variant in the enclosing [`ConfigMetadata`] that enables this parameter. `None` means that the parameter is unconditionally enabled.
     pub tag_variant: Option<&'static ConfigVariant>,
+    /// Deprecation info for the param. Not to be confused with deprecated param aliases.
+    pub deprecation: Option<Deprecation>,
     #[doc(hidden)] // implementation detail
     pub deserializer: &'static dyn ErasedDeserializer,
     #[doc(hidden)] // implementation detail
     pub default_value: Option<fn() -> Box<dyn any::Any>>,
+    /// Default value computed from other params of the same config. Mutually exclusive with a [fixed default](Self::default_value()).
+    pub computed_default: Option<ComputedDefault>,
     #[doc(hidden)] // implementation detail
     pub example_value: Option<fn() -> Box<dyn any::Any>>,
     #[doc(hidden)]
@@ -159,11 +512,13 @@ impl ParamMetadata {
 pub struct RustType {
     id: fn() -> any::TypeId,
     name_in_code: &'static str,
+    /// For generic types, returns the type name with concrete generic args.
+    concrete_name: Option<fn() -> &'static str>,
 }
 
 impl fmt::Debug for RustType {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        formatter.write_str(self.name_in_code)
+        formatter.write_str(self.name_in_code())
     }
 }
 
@@ -180,16 +535,45 @@ impl RustType {
         Self {
             id: any::TypeId::of::<T>,
             name_in_code,
+            concrete_name: None,
         }
     }
 
+    /// Creates a new generic type. Unlike [`Self::of()`], the [name](Self::name_in_code()) of the type
+    /// will include concrete generic args, so that different instantiations of a generic type have different names.
+    #[doc(hidden)] // used in the derive macros
+    #[allow(clippy::incompatible_msrv)] // false positive; `TypeId::of` is referenced, not invoked
+    pub const fn generic<T: 'static>(name_in_code: &'static str) -> Self {
+        Self {
+            id: any::TypeId::of::<T>,
+            name_in_code,
+            concrete_name: Some(Self::concrete_name::<T>),
+        }
+    }
+
+    /// Strips the module path of the outer type from the full type name, e.g.
+    /// `app::config::PoolConfig<alloc::string::String, 3>` -> `PoolConfig<alloc::string::String, 3>`.
+    fn concrete_name<T>() -> &'static str {
+        let full_name = any::type_name::<T>();
+        let args_start = full_name.find('<').unwrap_or(full_name.len());
+        let name_start = full_name[..args_start].rfind("::").map_or(0, |pos| pos + 2);
+        &full_name[name_start..]
+    }
+
     /// Returns the unique ID of this type.
     pub fn id(&self) -> any::TypeId {
         (self.id)()
     }
 
-    /// Returns the name of this type as specified in code.
-    pub const fn name_in_code(&self) -> &'static str {
+    /// Returns the name of this type as specified in code. For generic configs, the name includes
+    /// concrete generic args (e.g., `PoolConfig<alloc::string::String, 3>`); the exact format of generic args
+    /// is not stable, same as with [`any::type_name()`].
+    pub fn name_in_code(&self) -> &'static str {
+        self.concrete_name.map_or(self.name_in_code, |name| name())
+    }
+
+    /// Returns the name of this type as declared in code, without substituting generic args.
+    pub(crate) const fn declared_name(&self) -> &'static str {
         self.name_in_code
     }
 }
@@ -275,6 +659,10 @@ impl fmt::Debug for BasicTypes {
     }
 }
 
+/// Function redacting secret parts of a string value, replacing them with the provided placeholder.
+/// Returns `None` if the string has no secret parts.
+pub type RedactFn = fn(&str, &str) -> Option<String>;
+
 #[derive(Debug, Clone)]
 struct ChildDescription {
     expecting: BasicTypes,
@@ -305,10 +693,17 @@ pub enum TypeSuffixes {
     All,
     /// Duration units like `_sec` or `_millis`. May be prepended with `_in`, e.g. `_in_secs`.
     DurationUnits,
-    /// Byte size units like `_mb` or `_bytes`. May be prepended with `_in`, e.g. `_in_mb`.
+    /// Byte size units like `_mib`, `_gb` or `_bytes`. May be prepended with `_in`, e.g. `_in_mb`.
+    /// Both binary (`_kib`, `_mib`, `_gib`, `_tib`, `_pib`) and decimal SI units (`_kb`, `_mb`, `_gb`, `_tb`) are supported.
     SizeUnits,
     /// Ether units like `_wei` or `_ether`. May be prepended with `_in`, e.g. `_in_wei`.
     EtherUnits,
+    /// Rate units like `_per_sec` or `_per_min`.
+    RateUnits,
+    /// Bandwidth units like `_mib_per_sec` or `_kb_per_s`.
+    BandwidthUnits,
+    /// Ratio units like `_percent` or `_bps`. May be prepended with `_in`, e.g. `_in_percent`.
+    RatioUnits,
 }
 
 #[derive(Debug, Clone)]
@@ -335,7 +730,9 @@ pub struct TypeDescription {
     unit: Option<UnitOfMeasurement>,
     suffixes: Option<TypeSuffixes>,
     pub(crate) is_secret: bool,
-    validations: Vec<String>,
+    redact: Option<RedactFn>,
+    validations: Vec<(String, Severity)>,
+    allowed_values: Option<Vec<String>>,
     deserialize_if: Option<String>,
     items: Option<Items>,
     entries: Option<Entries>,
@@ -364,10 +761,16 @@ impl TypeDescription {
     }
 
     #[doc(hidden)] // exposes implementation details
-    pub fn validations(&self) -> &[String] {
+    pub fn validations(&self) -> &[(String, Severity)] {
         &self.validations
     }
 
+    /// Returns the exhaustive list of allowed values (formatted using `Debug`) if the type is restricted
+    /// to an enumeration by a [validation](Validate::allowed_values()).
+    pub fn allowed_values(&self) -> Option<&[String]> {
+        self.allowed_values.as_deref()
+    }
+
     #[doc(hidden)] // exposes implementation details
     pub fn deserialize_if(&self) -> Option<&str> {
         self.deserialize_if.as_deref()
@@ -434,6 +837,50 @@ impl TypeDescription {
         false
     }
 
+    /// Redacts secret parts of a string value of this type, replacing them with the `placeholder`.
+    /// Returns `None` if the type doesn't have a [redaction function](Self::set_redaction()),
+    /// or if the string has no secret parts.
+    pub fn redact_str(&self, value: &str, placeholder: &str) -> Option<String> {
+        self.redact?(value, placeholder)
+    }
+
+    /// Recursively redacts secret parts of a serialized value of this type, including array items and map values.
+    #[doc(hidden)] // used by the `commands` crate; logically private
+    pub fn redact_json(&self, value: &mut serde_json::Value, placeholder: &str) {
+        self.redact_recursively(value, placeholder);
+    }
+
+    /// Same as [`Self::redact_json()`], but for raw values. Secret strings in the raw value are left as is.
+    #[doc(hidden)] // used by the `commands` crate; logically private
+    pub fn redact_raw_value(&self, value: &mut WithOrigin, placeholder: &str) {
+        self.redact_recursively(value, placeholder);
+    }
+
+    fn redact_recursively<V: Redactable>(&self, value: &mut V, placeholder: &str) {
+        match value.as_redactable() {
+            RedactableMut::Str(s) => {
+                if let Some(redacted) = self.redact_str(s, placeholder) {
+                    *s = redacted;
+                }
+            }
+            RedactableMut::Items(items) => {
+                if let Some((_, item_description)) = self.items() {
+                    for item in items {
+                        item_description.redact_recursively(item, placeholder);
+                    }
+                }
+            }
+            RedactableMut::Values(values) => {
+                if let Some((_, value_description)) = self.values() {
+                    for value in values {
+                        value_description.redact_recursively(value, placeholder);
+                    }
+                }
+            }
+            RedactableMut::Other => { /* no secrets to redact */ }
+        }
+    }
+
     /// Sets human-readable type details.
     pub fn set_details(&mut self, details: impl Into<Cow<'static, str>>) -> &mut Self {
         self.details = Some(details.into());
@@ -453,7 +900,15 @@ impl TypeDescription {
 
     /// Sets validation for the type.
     pub fn set_validations<T>(&mut self, validations: &[&'static dyn Validate<T>]) -> &mut Self {
-        self.validations = validations.iter().map(ToString::to_string).collect();
+        self.validations = validations
+            .iter()
+            .map(|validation| (validation.to_string(), validation.severity()))
+            .collect();
+        // Non-fatal validations don't restrict the value.
+        self.allowed_values = validations
+            .iter()
+            .filter(|validation| (**validation).severity().is_fatal())
+            .find_map(|validation| (**validation).allowed_values());
         self
     }
 
@@ -469,6 +924,15 @@ impl TypeDescription {
         self
     }
 
+    /// Marks the value as partially secret (e.g., a URL with an embedded password). Unlike with [`Self::set_secret()`],
+    /// the value is not hidden as a whole; instead, the provided function is used to redact its secret parts
+    /// when serializing values with a [secret placeholder](crate::SerializerOptions::with_secret_placeholder())
+    /// and in debug output.
+    pub fn set_redaction(&mut self, redact: RedactFn) -> &mut Self {
+        self.redact = Some(redact);
+        self
+    }
+
     /// Adds a description of array items. This only makes sense for params accepting array input.
     pub fn set_items<T: 'static>(&mut self, items: &impl DeserializeParam<T>) -> &mut Self {
         self.items = Some(Items {
@@ -533,6 +997,8 @@ pub struct NestedConfigMetadata {
     pub rust_field_name: &'static str,
     /// Tag variant in the enclosing [`ConfigMetadata`] that enables this parameter. `None` means that the parameter is unconditionally enabled.
     pub tag_variant: Option<&'static ConfigVariant>,
+    /// Deprecation info for the nested config. Cannot be present for flattened configs.
+    pub deprecation: Option<Deprecation>,
     /// Config metadata.
     pub meta: &'static ConfigMetadata,
 }
@@ -552,6 +1018,10 @@ pub struct NestedConfigMetadata {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum TimeUnit {
+    /// Nanosecond (10^-9 seconds).
+    Nanos,
+    /// Microsecond (10^-6 seconds).
+    Micros,
     /// Millisecond (0.001 seconds).
     Millis,
     /// Base unit – second.
@@ -570,6 +1040,8 @@ pub enum TimeUnit {
 impl TimeUnit {
     pub(crate) fn plural(self) -> &'static str {
         match self {
+            TimeUnit::Nanos => "nanoseconds",
+            TimeUnit::Micros => "microseconds",
             TimeUnit::Millis => "milliseconds",
             TimeUnit::Seconds => "seconds",
             TimeUnit::Minutes => "minutes",
@@ -579,9 +1051,51 @@ impl TimeUnit {
         }
     }
 
+    pub(crate) fn singular(self) -> &'static str {
+        match self {
+            Self::Nanos => "nanosecond",
+            Self::Micros => "microsecond",
+            Self::Millis => "millisecond",
+            Self::Seconds => "second",
+            Self::Minutes => "minute",
+            Self::Hours => "hour",
+            Self::Days => "day",
+            Self::Weeks => "week",
+        }
+    }
+
+    /// Short suffix used when serializing durations.
+    pub(crate) const fn suffix(self) -> &'static str {
+        match self {
+            Self::Nanos => "ns",
+            Self::Micros => "us",
+            Self::Millis => "ms",
+            Self::Seconds => "s",
+            Self::Minutes => "min",
+            Self::Hours => "h",
+            Self::Days => "d",
+            Self::Weeks => "w",
+        }
+    }
+
+    pub(crate) const fn nanos_in_unit(self) -> u64 {
+        match self {
+            Self::Nanos => 1,
+            Self::Micros => 1_000,
+            Self::Millis => 1_000_000,
+            Self::Seconds => 1_000_000_000,
+            Self::Minutes => 60 * 1_000_000_000,
+            Self::Hours => 3_600 * 1_000_000_000,
+            Self::Days => 86_400 * 1_000_000_000,
+            Self::Weeks => 7 * 86_400 * 1_000_000_000,
+        }
+    }
+
     /// Multiplies this time unit by the specified factor.
     pub fn checked_mul(self, factor: u64) -> Option<Duration> {
         Some(match self {
+            Self::Nanos => Duration::from_nanos(factor),
+            Self::Micros => Duration::from_micros(factor),
             Self::Millis => Duration::from_millis(factor),
             Self::Seconds => Duration::from_secs(factor),
             Self::Minutes => {
@@ -613,6 +1127,8 @@ impl fmt::Display for TimeUnit {
 impl From<TimeUnit> for Duration {
     fn from(unit: TimeUnit) -> Self {
         match unit {
+            TimeUnit::Nanos => Duration::from_nanos(1),
+            TimeUnit::Micros => Duration::from_micros(1),
             TimeUnit::Millis => Duration::from_millis(1),
             TimeUnit::Seconds => Duration::from_secs(1),
             TimeUnit::Minutes => Duration::from_mins(1),
@@ -644,6 +1160,10 @@ impl ops::Mul<TimeUnit> for u64 {
 }
 
 /// Unit of byte size measurement.
+///
+/// Both binary units (powers of 1,024, such as [`Self::MiB`]) and decimal SI units (powers of 1,000,
+/// such as [`Self::MB`]) are supported. When parsing, `kb`, `mb`, `gb` and `tb` unit names (including
+/// name suffixes like `_mb`) denote decimal units.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
 pub enum SizeUnit {
@@ -655,24 +1175,78 @@ pub enum SizeUnit {
     MiB,
     /// Binary gigabyte (aka gibibyte) = 1,073,741,824 bytes.
     GiB,
+    /// Binary terabyte (aka tebibyte) = 1,024 GiB.
+    TiB,
+    /// Binary petabyte (aka pebibyte) = 1,024 TiB.
+    PiB,
+    /// Decimal kilobyte = 1,000 bytes.
+    KB,
+    /// Decimal megabyte = 1,000,000 bytes.
+    MB,
+    /// Decimal gigabyte = 1,000,000,000 bytes.
+    GB,
+    /// Decimal terabyte = 1,000,000,000,000 bytes.
+    TB,
 }
 
 impl SizeUnit {
+    /// Units in the descending order of their size.
+    pub(crate) const DESCENDING: [Self; 10] = [
+        Self::PiB,
+        Self::TiB,
+        Self::TB,
+        Self::GiB,
+        Self::GB,
+        Self::MiB,
+        Self::MB,
+        Self::KiB,
+        Self::KB,
+        Self::Bytes,
+    ];
+
     pub(crate) const fn as_str(self) -> &'static str {
         match self {
             Self::Bytes => "bytes",
-            Self::KiB => "kilobytes",
-            Self::MiB => "megabytes",
-            Self::GiB => "gigabytes",
+            Self::KiB => "kibibytes",
+            Self::MiB => "mebibytes",
+            Self::GiB => "gibibytes",
+            Self::TiB => "tebibytes",
+            Self::PiB => "pebibytes",
+            Self::KB => "kilobytes",
+            Self::MB => "megabytes",
+            Self::GB => "gigabytes",
+            Self::TB => "terabytes",
+        }
+    }
+
+    /// Short unit designation, such as `MiB` or `GB`.
+    pub(crate) const fn short_str(self) -> &'static str {
+        match self {
+            Self::Bytes => "B",
+            Self::KiB => "KiB",
+            Self::MiB => "MiB",
+            Self::GiB => "GiB",
+            Self::TiB => "TiB",
+            Self::PiB => "PiB",
+            Self::KB => "KB",
+            Self::MB => "MB",
+            Self::GB => "GB",
+            Self::TB => "TB",
         }
     }
 
     pub(crate) const fn value_in_unit(self) -> u64 {
         match self {
             Self::Bytes => 1,
-            Self::KiB => 1_024,
-            Self::MiB => 1_024 * 1_024,
-            Self::GiB => 1_024 * 1_024 * 1_024,
+            Self::KiB => 1 << 10,
+            Self::MiB => 1 << 20,
+            Self::GiB => 1 << 30,
+            Self::TiB => 1 << 40,
+            Self::PiB => 1 << 50,
+            Self::KB => 1_000,
+            Self::MB => 1_000_000,
+            Self::GB => 1_000_000_000,
+            Self::TB => 1_000_000_000_000,
         }
     }
 }
@@ -719,6 +1293,86 @@ impl EtherUnit {
     }
 }
 
+/// Unit of bandwidth measurement, e.g. mebibytes per second.
+///
+/// Can be used to deserialize a [`Bandwidth`](crate::Bandwidth) from a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BandwidthUnit {
+    /// Byte size unit.
+    pub size: SizeUnit,
+    /// Time unit.
+    pub per: TimeUnit,
+}
+
+impl BandwidthUnit {
+    /// Creates a new unit.
+    pub const fn new(size: SizeUnit, per: TimeUnit) -> Self {
+        Self { size, per }
+    }
+}
+
+impl fmt::Display for BandwidthUnit {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} per {}", self.size, self.per.singular())
+    }
+}
+
+/// Unit of ratio measurement.
+///
+/// Can be used to deserialize a [`Ratio`](crate::Ratio) from a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RatioUnit {
+    /// Base unit – plain ratio, e.g. 0.5 for a half.
+    Ratio,
+    /// Percent (0.01).
+    Percent,
+    /// Basis point (0.0001, i.e. 0.01%).
+    BasisPoints,
+}
+
+impl RatioUnit {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Ratio => "ratio",
+            Self::Percent => "percent",
+            Self::BasisPoints => "basis points",
+        }
+    }
+
+    /// Returns the decimal exponent of this unit relative to the base unit.
+    pub(crate) const fn exponent(self) -> i16 {
+        match self {
+            Self::Ratio => 0,
+            Self::Percent => -2,
+            Self::BasisPoints => -4,
+        }
+    }
+}
+
+impl fmt::Display for RatioUnit {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.as_str())
+    }
+}
+
+/// Unit of token amount measurement: a whole token with the specified number of decimals,
+/// e.g. USDC with 6 decimals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenUnit {
+    /// Token symbol, e.g. `usdc`. May be unknown if the token amount accepts any symbol.
+    pub symbol: Option<&'static str>,
+    /// Number of decimals in a whole token, i.e. the whole token is `10^decimals` base units.
+    pub decimals: u8,
+}
+
+impl fmt::Display for TokenUnit {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = self.symbol.unwrap_or("token");
+        write!(formatter, "{symbol} with {} decimals", self.decimals)
+    }
+}
+
 /// General unit of measurement.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -729,6 +1383,14 @@ pub enum UnitOfMeasurement {
     ByteSize(SizeUnit),
     /// Unit of ether amount measurement.
     Ether(EtherUnit),
+    /// Unit of rate measurement, i.e. number of events per the specified time unit.
+    Rate(TimeUnit),
+    /// Unit of bandwidth measurement.
+    Bandwidth(BandwidthUnit),
+    /// Unit of ratio measurement.
+    Ratio(RatioUnit),
+    /// Unit of token amount measurement.
+    Token(TokenUnit),
 }
 
 impl fmt::Display for UnitOfMeasurement {
@@ -737,6 +1399,10 @@ impl fmt::Display for UnitOfMeasurement {
             Self::Time(unit) => fmt::Display::fmt(unit, formatter),
             Self::ByteSize(unit) => fmt::Display::fmt(unit, formatter),
             Self::Ether(unit) => fmt::Display::fmt(unit, formatter),
+            Self::Rate(unit) => write!(formatter, "per {}", unit.singular()),
+            Self::Bandwidth(unit) => fmt::Display::fmt(unit, formatter),
+            Self::Ratio(unit) => fmt::Display::fmt(unit, formatter),
+            Self::Token(unit) => fmt::Display::fmt(unit, formatter),
         }
     }
 }
@@ -758,3 +1424,55 @@ impl From<EtherUnit> for UnitOfMeasurement {
         Self::Ether(unit)
     }
 }
+
+impl From<BandwidthUnit> for UnitOfMeasurement {
+    fn from(unit: BandwidthUnit) -> Self {
+        Self::Bandwidth(unit)
+    }
+}
+
+impl From<RatioUnit> for UnitOfMeasurement {
+    fn from(unit: RatioUnit) -> Self {
+        Self::Ratio(unit)
+    }
+}
+
+impl From<TokenUnit> for UnitOfMeasurement {
+    fn from(unit: TokenUnit) -> Self {
+        Self::Token(unit)
+    }
+}
+
+/// Value that can be redacted by [`TypeDescription`]: either a serialized JSON value, or a raw value.
+trait Redactable {
+    fn as_redactable(&mut self) -> RedactableMut<'_, Self>;
+}
+
+enum RedactableMut<'a, V: ?Sized> {
+    Str(&'a mut String),
+    Items(Box<dyn Iterator<Item = &'a mut V> + 'a>),
+    Values(Box<dyn Iterator<Item = &'a mut V> + 'a>),
+    Other,
+}
+
+impl Redactable for serde_json::Value {
+    fn as_redactable(&mut self) -> RedactableMut<'_, Self> {
+        match self {
+            Self::String(s) => RedactableMut::Str(s),
+            Self::Array(items) => RedactableMut::Items(Box::new(items.iter_mut())),
+            Self::Object(map) => RedactableMut::Values(Box::new(map.values_mut())),
+            _ => RedactableMut::Other,
+        }
+    }
+}
+
+impl Redactable for WithOrigin {
+    fn as_redactable(&mut self) -> RedactableMut<'_, Self> {
+        match &mut self.inner {
+            Value::String(StrValue::Plain(s)) => RedactableMut::Str(s),
+            Value::Array(items) => RedactableMut::Items(Box::new(items.iter_mut())),
+            Value::Object(map) => RedactableMut::Values(Box::new(map.values_mut())),
+            _ => RedactableMut::Other,
+        }
+    }
+}
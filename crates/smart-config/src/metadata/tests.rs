@@ -6,7 +6,11 @@ use super::*;
 use crate::{
     DescribeConfig, ParseErrors, config,
     de::{DeserializeContext, DeserializerOptions},
-    testonly::{ComposedConfig, ConfigWithComplexTypes, DefaultingEnumConfig, EnumConfig},
+    testonly::{
+        ComposedConfig, ConfigWithComplexTypes, ConfigWithComputedDefaults, ConfigWithDeprecations,
+        ConfigWithEnvVars, DefaultingEnumConfig, EnumConfig, ExternalEnumConfig, GenericConfig,
+        ModeConfig, UntaggedEnumConfig,
+    },
 };
 
 #[test]
@@ -85,6 +89,142 @@ fn describing_defaulting_enum_config() {
     assert_eq!(tag.default_variant.unwrap().name, "Second");
 }
 
+#[test]
+fn describing_external_and_untagged_enum_configs() {
+    let metadata = &ExternalEnumConfig::DESCRIPTION;
+    let tag = metadata.tag.unwrap();
+    assert_eq!(tag.representation, TagRepresentation::External);
+    assert_eq!(tag.param.name, "_variant");
+    // The default variant is applied by the tag deserializer.
+    assert!(tag.param.default_value.is_none());
+    assert_eq!(tag.default_variant.unwrap().rust_name, "Memory");
+    let s3_variant = &tag.variants[1];
+    let external_keys: Vec<_> = s3_variant.external_keys().collect();
+    assert_eq!(external_keys, ["s3", "aws"]);
+    assert_eq!(metadata.variant_keys(s3_variant), ["bucket", "region"]);
+
+    let metadata = &UntaggedEnumConfig::DESCRIPTION;
+    let tag = metadata.tag.unwrap();
+    assert_eq!(tag.representation, TagRepresentation::Untagged);
+    assert_eq!(
+        metadata.variant_keys(&tag.variants[2]),
+        ["renamed", "other_int", "map"]
+    );
+}
+
+#[test]
+fn describing_explicit_env_vars() {
+    let [port_param, url_param] = &ConfigWithEnvVars::DESCRIPTION.params else {
+        unreachable!();
+    };
+    assert_eq!(port_param.env_vars, ["LEGACY_PORT", "PORT"]);
+    assert_eq!(url_param.env_vars, ["DATABASE_URL"]);
+    assert!(
+        EnumConfig::DESCRIPTION
+            .params
+            .iter()
+            .all(|param| param.env_vars.is_empty())
+    );
+}
+
+#[test]
+fn describing_deprecations() {
+    let metadata = &ConfigWithDeprecations::DESCRIPTION;
+    let [rps_param, max_rps_param] = &metadata.params else {
+        unreachable!();
+    };
+    let rps_deprecation = rps_param.deprecation.unwrap();
+    assert_eq!(
+        rps_deprecation,
+        Deprecation::new()
+            .with_note("use `max_rps`")
+            .since("0.9")
+            .remove_in("1.0")
+    );
+    assert_eq!(
+        rps_deprecation.to_string(),
+        "deprecated since 0.9, removed in 1.0; use `max_rps`"
+    );
+    assert!(!rps_deprecation.is_removed_in("0.9.5").unwrap());
+    assert!(!rps_deprecation.is_removed_in("1.0.0-rc.1").unwrap());
+    assert!(rps_deprecation.is_removed_in("1.0").unwrap());
+    assert!(rps_deprecation.is_removed_in("v1.0.0").unwrap());
+    assert!(rps_deprecation.is_removed_in("1.0.0+build.5").unwrap());
+    assert!(rps_deprecation.is_removed_in("1.2").unwrap());
+    let err = rps_deprecation.is_removed_in("not a version").unwrap_err();
+    assert!(err.to_string().contains("invalid app version"), "{err:#}");
+    assert_eq!(max_rps_param.deprecation, None);
+
+    let [legacy_config, mode_config] = &metadata.nested_configs else {
+        unreachable!();
+    };
+    assert_eq!(legacy_config.deprecation, Some(Deprecation::new()));
+    assert_eq!(mode_config.deprecation, None);
+
+    let tag = ModeConfig::DESCRIPTION.tag.unwrap();
+    let [fast_variant, slow_variant] = tag.variants else {
+        unreachable!();
+    };
+    assert_eq!(fast_variant.deprecation, None);
+    assert_eq!(
+        slow_variant.deprecation,
+        Some(Deprecation::new().with_note("use `Fast`"))
+    );
+}
+
+#[test]
+fn describing_computed_defaults() {
+    let params = ConfigWithComputedDefaults::DESCRIPTION.params;
+    let [port, metrics_port, debug_port, url, public_url] = params else {
+        unreachable!();
+    };
+    for param in [port, url] {
+        assert!(param.computed_default.is_none());
+    }
+
+    let computed = metrics_port.computed_default.unwrap();
+    assert_eq!(computed.dependencies, ["port"]);
+    assert_eq!(computed.description, Some("`port` + 1"));
+    assert_eq!(computed.to_string(), "computed as `port` + 1");
+    assert!(metrics_port.default_value.is_none());
+
+    let computed = debug_port.computed_default.unwrap();
+    assert_eq!(computed.dependencies, ["metrics_port"]);
+    assert_eq!(computed.description, None);
+    assert_eq!(computed.to_string(), "computed from `metrics_port`");
+
+    let computed = public_url.computed_default.unwrap();
+    assert_eq!(computed.dependencies, ["url", "port"]);
+    assert_eq!(computed.to_string(), "computed from `url`, `port`");
+}
+
+#[test]
+fn describing_generic_configs() {
+    let metadata = &GenericConfig::<String, 3>::DESCRIPTION;
+    assert_eq!(
+        metadata.ty.name_in_code(),
+        "GenericConfig<alloc::string::String, 3>"
+    );
+    assert_eq!(
+        metadata.ty.id(),
+        any::TypeId::of::<GenericConfig<String, 3>>()
+    );
+    let value_param = &metadata.params[0];
+    assert_eq!(value_param.rust_type.id(), any::TypeId::of::<String>());
+    assert_eq!(value_param.expecting, BasicTypes::STRING);
+    assert_eq!(metadata.params[1].default_value_json(), Some(3.into()));
+
+    // Each monomorphization gets its own metadata.
+    let other_metadata = &GenericConfig::<u64, 5>::DESCRIPTION;
+    assert_ne!(metadata.ty, other_metadata.ty);
+    assert_eq!(other_metadata.ty.name_in_code(), "GenericConfig<u64, 5>");
+    assert_eq!(other_metadata.params[0].expecting, BasicTypes::INTEGER);
+    assert_eq!(
+        other_metadata.params[1].default_value_json(),
+        Some(5.into())
+    );
+}
+
 #[test]
 fn describing_complex_types() {
     let metadata = &ConfigWithComplexTypes::DESCRIPTION;
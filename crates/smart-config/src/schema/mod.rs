@@ -3,7 +3,7 @@
 use std::{
     any,
     borrow::Cow,
-    collections::{BTreeMap, BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     iter,
 };
 
@@ -11,11 +11,13 @@ use anyhow::Context;
 
 use self::mount::{MountingPoint, MountingPoints};
 use crate::{
+    ErrorWithOrigin,
     metadata::{
         AliasOptions, BasicTypes, ConfigMetadata, ConfigVariant, NestedConfigMetadata,
-        ParamMetadata,
+        ParamMetadata, TagRepresentation,
     },
     utils::EnumVariant,
+    validation::{ConfigTuple, ParticipatingParam, SchemaValidation},
     value::Pointer,
 };
 
@@ -35,6 +37,7 @@ pub(crate) struct ConfigData {
     parent_link: Option<ParentLink>,
     pub(crate) is_top_level: bool,
     pub(crate) coerce_serde_enums: bool,
+    coerce_param_names: bool,
     all_paths: Vec<(Cow<'static, str>, AliasOptions)>,
 }
 
@@ -57,19 +60,36 @@ impl ConfigData {
         self.all_paths_for_child(param.name, param.aliases, param.tag_variant)
     }
 
+    /// Checks whether variant params are (also) expected to be nested under the variant keys, as for externally tagged enums.
+    pub(crate) fn has_external_tag(&self) -> bool {
+        self.coerce_serde_enums
+            || self
+                .metadata
+                .tag
+                .is_some_and(|tag| tag.representation == TagRepresentation::External)
+    }
+
     fn all_paths_for_child(
         &self,
         name: &'static str,
         aliases: &'static [(&'static str, AliasOptions)],
         tag_variant: Option<&'static ConfigVariant>,
     ) -> impl Iterator<Item = (String, AliasOptions)> + '_ {
-        let local_names =
-            iter::once((name, AliasOptions::default())).chain(aliases.iter().copied());
+        let local_names = iter::once((name, AliasOptions::default()))
+            .chain(aliases.iter().copied())
+            .map(|(name, options)| (Cow::<str>::Borrowed(name), options));
+        let mut local_names: Vec<_> = local_names.collect();
+        if self.coerce_param_names {
+            let spellings = local_names.iter().flat_map(|(name, options)| {
+                name_spellings(name).map(|spelling| (Cow::Owned(spelling), *options))
+            });
+            let spellings: Vec<_> = spellings.collect();
+            local_names.extend(spellings);
+        }
+        let local_names = local_names.into_iter();
 
-        let enum_names = if let (true, Some(variant)) = (self.coerce_serde_enums, tag_variant) {
-            let variant_names = iter::once(variant.name)
-                .chain(variant.aliases.iter().copied())
-                .filter_map(|name| Some(EnumVariant::new(name)?.to_snake_case()));
+        let enum_names = if let (true, Some(variant)) = (self.has_external_tag(), tag_variant) {
+            let variant_names = variant.external_keys();
             let local_names_ = local_names.clone();
             let paths = variant_names.flat_map(move |variant_name| {
                 local_names_
@@ -79,7 +99,7 @@ impl ConfigData {
                             // Only consider simple aliases, not path ones.
                             return None;
                         }
-                        let full_path = Pointer(&variant_name).join(name_or_path);
+                        let full_path = Pointer(&variant_name).join(&name_or_path);
                         Some((Cow::Owned(full_path), options))
                     })
             });
@@ -88,10 +108,11 @@ impl ConfigData {
             None
         };
         let enum_names = enum_names.into_iter().flatten();
-        let local_names = local_names
-            .map(|(name, options)| (Cow::Borrowed(name), options))
-            .chain(enum_names);
+        let local_names = local_names.chain(enum_names);
 
+        // Path aliases and coerced spellings can produce the same full path for multiple config paths,
+        // so we deduplicate paths, retaining the highest-priority one.
+        let mut seen_paths = HashSet::new();
         self.all_paths
             .iter()
             .flat_map(move |(alias, config_options)| {
@@ -102,9 +123,24 @@ impl ConfigData {
                         Some((full_path, options.combine(*config_options)))
                     })
             })
+            .filter(move |(path, _)| seen_paths.insert(path.clone()))
     }
 }
 
+/// Returns `camelCase` and `kebab-case` spellings of a `snake_case` param / config name, skipping ones coinciding with the name.
+fn name_spellings(name: &str) -> impl Iterator<Item = String> {
+    let variant = if name.starts_with('.') {
+        None // Path aliases are not coerced.
+    } else {
+        EnumVariant::new(name)
+    };
+    let spellings = variant.map(|variant| [variant.to_lower_camel_case(), variant.to_kebab_case()]);
+    spellings
+        .into_iter()
+        .flatten()
+        .filter(move |spelling| spelling != name)
+}
+
 /// Reference to a specific configuration inside [`ConfigSchema`].
 #[derive(Debug, Clone, Copy)]
 pub struct ConfigRef<'a> {
@@ -249,6 +285,8 @@ pub struct ConfigSchema {
     configs: BTreeMap<Cow<'static, str>, ConfigsForPrefix>,
     mounting_points: MountingPoints,
     coerce_serde_enums: bool,
+    coerce_param_names: bool,
+    validations: Vec<SchemaValidation>,
 }
 
 impl ConfigSchema {
@@ -269,11 +307,26 @@ impl ConfigSchema {
     /// (`snake_cased` tag + param name) will be added for the param. Tag aliases and param aliases will result
     /// in additional path aliases, as expected. For example, if `param` has alias `alias` and the tag has alias `AliasTag`,
     /// then the param will have `.alias_tag.param`, `.alias_tag.alias` and `.some_tag.alias` aliases.
+    ///
+    /// Enum configs declared with the externally tagged representation (`#[config(tag = external)]`) are always coerced
+    /// in this way, regardless of this setting.
     pub fn coerce_serde_enums(&mut self, coerce: bool) -> &mut Self {
         self.coerce_serde_enums = coerce;
         self
     }
 
+    /// Switches coercing param names to other naming conventions. Coercion will add `camelCase` and `kebab-case` aliases
+    /// for all params and nested configs in configs added to the schema afterward (or until `coerce_param_names(false)` is called).
+    /// Coercion will apply to nested configs as well. Canonical param names remain in `snake_case`.
+    ///
+    /// For example, if a config param is named `poll_latency_ms`, then aliases `pollLatencyMs` and `poll-latency-ms`
+    /// will be added for it. Param aliases will result in additional aliases as well. Since aliases are a part of the schema,
+    /// the accepted spellings are displayed in the config help.
+    pub fn coerce_param_names(&mut self, coerce: bool) -> &mut Self {
+        self.coerce_param_names = coerce;
+        self
+    }
+
     /// Iterates over all configs with their canonical prefixes.
     pub(crate) fn iter_ll(&self) -> impl Iterator<Item = (Pointer<'_>, &ConfigData)> + '_ {
         self.configs
@@ -440,9 +493,10 @@ impl ConfigSchema {
         metadata: &'static ConfigMetadata,
         prefix: &'static str,
     ) -> anyhow::Result<ConfigMut<'_>> {
-        let coerce_serde_enums = self.coerce_serde_enums;
+        let (coerce_serde_enums, coerce_param_names) =
+            (self.coerce_serde_enums, self.coerce_param_names);
         let mut patched = PatchedSchema::new(self);
-        patched.insert_config(prefix, metadata, coerce_serde_enums)?;
+        patched.insert_config(prefix, metadata, coerce_serde_enums, coerce_param_names)?;
         patched.commit();
         Ok(ConfigMut {
             schema: self,
@@ -450,6 +504,119 @@ impl ConfigSchema {
             prefix: prefix.to_owned(),
         })
     }
+
+    /// Registers a validation spanning one or more configs in this schema. This allows checking invariants
+    /// that cannot be expressed with `#[config(validate(..))]` because
+    /// they involve configs mounted at different paths.
+    ///
+    /// The validation is run by [`ConfigRepository::validate()`](crate::ConfigRepository::validate()) after parsing
+    /// all participating configs. If a validation fails, a [`ParseError`](crate::ParseError) is reported
+    /// for each of the specified `params`, or for each participating config if `params` are empty.
+    ///
+    /// - `Cs` is a tuple of participating config types, e.g. `(ApiConfig, MempoolConfig)`.
+    ///   Each config must be registered in the schema at a single location.
+    /// - `params` are canonical absolute paths to params participating in the validation (e.g., `api.max_batch_size`).
+    ///   Each param must belong to one of the participating configs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the participating configs is not registered in the schema or has multiple mount points,
+    /// or if any of `params` doesn't correspond to a param in the participating configs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use smart_config::{DescribeConfig, DeserializeConfig, ErrorWithOrigin};
+    /// use smart_config::{ConfigRepository, ConfigSchema};
+    ///
+    /// #[derive(DescribeConfig, DeserializeConfig)]
+    /// struct ApiConfig {
+    ///     #[config(default_t = 100)]
+    ///     max_batch_size: usize,
+    /// }
+    ///
+    /// #[derive(DescribeConfig, DeserializeConfig)]
+    /// struct MempoolConfig {
+    ///     #[config(default_t = 1_000)]
+    ///     capacity: usize,
+    /// }
+    ///
+    /// let mut schema = ConfigSchema::default();
+    /// schema.insert(&ApiConfig::DESCRIPTION, "api")?;
+    /// schema.insert(&MempoolConfig::DESCRIPTION, "mempool")?;
+    /// schema.validate_configs::<(ApiConfig, MempoolConfig)>(
+    ///     "API batch size must not exceed mempool capacity",
+    ///     &["api.max_batch_size", "mempool.capacity"],
+    ///     |(api, mempool)| {
+    ///         if api.max_batch_size > mempool.capacity {
+    ///             return Err(ErrorWithOrigin::custom(format!(
+    ///                 "batch size {} exceeds mempool capacity {}",
+    ///                 api.max_batch_size, mempool.capacity
+    ///             )));
+    ///         }
+    ///         Ok(())
+    ///     },
+    /// )?;
+    ///
+    /// let input = smart_config::config!("api.max_batch_size": 5_000);
+    /// let repo = ConfigRepository::new(&schema).with(input);
+    /// let errors = repo.validate().unwrap_err();
+    /// let paths: Vec<_> = errors.iter().map(|err| err.path()).collect();
+    /// assert_eq!(paths, ["api.max_batch_size", "mempool.capacity"]);
+    /// # anyhow::Ok(())
+    /// ```
+    pub fn validate_configs<Cs: ConfigTuple>(
+        &mut self,
+        description: &'static str,
+        params: &[&str],
+        validation: impl for<'a> Fn(Cs::Refs<'a>) -> Result<(), ErrorWithOrigin> + Send + Sync + 'static,
+    ) -> anyhow::Result<&mut Self> {
+        let configs = Cs::metadata()
+            .into_iter()
+            .map(|metadata| {
+                let config_ref = self.single(metadata)?;
+                Ok((metadata, config_ref.prefix().to_owned()))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let params = params
+            .iter()
+            .map(|&path| Self::locate_participating_param(&configs, path))
+            .collect::<anyhow::Result<_>>()?;
+
+        self.validations.push(SchemaValidation::new::<Cs>(
+            description,
+            configs,
+            params,
+            validation,
+        ));
+        Ok(self)
+    }
+
+    fn locate_participating_param(
+        configs: &[(&'static ConfigMetadata, String)],
+        path: &str,
+    ) -> anyhow::Result<ParticipatingParam> {
+        for (config_idx, (metadata, prefix)) in configs.iter().enumerate() {
+            let param_idx = metadata
+                .params
+                .iter()
+                .position(|param| Pointer(prefix).join(param.name) == path);
+            if let Some(param_idx) = param_idx {
+                return Ok(ParticipatingParam {
+                    path: path.to_owned(),
+                    config_idx,
+                    param_idx,
+                });
+            }
+        }
+        anyhow::bail!("param `{path}` does not belong to any of the validated configs")
+    }
+
+    /// Iterates over schema-level validations in the order of their registration.
+    pub(crate) fn validations(&self) -> &[SchemaValidation] {
+        &self.validations
+    }
 }
 
 /// [`ConfigSchema`] together with a patch that can be atomically committed.
@@ -480,6 +647,7 @@ impl<'a> PatchedSchema<'a> {
         prefix: &'static str,
         metadata: &'static ConfigMetadata,
         coerce_serde_enums: bool,
+        coerce_param_names: bool,
     ) -> anyhow::Result<()> {
         self.insert_recursively(
             prefix.into(),
@@ -489,6 +657,7 @@ impl<'a> PatchedSchema<'a> {
                 parent_link: None,
                 is_top_level: true,
                 coerce_serde_enums,
+                coerce_param_names,
                 all_paths: vec![(prefix.into(), AliasOptions::new())],
             },
         )
@@ -545,6 +714,7 @@ impl<'a> PatchedSchema<'a> {
                 parent_link: config_data.parent_link,
                 is_top_level: config_data.is_top_level,
                 coerce_serde_enums: config_data.coerce_serde_enums,
+                coerce_param_names: config_data.coerce_param_names,
                 all_paths: vec![(alias.0.into(), options)],
             },
         )
@@ -569,6 +739,7 @@ impl<'a> PatchedSchema<'a> {
                 }),
                 is_top_level: false,
                 coerce_serde_enums: data.coerce_serde_enums,
+                coerce_param_names: data.coerce_param_names,
                 all_paths,
             };
             (prefix.join(nested.name), config_data)
@@ -655,7 +826,13 @@ impl<'a> PatchedSchema<'a> {
         if let Some(prev_data) = prev_data {
             // Append new aliases to the end since their ordering determines alias priority
             let mut all_paths = prev_data.all_paths.clone();
-            all_paths.extend_from_slice(&data.all_paths);
+            let new_paths = data.all_paths.iter().filter(|(path, _)| {
+                !prev_data
+                    .all_paths
+                    .iter()
+                    .any(|(prev_path, _)| prev_path == path)
+            });
+            all_paths.extend(new_paths.cloned());
             data.all_paths = all_paths;
         }
 
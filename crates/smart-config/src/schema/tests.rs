@@ -1,13 +1,16 @@
-use std::collections::HashSet;
+use std::{
+    any,
+    collections::{HashMap, HashSet},
+};
 
 use assert_matches::assert_matches;
 
 use super::*;
 use crate::{
-    ConfigRepository, DescribeConfig, DeserializeConfig, Environment,
+    ConfigRepository, DescribeConfig, DeserializeConfig, Environment, ErrorWithOrigin,
     metadata::BasicTypes,
-    testonly::{AliasedConfig, EnumConfig, NestedAliasedConfig, NestedConfig},
-    value::{StrValue, Value},
+    testonly::{AliasedConfig, CompoundConfig, EnumConfig, NestedAliasedConfig, NestedConfig},
+    value::{StrValue, Value, ValueOrigin},
 };
 
 /// # Test configuration
@@ -549,3 +552,228 @@ fn path_aliases_with_enum_coercion() {
         ]
     );
 }
+
+#[test]
+fn param_name_coercion() {
+    let mut schema = ConfigSchema::default();
+    schema
+        .coerce_param_names(true)
+        .insert(&CompoundConfig::DESCRIPTION, "test")
+        .unwrap();
+
+    let nested_ref = schema
+        .get(&NestedConfig::DESCRIPTION, "test.nested_opt")
+        .unwrap();
+    let nested_aliases: Vec<_> = nested_ref.aliases().map(|(alias, _)| alias).collect();
+    assert_eq!(nested_aliases, ["test.nestedOpt", "test.nested-opt"]);
+
+    let [enum_param, int_param, _] = &NestedConfig::DESCRIPTION.params else {
+        unreachable!();
+    };
+    let int_paths: Vec<_> = nested_ref
+        .all_paths_for_param(int_param)
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(
+        int_paths,
+        [
+            "test.nested_opt.other_int",
+            "test.nested_opt.otherInt",
+            "test.nested_opt.other-int",
+            "test.nestedOpt.other_int",
+            "test.nestedOpt.otherInt",
+            "test.nestedOpt.other-int",
+            "test.nested-opt.other_int",
+            "test.nested-opt.otherInt",
+            "test.nested-opt.other-int",
+        ]
+    );
+
+    // Single-word names and path aliases must not be affected.
+    let enum_paths: Vec<_> = nested_ref
+        .all_paths_for_param(enum_param)
+        .map(|(name, _)| name)
+        .collect();
+    assert_eq!(
+        enum_paths,
+        [
+            "test.nested_opt.renamed",
+            "test.nested_opt.enum",
+            "test.nested_opt.experimental.enum",
+            "test.top.enum",
+            "test.nestedOpt.renamed",
+            "test.nestedOpt.enum",
+            "test.nestedOpt.experimental.enum",
+            "test.nested-opt.renamed",
+            "test.nested-opt.enum",
+            "test.nested-opt.experimental.enum",
+        ]
+    );
+
+    // Each path must be listed only once.
+    for config in schema.iter() {
+        let config_paths: Vec<_> = iter::once(config.prefix())
+            .chain(config.aliases().map(|(alias, _)| alias))
+            .collect();
+        let unique_config_paths: HashSet<_> = config_paths.iter().copied().collect();
+        assert_eq!(
+            unique_config_paths.len(),
+            config_paths.len(),
+            "{config_paths:?}"
+        );
+
+        for param in config.metadata().params {
+            let paths: Vec<_> = config
+                .all_paths_for_param(param)
+                .map(|(path, _)| path)
+                .collect();
+            let unique_paths: HashSet<_> = paths.iter().collect();
+            assert_eq!(unique_paths.len(), paths.len(), "{paths:?}");
+        }
+    }
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+struct ApiConfig {
+    #[config(default_t = 100)]
+    max_batch_size: usize,
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+struct MempoolConfig {
+    #[config(default_t = 1_000)]
+    capacity: usize,
+}
+
+fn schema_with_cross_validation() -> ConfigSchema {
+    let mut schema = ConfigSchema::default();
+    schema.insert(&ApiConfig::DESCRIPTION, "api").unwrap();
+    schema
+        .insert(&MempoolConfig::DESCRIPTION, "mempool")
+        .unwrap();
+    schema
+        .validate_configs::<(ApiConfig, MempoolConfig)>(
+            "batch size must not exceed mempool capacity",
+            &["api.max_batch_size", "mempool.capacity"],
+            |(api, mempool)| {
+                if api.max_batch_size > mempool.capacity {
+                    return Err(ErrorWithOrigin::custom("batch size is too large"));
+                }
+                Ok(())
+            },
+        )
+        .unwrap();
+    schema
+}
+
+#[test]
+fn cross_config_validation() {
+    let schema = schema_with_cross_validation();
+    let env = Environment::from_iter("", [("API_MAX_BATCH_SIZE", "500")]);
+    let repo = ConfigRepository::new(&schema).with(env);
+    repo.validate().unwrap();
+
+    let env = Environment::from_iter(
+        "",
+        [("API_MAX_BATCH_SIZE", "500"), ("MEMPOOL_CAPACITY", "200")],
+    );
+    let repo = ConfigRepository::new(&schema).with(env);
+    let errors = repo.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    let errors: Vec<_> = errors.into_iter().collect();
+
+    assert_eq!(errors[0].path(), "api.max_batch_size");
+    assert_eq!(errors[0].config().ty.name_in_code(), "ApiConfig");
+    assert_eq!(errors[0].param().unwrap().name, "max_batch_size");
+    assert_matches!(errors[0].origin(), ValueOrigin::Path { path, .. } if path == "API_MAX_BATCH_SIZE");
+    assert_eq!(errors[1].path(), "mempool.capacity");
+    assert_eq!(errors[1].param().unwrap().name, "capacity");
+    assert_matches!(errors[1].origin(), ValueOrigin::Path { path, .. } if path == "MEMPOOL_CAPACITY");
+
+    for err in &errors {
+        assert_eq!(
+            err.validation(),
+            Some("batch size must not exceed mempool capacity")
+        );
+        assert_eq!(err.inner().to_string(), "batch size is too large");
+        let err = err.to_string();
+        assert!(err.contains("error validating"), "{err}");
+    }
+}
+
+#[test]
+fn cross_config_validation_is_skipped_on_parse_errors() {
+    let schema = schema_with_cross_validation();
+    let env = Environment::from_iter(
+        "",
+        [("API_MAX_BATCH_SIZE", "what?"), ("MEMPOOL_CAPACITY", "200")],
+    );
+    let repo = ConfigRepository::new(&schema).with(env);
+    repo.validate().unwrap();
+    repo.single::<ApiConfig>().unwrap().parse().unwrap_err();
+}
+
+#[test]
+fn cross_config_validation_reuses_parsed_configs() {
+    let schema = schema_with_cross_validation();
+    let env = Environment::from_iter(
+        "",
+        [("API_MAX_BATCH_SIZE", "500"), ("MEMPOOL_CAPACITY", "200")],
+    );
+    let repo = ConfigRepository::new(&schema).with(env);
+    let mempool: Box<dyn any::Any> = Box::new(MempoolConfig { capacity: 1_000 });
+    let mempool_key = (any::TypeId::of::<MempoolConfig>(), "mempool".to_owned());
+    let mut parsed = HashMap::from([(mempool_key, Some(mempool))]);
+    repo.validate_parsed(&mut parsed).unwrap();
+
+    // The missing config must be parsed and cached.
+    assert_eq!(parsed.len(), 2);
+    let api_key = (any::TypeId::of::<ApiConfig>(), "api".to_owned());
+    let api = parsed[&api_key].as_ref().unwrap();
+    assert_eq!(api.downcast_ref::<ApiConfig>().unwrap().max_batch_size, 500);
+}
+
+#[test]
+fn cross_config_validation_without_params() {
+    let mut schema = schema_with_cross_validation();
+    schema
+        .validate_configs::<(MempoolConfig,)>("capacity must be even", &[], |(mempool,)| {
+            if mempool.capacity % 2 != 0 {
+                return Err(ErrorWithOrigin::custom("odd capacity"));
+            }
+            Ok(())
+        })
+        .unwrap();
+
+    let env = Environment::from_iter("", [("MEMPOOL_CAPACITY", "2001")]);
+    let repo = ConfigRepository::new(&schema).with(env);
+    let errors = repo.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    let err = errors.first();
+    assert_eq!(err.path(), "mempool");
+    assert!(err.param().is_none());
+    assert_eq!(err.validation(), Some("capacity must be even"));
+}
+
+#[test]
+fn cross_config_validation_errors() {
+    let mut schema = ConfigSchema::default();
+    schema.insert(&ApiConfig::DESCRIPTION, "api").unwrap();
+
+    let err = schema
+        .validate_configs::<(ApiConfig, MempoolConfig)>("test", &[], |_| Ok(()))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("not registered"), "{err}");
+
+    let err = schema
+        .validate_configs::<(ApiConfig,)>("test", &["api.capacity"], |_| Ok(()))
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("`api.capacity` does not belong to any of the validated configs"),
+        "{err}"
+    );
+}
@@ -1,20 +1,30 @@
-use std::{env, fmt, mem, sync::Arc};
+use std::{collections::HashSet, env, fmt, mem, sync::Arc};
 
 use anyhow::Context as _;
 
 use super::{ConfigSource, Flat};
 use crate::{
     Json,
+    schema::ConfigSchema,
     testing::MOCK_ENV_VARS,
     utils::JsonObject,
     value::{FileFormat, Map, Value, ValueOrigin, WithOrigin},
 };
 
+/// Prefix for explicit var names in [`Environment`] contents.
+pub(crate) const EXPLICIT_VAR_PREFIX: &str = "$";
+
 /// Configuration sourced from environment variables.
+///
+/// Besides the variables with the specified prefix, the source retains variables that can be bound to params
+/// via explicit names (the `env` / `env_alias` attributes in the [derive macro](macro@crate::DescribeConfig)).
+/// Once the source is inserted into a [`ConfigRepository`](crate::ConfigRepository), only the variables with names
+/// declared in the schema are used; other variables are discarded.
 #[derive(Debug, Clone)]
 pub struct Environment {
     origin: Arc<ValueOrigin>,
     map: Map,
+    explicit_vars: ExplicitVars,
 }
 
 impl Default for Environment {
@@ -22,10 +32,69 @@ impl Default for Environment {
         Self {
             origin: Arc::new(ValueOrigin::EnvVars),
             map: Map::new(),
+            explicit_vars: ExplicitVars::default(),
         }
     }
 }
 
+/// Variables that can be bound to params via explicit names, keyed by the full var name.
+/// `Debug` implementation only outputs var names since this may contain unrelated vars.
+#[derive(Clone, Default)]
+struct ExplicitVars(Map);
+
+impl fmt::Debug for ExplicitVars {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.debug_set().entries(self.0.keys()).finish()
+    }
+}
+
+impl ExplicitVars {
+    /// Explicit var names can only contain uppercase ASCII letters, digits and `_`; other vars are not retained.
+    fn may_be_explicit(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .bytes()
+                .all(|ch| ch == b'_' || ch.is_ascii_uppercase() || ch.is_ascii_digit())
+    }
+
+    fn insert(&mut self, name: &str, value: &WithOrigin) {
+        if Self::may_be_explicit(name) {
+            self.0.insert(name.to_owned(), value.clone());
+        }
+    }
+
+    /// Vars are distinguished from the conventional ones by the name prefix, which cannot occur in the lowercased names.
+    fn add_to_contents(self, contents: &mut Map) {
+        let vars = self
+            .0
+            .into_iter()
+            .map(|(name, value)| (format!("{EXPLICIT_VAR_PREFIX}{name}"), value));
+        contents.extend(vars);
+    }
+}
+
+/// Removes vars with explicit names not declared in the `schema` from flat source `contents`,
+/// so that unrelated vars are not decrypted etc.
+pub(crate) fn retain_declared_explicit_vars(contents: &mut Map, schema: &ConfigSchema) {
+    if !contents
+        .keys()
+        .any(|key| key.starts_with(EXPLICIT_VAR_PREFIX))
+    {
+        return;
+    }
+
+    let declared_names: HashSet<_> = schema
+        .iter_ll()
+        .flat_map(|(_, config)| config.metadata.params)
+        .flat_map(|param| param.env_vars)
+        .copied()
+        .collect();
+    contents.retain(|key, _| {
+        key.strip_prefix(EXPLICIT_VAR_PREFIX)
+            .is_none_or(|name| declared_names.contains(name))
+    });
+}
+
 impl Environment {
     /// Loads environment variables with the specified prefix.
     pub fn prefixed(prefix: &str) -> Self {
@@ -44,21 +113,32 @@ impl Environment {
         V: Into<String>,
     {
         let origin = Arc::new(ValueOrigin::EnvVars);
-        let map = env.into_iter().filter_map(|(name, value)| {
-            let retained_name = name.as_ref().strip_prefix(prefix)?.to_lowercase();
-            Some((
-                retained_name,
-                WithOrigin {
-                    inner: Value::from(value.into()),
-                    origin: Arc::new(ValueOrigin::Path {
-                        source: origin.clone(),
-                        path: name.into(),
-                    }),
-                },
-            ))
-        });
-        let map = map.collect();
-        Self { origin, map }
+        let mut map = Map::new();
+        let mut explicit_vars = ExplicitVars::default();
+        for (name, value) in env {
+            let retained_name = name.as_ref().strip_prefix(prefix).map(str::to_lowercase);
+            if retained_name.is_none() && !ExplicitVars::may_be_explicit(name.as_ref()) {
+                continue;
+            }
+
+            let name = name.into();
+            let value = WithOrigin {
+                inner: Value::from(value.into()),
+                origin: Arc::new(ValueOrigin::Path {
+                    source: origin.clone(),
+                    path: name.clone(),
+                }),
+            };
+            explicit_vars.insert(&name, &value);
+            if let Some(retained_name) = retained_name {
+                map.insert(retained_name, value);
+            }
+        }
+        Self {
+            origin,
+            map,
+            explicit_vars,
+        }
     }
 
     /// Adds additional variables to this environment. This is useful if the added vars don't have the necessary prefix.
@@ -78,7 +158,10 @@ impl Environment {
                 },
             ))
         });
-        self.map.extend(defined_vars);
+        for (name, value) in defined_vars {
+            self.explicit_vars.insert(&name, &value);
+            self.map.insert(name, value);
+        }
         self
     }
 
@@ -89,6 +172,7 @@ impl Environment {
             format: FileFormat::Dotenv,
         });
         let mut map = Map::default();
+        let mut explicit_vars = ExplicitVars::default();
         for line in contents.lines().map(str::trim) {
             if line.is_empty() || line.starts_with('#') {
                 continue;
@@ -97,18 +181,21 @@ impl Environment {
                 format!("Incorrect line for setting environment variable: {line}")
             })?;
             let variable_value = variable_value.trim_matches(['"', '\'']);
-            map.insert(
-                name.to_lowercase(),
-                WithOrigin {
-                    inner: Value::from(variable_value.to_owned()),
-                    origin: Arc::new(ValueOrigin::Path {
-                        source: origin.clone(),
-                        path: name.into(),
-                    }),
-                },
-            );
+            let value = WithOrigin {
+                inner: Value::from(variable_value.to_owned()),
+                origin: Arc::new(ValueOrigin::Path {
+                    source: origin.clone(),
+                    path: name.into(),
+                }),
+            };
+            explicit_vars.insert(name, &value);
+            map.insert(name.to_lowercase(), value);
         }
-        Ok(Self { origin, map })
+        Ok(Self {
+            origin,
+            map,
+            explicit_vars,
+        })
     }
 
     /// Iterates over variables in this container.
@@ -127,6 +214,7 @@ impl Environment {
         Self {
             origin: self.origin,
             map: filtered.collect(),
+            explicit_vars: self.explicit_vars,
         }
     }
 
@@ -230,7 +318,9 @@ impl ConfigSource for Environment {
     type Kind = Flat;
 
     fn into_contents(self) -> WithOrigin<Map> {
-        WithOrigin::new(self.map, self.origin)
+        let mut map = self.map;
+        self.explicit_vars.add_to_contents(&mut map);
+        WithOrigin::new(map, self.origin)
     }
 }
 
@@ -277,6 +367,31 @@ mod tests {
         assert_eq!(env.map["other"].inner.as_plain_str(), Some("test string"));
     }
 
+    #[test]
+    fn retaining_explicit_vars() {
+        let env = Environment::from_iter(
+            "APP_",
+            [
+                ("APP_TEST", "1"),
+                ("LEGACY_TEST", "secret"),
+                ("lowercase_var", "2"),
+            ],
+        );
+        assert_eq!(env.map.len(), 1, "{:?}", env.map);
+        let explicit_names: Vec<_> = env.explicit_vars.0.keys().collect();
+        assert_eq!(explicit_names, ["APP_TEST", "LEGACY_TEST"]);
+        let debug_output = format!("{env:?}");
+        assert!(!debug_output.contains("secret"), "{debug_output}");
+
+        let contents = env.into_contents().inner;
+        let keys: Vec<_> = contents.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["$APP_TEST", "$LEGACY_TEST", "test"]);
+        assert_eq!(
+            contents["$LEGACY_TEST"].inner.as_plain_str(),
+            Some("secret")
+        );
+    }
+
     #[test]
     fn converting_flat_params() {
         let params = serde_json::json!({
@@ -1,19 +1,24 @@
 use std::{
     any,
-    collections::{BTreeMap, HashSet},
-    iter,
+    collections::{BTreeMap, HashMap, HashSet},
     marker::PhantomData,
     sync::Arc,
 };
 
 pub use self::{env::Environment, json::Json, yaml::Yaml};
 use crate::{
-    DescribeConfig, DeserializeConfig, DeserializeConfigError, ParseError, ParseErrors,
+    DescribeConfig, DeserializeConfig, DeserializeConfigError, ErrorWithOrigin, ParseError,
+    ParseErrorCategory, ParseErrors,
     de::{DeserializeContext, DeserializerOptions},
+    decrypt::{DecryptionFailure, Decryptor},
+    error::{LocationInConfig, LowLevelError},
     fallback::Fallbacks,
-    metadata::{BasicTypes, ConfigTag, ConfigVariant, TypeSuffixes},
+    metadata::{
+        BasicTypes, ConfigMetadata, ConfigTag, ConfigVariant, TagRepresentation, TypeSuffixes,
+    },
     schema::{ConfigData, ConfigRef, ConfigSchema},
-    utils::{EnumVariant, JsonObject, merge_json},
+    utils::{JsonObject, merge_json},
+    validation::{SchemaValidation, Severity},
     value::{Map, Pointer, Value, ValueOrigin, WithOrigin},
     visit::Serializer,
 };
@@ -26,6 +31,9 @@ mod json;
 mod tests;
 mod yaml;
 
+/// Configs parsed from a [`ConfigRepository`] keyed by the config type ID and canonical prefix.
+type ParsedConfigs = HashMap<(any::TypeId, String), Option<Box<dyn any::Any>>>;
+
 /// Kind of a [`ConfigSource`].
 pub trait ConfigSourceKind: crate::utils::Sealed {
     #[doc(hidden)] // implementation detail
@@ -157,6 +165,9 @@ impl SerializerOptions {
     }
 
     /// Sets the placeholder string value for secret params. By default, secrets will be output as-is.
+    ///
+    /// Secret parts of partially secret params (e.g., a password in a [`SensitiveUrl`](crate::SensitiveUrl))
+    /// are replaced with the placeholder as well.
     #[must_use]
     pub fn with_secret_placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.secret_placeholder = Some(placeholder.into());
@@ -226,11 +237,15 @@ impl SerializerOptions {
 ///   are removed.
 /// - **Hiding secrets:** Values corresponding to [secret params](crate::de#secrets) are wrapped in
 ///   opaque, zero-on-drop wrappers.
+/// - **Decryption:** If a [`Decryptor`] is [set](Self::with_decryptor()), [encrypted values](crate::decrypt)
+///   are decrypted and treated as secrets.
 #[derive(Debug, Clone)]
 pub struct ConfigRepository<'a> {
     schema: &'a ConfigSchema,
     prefixes_for_canonical_configs: HashSet<Pointer<'a>>,
     de_options: DeserializerOptions,
+    decryptor: Option<Arc<dyn Decryptor>>,
+    decryption_failures: Vec<DecryptionFailure>,
     sources: Vec<SourceInfo>,
     merged: WithOrigin,
 }
@@ -248,6 +263,8 @@ impl<'a> ConfigRepository<'a> {
             schema,
             prefixes_for_canonical_configs,
             de_options: DeserializerOptions::default(),
+            decryptor: None,
+            decryption_failures: vec![],
             sources: vec![],
             merged: WithOrigin {
                 inner: Value::Object(Map::default()),
@@ -271,6 +288,14 @@ impl<'a> ConfigRepository<'a> {
         &mut self.de_options
     }
 
+    /// Sets the decryptor for [encrypted values](crate::decrypt) in sources. The decryptor only applies
+    /// to sources added after this call.
+    #[must_use]
+    pub fn with_decryptor(mut self, decryptor: impl Decryptor) -> Self {
+        self.decryptor = Some(Arc::new(decryptor));
+        self
+    }
+
     /// Extends this environment with a new configuration source.
     #[must_use]
     pub fn with<S: ConfigSource>(mut self, source: S) -> Self {
@@ -283,7 +308,16 @@ impl<'a> ConfigRepository<'a> {
         name = "ConfigRepository::insert",
         skip(self, contents)
     )]
-    fn insert_inner(&mut self, contents: WithOrigin<Map>, is_flat: bool) {
+    fn insert_inner(&mut self, mut contents: WithOrigin<Map>, is_flat: bool) {
+        if is_flat {
+            env::retain_declared_explicit_vars(&mut contents.inner, self.schema);
+        }
+        if let Some(decryptor) = &self.decryptor {
+            let decrypted_count =
+                contents.decrypt_values(decryptor.as_ref(), &mut self.decryption_failures);
+            tracing::debug!(decrypted_count, "decrypted values in source");
+        }
+
         let mut source_value = if is_flat {
             WithOrigin::nest_kvs(contents.inner, self.schema, &contents.origin)
         } else {
@@ -363,6 +397,97 @@ impl<'a> ConfigRepository<'a> {
         Ok(json)
     }
 
+    /// Runs [schema-level validations](ConfigSchema::validate_configs()) on the configs parsed from this repository.
+    /// Each participating config is parsed once, even if it participates in multiple validations.
+    ///
+    /// A validation is skipped if any of its participating configs fails to parse; such errors are reported
+    /// when parsing the config itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns errors for all failed validations. Each failed validation results in an error per participating param,
+    /// or per participating config if the validation doesn't specify params.
+    pub fn validate(&self) -> Result<(), ParseErrors> {
+        self.validate_parsed(&mut HashMap::new())
+    }
+
+    /// Same as [`Self::validate()`], but reuses configs already parsed from this repository. Configs are keyed
+    /// by their type ID and canonical prefix; `None` values correspond to configs that failed to parse.
+    /// Configs missing from `parsed` are parsed and inserted into it.
+    #[doc(hidden)] // used by the `commands` crate; logically private
+    pub fn validate_parsed(&self, parsed: &mut ParsedConfigs) -> Result<(), ParseErrors> {
+        let mut errors = ParseErrors::default();
+        for validation in self.schema.validations() {
+            for &(metadata, ref prefix) in &validation.configs {
+                let key = (metadata.ty.id(), prefix.clone());
+                parsed.entry(key).or_insert_with(|| {
+                    let config_ref = self.schema.get(metadata, prefix)?;
+                    let parser = ConfigParser {
+                        repo: self,
+                        config_ref,
+                        _config: PhantomData::<()>,
+                    };
+                    parser.parse().ok()
+                });
+            }
+
+            let configs: Option<Vec<_>> = validation
+                .configs
+                .iter()
+                .map(|(metadata, prefix)| {
+                    parsed.get(&(metadata.ty.id(), prefix.clone()))?.as_deref()
+                })
+                .collect();
+            let Some(configs) = configs else {
+                continue;
+            };
+
+            if let Err(err) = validation.validate(&configs) {
+                self.push_validation_errors(validation, &err, &mut errors);
+            }
+        }
+        (errors.len() == 0).then_some(()).ok_or(errors)
+    }
+
+    fn push_validation_errors(
+        &self,
+        validation: &SchemaValidation,
+        err: &ErrorWithOrigin,
+        errors: &mut ParseErrors,
+    ) {
+        let message = match &err.inner {
+            LowLevelError::Json { err, .. } => err.to_string(),
+            _ => validation.description.to_owned(),
+        };
+        let mut push_error = |path: &str, config_idx: usize, location| {
+            let origin = self
+                .merged
+                .get(Pointer(path))
+                .map_or_else(|| err.origin.clone(), |val| val.origin.clone());
+            errors.push(ParseError {
+                inner: serde::de::Error::custom(&message),
+                category: ParseErrorCategory::Generic,
+                path: path.to_owned(),
+                origin,
+                config: validation.configs[config_idx].0,
+                location_in_config: location,
+                validation: Some(validation.description.to_owned()),
+                severity: Severity::Deny,
+            });
+        };
+
+        if validation.params.is_empty() {
+            for (config_idx, (_, prefix)) in validation.configs.iter().enumerate() {
+                push_error(prefix, config_idx, None);
+            }
+        } else {
+            for param in &validation.params {
+                let location = Some(LocationInConfig::Param(param.param_idx));
+                push_error(&param.path, param.config_idx, location);
+            }
+        }
+    }
+
     /// Iterates over parsers for all configs in the schema.
     pub fn iter(&self) -> impl Iterator<Item = ConfigParser<'_, ()>> + '_ {
         self.schema.iter().map(|config_ref| ConfigParser {
@@ -416,6 +541,7 @@ impl ConfigParser<'_, ()> {
     #[allow(clippy::redundant_closure_for_method_calls)] // false positive because of lifetimes
     pub fn parse(&self) -> Result<Box<dyn any::Any>, ParseErrors> {
         self.with_context(|ctx| ctx.deserialize_any_config())
+            .map(|(config, _)| config)
     }
 
     /// Attempts to parse an optional config from the repository input. Returns the boxed parsed config.
@@ -428,6 +554,23 @@ impl ConfigParser<'_, ()> {
     #[doc(hidden)] // not stable yet
     #[allow(clippy::redundant_closure_for_method_calls)] // false positive because of lifetimes
     pub fn parse_opt(&self) -> Result<Option<Box<dyn any::Any>>, ParseErrors> {
+        self.with_context(|ctx| ctx.deserialize_any_config_opt())
+            .map(|(config, _)| config)
+    }
+
+    /// Same as [`Self::parse_opt()`], but also returns non-fatal [warnings](ParseErrors::warnings()).
+    ///
+    /// # Errors
+    ///
+    /// Returns parsing errors if any.
+    #[doc(hidden)] // not stable yet
+    #[allow(
+        clippy::redundant_closure_for_method_calls, // false positive because of lifetimes
+        clippy::type_complexity // the type isn't reused
+    )]
+    pub fn parse_opt_with_warnings(
+        &self,
+    ) -> Result<(Option<Box<dyn any::Any>>, Vec<ParseError>), ParseErrors> {
         self.with_context(|ctx| ctx.deserialize_any_config_opt())
     }
 }
@@ -441,7 +584,7 @@ impl<'a, C> ConfigParser<'a, C> {
     fn with_context<R>(
         &self,
         action: impl FnOnce(DeserializeContext<'_>) -> Result<R, DeserializeConfigError>,
-    ) -> Result<R, ParseErrors> {
+    ) -> Result<(R, Vec<ParseError>), ParseErrors> {
         let mut errors = ParseErrors::default();
         let prefix = self.config_ref.prefix();
         let metadata = self.config_ref.data.metadata;
@@ -451,13 +594,15 @@ impl<'a, C> ConfigParser<'a, C> {
             prefix.to_owned(),
             metadata,
             &mut errors,
-        );
-        action(ctx).map_err(|_| {
-            if errors.len() == 0 {
-                errors.push(ParseError::generic(prefix.to_owned(), metadata));
-            }
-            errors
-        })
+        )
+        .with_decryption_failures(&self.repo.decryption_failures);
+        if let Ok(config) = action(ctx) {
+            return Ok((config, errors.take_warnings()));
+        }
+        if errors.len() == 0 {
+            errors.push(ParseError::generic(prefix.to_owned(), metadata));
+        }
+        Err(errors)
     }
 }
 
@@ -470,6 +615,18 @@ impl<C: DeserializeConfig> ConfigParser<'_, C> {
     /// there is no short-circuiting on encountering an error).
     #[allow(clippy::redundant_closure_for_method_calls)] // doesn't work as an fn pointer because of the context lifetime
     pub fn parse(self) -> Result<C, ParseErrors> {
+        self.parse_with_warnings().map(|(config, _)| config)
+    }
+
+    /// Performs parsing, returning the parsed config together with non-fatal warnings produced by failed validations
+    /// with [`Warn`](crate::validation::Severity::Warn) or [`Info`](crate::validation::Severity::Info) severity.
+    ///
+    /// # Errors
+    ///
+    /// Returns errors encountered during parsing. Warnings encountered before the failure are available
+    /// via [`ParseErrors::warnings()`].
+    #[allow(clippy::redundant_closure_for_method_calls)] // doesn't work as an fn pointer because of the context lifetime
+    pub fn parse_with_warnings(self) -> Result<(C, Vec<ParseError>), ParseErrors> {
         self.with_context(|ctx| ctx.deserialize_config::<C>())
     }
 
@@ -482,6 +639,7 @@ impl<C: DeserializeConfig> ConfigParser<'_, C> {
     #[allow(clippy::redundant_closure_for_method_calls)] // doesn't work as an fn pointer because of the context lifetime
     pub fn parse_opt(self) -> Result<Option<C>, ParseErrors> {
         self.with_context(|ctx| ctx.deserialize_config_opt::<C>())
+            .map(|(config, _)| config)
     }
 }
 
@@ -493,7 +651,7 @@ impl WithOrigin {
     ) -> usize {
         self.copy_aliased_values(schema);
         self.mark_secrets(schema);
-        self.convert_serde_enums(schema);
+        self.detect_enum_variants(schema);
         self.nest_object_params_and_sub_configs(schema);
         self.nest_array_params(schema);
         self.collect_garbage(schema, prefixes_for_canonical_configs, Pointer(""))
@@ -671,8 +829,10 @@ impl WithOrigin {
         }
     }
 
+    /// Inserts tags for enum configs with the variant specified in a non-internal way (externally tagged enums,
+    /// incl. coerced `serde` enums, and untagged enums).
     #[tracing::instrument(level = "debug", skip_all)]
-    fn convert_serde_enums(&mut self, schema: &ConfigSchema) {
+    fn detect_enum_variants(&mut self, schema: &ConfigSchema) {
         for config_data in schema.iter() {
             let config_meta = config_data.metadata();
             let prefix = Pointer(config_data.prefix());
@@ -680,41 +840,60 @@ impl WithOrigin {
             let Some(tag) = &config_meta.tag else {
                 continue; // Not an enum config, nothing to do.
             };
-            if !config_data.data.coerce_serde_enums {
+            let is_untagged = tag.representation == TagRepresentation::Untagged;
+            if !config_data.data.has_external_tag() && !is_untagged {
                 continue;
             }
 
             let canonical_map = self.get(prefix).and_then(|val| val.inner.as_object());
-            let alias_maps = config_data
-                .aliases()
-                .filter_map(|(alias, _)| self.get(Pointer(alias))?.inner.as_object());
-
             if canonical_map.is_some_and(|map| map.contains_key(tag.param.name)) {
                 // The source contains the relevant tag. It's sufficient to check the canonical map only since we've performed de-aliasing for tags already.
                 continue;
             }
 
             let _span_guard = tracing::info_span!(
-                "convert_serde_enum",
+                "detect_enum_variant",
                 config = ?config_meta.ty,
                 prefix = prefix.0,
                 tag = tag.param.name,
             )
             .entered();
 
-            if let Some((variant, variant_content)) =
-                Self::detect_serde_enum_variant(canonical_map, alias_maps, tag)
-            {
+            let detected = if is_untagged {
+                canonical_map.and_then(|map| {
+                    let variant = Self::detect_enum_variant_by_params(map, config_meta, tag)?;
+                    let origin = ValueOrigin::Synthetic {
+                        source: self.get(prefix).unwrap().origin.clone(),
+                        transform: "detecting untagged enum variant".to_owned(),
+                    };
+                    Some((variant, origin))
+                })
+            } else {
+                let alias_maps = config_data
+                    .aliases()
+                    .filter_map(|(alias, _)| self.get(Pointer(alias))?.inner.as_object());
+                Self::detect_serde_enum_variant(canonical_map, alias_maps, tag).map(
+                    |(variant, variant_content)| {
+                        let transform = if tag.representation == TagRepresentation::External {
+                            "detecting externally tagged enum variant"
+                        } else {
+                            "coercing serde enum"
+                        };
+                        let origin = ValueOrigin::Synthetic {
+                            source: variant_content.origin.clone(),
+                            transform: transform.to_owned(),
+                        };
+                        (variant, origin)
+                    },
+                )
+            };
+
+            if let Some((variant, origin)) = detected {
                 tracing::debug!(
                     variant = variant.name,
-                    origin = %variant_content.origin,
+                    %origin,
                     "adding detected tag variant"
                 );
-                let origin = ValueOrigin::Synthetic {
-                    source: variant_content.origin.clone(),
-                    transform: "coercing serde enum".to_owned(),
-                };
-
                 let canonical_map = self.ensure_object(prefix, |_| {
                     Arc::new(ValueOrigin::Synthetic {
                         source: Arc::default(),
@@ -729,15 +908,63 @@ impl WithOrigin {
         }
     }
 
+    /// Detects the variant of an untagged enum config. The variant is detected if it's the only variant
+    /// whose params include all variant-specific params present in the `map`; not all of the variant params
+    /// need to be present.
+    fn detect_enum_variant_by_params(
+        map: &Map,
+        config: &'static ConfigMetadata,
+        tag: &'static ConfigTag,
+    ) -> Option<&'static ConfigVariant> {
+        let keys_by_variant: Vec<_> = tag
+            .variants
+            .iter()
+            .map(|variant| (variant, config.variant_keys(variant)))
+            .collect();
+        let present_keys: HashSet<_> = keys_by_variant
+            .iter()
+            .flat_map(|(_, keys)| keys)
+            .copied()
+            .filter(|&key| map.contains_key(key))
+            .collect();
+        if present_keys.is_empty() {
+            tracing::info!("no variant-specific params present");
+            return None;
+        }
+
+        let mut matching_variants = keys_by_variant.iter().filter_map(|(variant, keys)| {
+            let matches = present_keys.iter().all(|key| keys.contains(key));
+            matches.then_some(*variant)
+        });
+        let Some(variant) = matching_variants.next() else {
+            tracing::info!(
+                ?present_keys,
+                "present params do not correspond to a single variant"
+            );
+            return None;
+        };
+        if let Some(other_variant) = matching_variants.next() {
+            // The variant may be determined after merging with other sources.
+            tracing::info!(
+                ?present_keys,
+                variant = variant.name,
+                other_variant = other_variant.name,
+                "present params correspond to multiple variants"
+            );
+            return None;
+        }
+        Some(variant)
+    }
+
     fn detect_serde_enum_variant<'a>(
         canonical_map: Option<&'a Map>,
         alias_maps: impl Iterator<Item = &'a Map>,
         tag: &'static ConfigTag,
     ) -> Option<(&'static ConfigVariant, &'a Self)> {
         let all_variant_names = tag.variants.iter().flat_map(|variant| {
-            iter::once(variant.name)
-                .chain(variant.aliases.iter().copied())
-                .filter_map(move |name| Some((EnumVariant::new(name)?.to_snake_case(), variant)))
+            variant
+                .external_keys()
+                .map(move |variant_name| (variant_name, variant))
         });
 
         // We need to look for variant fields in the alias maps because they were not copied during de-aliasing.
@@ -978,7 +1205,13 @@ impl WithOrigin {
             origin: source_origin.clone(),
         };
 
+        let mut explicit_vars = HashMap::new();
         for (key, value) in kvs {
+            if let Some(var_name) = key.strip_prefix(env::EXPLICIT_VAR_PREFIX) {
+                explicit_vars.insert(var_name.to_owned(), value);
+                continue;
+            }
+
             // Get all params with full paths matching a prefix of `key` split on one of `_`s. E.g.,
             // for `key = "very_long_prefix_value"`, we'll try "very_long_prefix_value", "very_long_prefix", ..., "very".
             // If any of these prefixes corresponds to a param, we'll nest the value to align with the param.
@@ -1023,9 +1256,51 @@ impl WithOrigin {
                 }
             }
         }
+
+        if !explicit_vars.is_empty() {
+            dest.bind_explicit_env_vars(&explicit_vars, schema, source_origin);
+        }
         dest
     }
 
+    /// Binds vars with explicit names (from `env` / `env_alias` param attrs) to all param locations.
+    /// Explicit vars have lower priority than conventionally named ones.
+    fn bind_explicit_env_vars(
+        &mut self,
+        vars: &HashMap<String, WithOrigin>,
+        schema: &ConfigSchema,
+        source_origin: &Arc<ValueOrigin>,
+    ) {
+        for (prefix, config) in schema.iter_ll() {
+            for param in config.metadata.params {
+                let Some((var_name, value)) = param
+                    .env_vars
+                    .iter()
+                    .find_map(|&name| Some((name, vars.get(name)?)))
+                else {
+                    continue;
+                };
+
+                let param_path = prefix.join(param.name);
+                let origin = Arc::new(ValueOrigin::Synthetic {
+                    source: source_origin.clone(),
+                    transform: format!("binding env var {var_name} to '{param_path}'"),
+                });
+                let config_object = self.ensure_object(prefix, |_| origin.clone());
+                if config_object.contains_key(param.name) {
+                    tracing::trace!(
+                        param_path,
+                        var_name,
+                        "explicit env var is overridden by conventional one"
+                    );
+                } else {
+                    tracing::trace!(param_path, var_name, "bound explicit env var");
+                    config_object.insert(param.name.to_owned(), value.clone());
+                }
+            }
+        }
+    }
+
     fn copy_kv_entry(
         &mut self,
         source_origin: &Arc<ValueOrigin>,
@@ -312,7 +312,8 @@ impl<'a> CompletenessChecker<'a> {
         let should_add_param = match self.mode {
             CompletenessCheckerMode::Complete => self.sample.get(Pointer(&param_path)).is_none(),
             CompletenessCheckerMode::Minimal => {
-                param.default_value.is_some() && self.sample.get(Pointer(&param_path)).is_some()
+                let has_default = param.default_value.is_some() || param.computed_default.is_some();
+                has_default && self.sample.get(Pointer(&param_path)).is_some()
             }
         };
 
@@ -558,12 +559,32 @@ impl<C> Tester<'_, C> {
         self
     }
 
+    /// Sets the application version used to escalate usages of removed [deprecated](crate::metadata::Deprecation) items to errors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the version cannot be parsed; see [`DeserializerOptions::set_app_version()`](crate::de::DeserializerOptions::set_app_version()).
+    pub fn set_app_version(&mut self, version: &str) -> &mut Self {
+        self.data
+            .as_mut()
+            .de_options
+            .set_app_version(version)
+            .expect("invalid app version");
+        self
+    }
+
     /// Enables coercion of serde-style enums.
     pub fn coerce_serde_enums(&mut self) -> &mut Self {
         self.data.as_mut().schema.coerce_serde_enums(true);
         self
     }
 
+    /// Enables coercion of param names to `camelCase` and `kebab-case`.
+    pub fn coerce_param_names(&mut self) -> &mut Self {
+        self.data.as_mut().schema.coerce_param_names(true);
+        self
+    }
+
     /// Sets mock environment variables that will be recognized by [`Environment`](crate::Environment)
     /// and [`Env`](crate::fallback::Env) fallbacks.
     ///
@@ -138,6 +138,49 @@ pub(crate) enum RenamedEnumConfig {
     V3(EnumConfig),
 }
 
+#[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate, tag = external)]
+pub(crate) enum ExternalEnumConfig {
+    /// Stores data locally.
+    Local {
+        path: PathBuf,
+    },
+    #[config(alias = "Aws")]
+    S3 {
+        bucket: String,
+        #[config(default)]
+        region: Option<String>,
+    },
+    #[config(default)]
+    Memory,
+    Nested(NestedConfig),
+}
+
+#[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate, untagged)]
+pub(crate) enum UntaggedEnumConfig {
+    Url {
+        url: String,
+        #[config(default_t = 30)]
+        timeout_sec: u64,
+    },
+    Socket {
+        path: PathBuf,
+        #[config(default_t = 30)]
+        timeout_sec: u64,
+    },
+    Nested(NestedConfig),
+}
+
+#[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+pub(crate) struct ConfigWithEnums {
+    #[config(nest)]
+    pub storage: ExternalEnumConfig,
+    #[config(nest, alias = "conn")]
+    pub connection: UntaggedEnumConfig,
+}
+
 #[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig, ExampleConfig)]
 #[config(crate = crate)]
 pub(crate) struct CompoundConfig {
@@ -351,6 +394,55 @@ const STR_SOURCE: &'static dyn FallbackSource =
             .filter(|val| val.inner.as_plain_str() != Some("unset"))
     });
 
+#[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig, ExampleConfig)]
+#[config(crate = crate, derive(Default))]
+pub(crate) struct ConfigWithComputedDefaults {
+    #[config(default_t = 3000)]
+    pub port: u16,
+    /// Port for metrics.
+    #[config(default_from(|port| port + 1, "`port` + 1"), validate(1_024.., "must be unprivileged"))]
+    pub metrics_port: u16,
+    #[config(default_from(|metrics_port| metrics_port + 1))]
+    pub debug_port: u16,
+    #[config(example = Some("https://example.com".into()))]
+    pub url: Option<String>,
+    #[config(default_from(|url, port| url.clone().unwrap_or_else(|| format!("http://localhost:{port}"))))]
+    pub public_url: String,
+}
+
+#[derive(Debug, PartialEq, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate, tag = "kind")]
+pub(crate) enum ModeConfig {
+    #[config(default)]
+    Fast,
+    #[config(deprecated(note = "use `Fast`"))]
+    Slow {
+        #[config(default)]
+        delay_ms: u64,
+    },
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+pub(crate) struct ConfigWithDeprecations {
+    #[config(deprecated(note = "use `max_rps`", since = "0.9", remove_in = "1.0"))]
+    pub rps: Option<u32>,
+    pub max_rps: Option<u32>,
+    #[config(nest, deprecated)]
+    pub legacy: Option<DefaultingConfig>,
+    #[config(nest)]
+    pub mode: ModeConfig,
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+pub(crate) struct ConfigWithEnvVars {
+    #[config(default_t = 8080, env = "LEGACY_PORT", env_alias = "PORT")]
+    pub port: u16,
+    #[config(env_alias = "DATABASE_URL")]
+    pub url: Option<String>,
+}
+
 #[derive(DescribeConfig, DeserializeConfig)]
 #[config(crate = crate)]
 pub(crate) struct ConfigWithFallbacks {
@@ -390,6 +482,73 @@ impl ConfigWithValidations {
     }
 }
 
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+#[config(validate(
+    Self::has_reasonable_total_time,
+    "total retry time should not exceed 1 minute",
+    severity = info
+))]
+pub(crate) struct ConfigWithWarnings {
+    #[config(default_t = Duration::from_secs(1))]
+    #[config(validate(Duration::from_millis(100).., "is unusually low", severity = warn))]
+    pub timeout: Duration,
+    #[config(default_t = 3, validate(..100), validate(..10, "is unusually high", severity = warn))]
+    pub retries: u32,
+}
+
+impl ConfigWithWarnings {
+    fn has_reasonable_total_time(&self) -> bool {
+        self.timeout * self.retries <= Duration::from_mins(1)
+    }
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+#[config(one_of_required(url, path))]
+pub(crate) struct ConfigWithConstraints {
+    pub url: Option<String>,
+    #[config(conflicts_with = url)]
+    pub path: Option<PathBuf>,
+    pub tls_cert: Option<PathBuf>,
+    #[config(required_with = tls_cert)]
+    pub tls_key: Option<PathBuf>,
+    #[config(default)]
+    pub auth: bool,
+    #[config(required_if(Self::requires_auth, "`auth` is enabled"))]
+    pub token: Option<String>,
+}
+
+impl ConfigWithConstraints {
+    fn requires_auth(&self) -> bool {
+        self.auth
+    }
+}
+
+/// Config generic by the param type and the max value of another param.
+#[derive(Debug, DescribeConfig, DeserializeConfig, ExampleConfig)]
+#[config(crate = crate)]
+pub(crate) struct GenericConfig<T: WellKnown + Default + fmt::Debug, const MAX: u32> {
+    #[config(default)]
+    pub value: T,
+    #[config(default_t = MAX, validate(..=MAX))]
+    pub retries: u32,
+    #[config(deserialize_if(NotEmpty, "must not be empty"))]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, DescribeConfig, DeserializeConfig)]
+#[config(crate = crate)]
+pub(crate) struct ConfigWithGenericNesting<C>
+where
+    C: DeserializeConfig + fmt::Debug,
+{
+    #[config(nest)]
+    pub nested: C,
+    #[config(nest)]
+    pub numbers: GenericConfig<u64, 5>,
+}
+
 #[derive(Debug, DescribeConfig, DeserializeConfig)]
 #[config(crate = crate)]
 pub(crate) struct ConfigWithNestedValidations {
@@ -0,0 +1,141 @@
+use std::{fmt, str::FromStr};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, Error as DeError},
+};
+
+/// EVM chain ID, such as 1 for Ethereum mainnet.
+///
+/// # Examples
+///
+/// ```
+/// # use smart_config::ChainId;
+/// assert_eq!(ChainId::MAINNET, ChainId(1));
+/// assert_eq!(ChainId::SEPOLIA.to_string(), "11155111");
+/// assert_eq!(ChainId::SEPOLIA.name(), Some("sepolia"));
+/// assert_eq!(ChainId(12_345).name(), None);
+/// ```
+///
+/// ## As config param
+///
+/// `ChainId` can be deserialized from an integer, a decimal string, or a well-known chain name (case-insensitive),
+/// such as `mainnet`, `sepolia` or `holesky`; see [`Self::KNOWN_CHAINS`] for the full list. The chain ID
+/// is always serialized as an integer.
+///
+/// ```
+/// # use smart_config::ChainId;
+/// let id: ChainId = "Sepolia".parse()?;
+/// assert_eq!(id, ChainId(11_155_111));
+/// let id: ChainId = "324".parse()?;
+/// assert_eq!(id, ChainId(324));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloy")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ChainId(pub u64);
+
+impl ChainId {
+    /// Ethereum mainnet.
+    pub const MAINNET: Self = Self(1);
+    /// Sepolia testnet.
+    pub const SEPOLIA: Self = Self(11_155_111);
+    /// Holesky testnet.
+    pub const HOLESKY: Self = Self(17_000);
+    /// Hoodi testnet.
+    pub const HOODI: Self = Self(560_048);
+
+    /// Well-known chains recognized by name.
+    pub const KNOWN_CHAINS: &'static [(&'static str, Self)] = &[
+        ("mainnet", Self::MAINNET),
+        ("sepolia", Self::SEPOLIA),
+        ("holesky", Self::HOLESKY),
+        ("hoodi", Self::HOODI),
+        ("optimism", Self(10)),
+        ("bsc", Self(56)),
+        ("polygon", Self(137)),
+        ("zksync", Self(324)),
+        ("base", Self(8_453)),
+        ("arbitrum", Self(42_161)),
+        ("linea", Self(59_144)),
+        ("scroll", Self(534_352)),
+    ];
+
+    const EXPECTING: &'static str = "chain ID or well-known chain name, like 'mainnet'";
+
+    /// Returns the well-known name of this chain, if any.
+    pub fn name(self) -> Option<&'static str> {
+        Self::KNOWN_CHAINS
+            .iter()
+            .find_map(|&(name, id)| (id == self).then_some(name))
+    }
+}
+
+impl From<u64> for ChainId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ChainId> for u64 {
+    fn from(id: ChainId) -> Self {
+        id.0
+    }
+}
+
+/// Outputs the numeric ID, consistently with serialization. Use [`ChainId::name()`] to get the well-known chain name.
+impl fmt::Display for ChainId {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, formatter)
+    }
+}
+
+impl FromStr for ChainId {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_empty() && s.bytes().all(|ch| ch.is_ascii_digit()) {
+            return s.parse().map(Self).map_err(DeError::custom);
+        }
+        Self::KNOWN_CHAINS
+            .iter()
+            .find_map(|&(name, id)| name.eq_ignore_ascii_case(s).then_some(id))
+            .ok_or_else(|| DeError::invalid_value(de::Unexpected::Str(s), &Self::EXPECTING))
+    }
+}
+
+impl Serialize for ChainId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ChainIdVisitor;
+
+        impl de::Visitor<'_> for ChainIdVisitor {
+            type Value = ChainId;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str(ChainId::EXPECTING)
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(ChainId(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                u64::try_from(value)
+                    .map(ChainId)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(ChainIdVisitor)
+    }
+}
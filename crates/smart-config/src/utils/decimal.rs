@@ -1,6 +1,6 @@
-use std::{cmp, fmt, ops, str::FromStr};
+use std::{cmp, fmt, hash, ops, str::FromStr};
 
-use serde::{Deserialize, Deserializer, de};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 use super::FromStrStart;
 
@@ -84,14 +84,62 @@ enum PrintFormat {
     Exponential,
 }
 
-/// Ad-hoc non-negative decimal value with `u64` precision.
+/// Non-negative fixed-point decimal value with `u64` mantissa and `i16` decimal exponent.
+///
+/// The value is equal to `mantissa * 10^exponent`. Hence, it can represent up to 19 significant decimal digits
+/// ([`Self::MAX_PRECISION`]) exactly, and supports exponents in the `i16` range (vs -308..=308 for `f64`).
+/// The value is always stored in the reduced form, i.e., with trailing zeros moved from the mantissa into the exponent.
 ///
 /// # Why not use `f64`?
 ///
-/// - Additional precision when parsing from ints and strings; the latter supports `i16` decimal exponents (vs -308..=308 for `f64`).
+/// - Additional precision when parsing from ints and strings.
+/// - Lossless parsing and serialization: a value is never rounded to the nearest binary fraction, e.g. `0.1` is exactly `1e-1`.
 /// - Lossless conversion to integers; error on overflow and imprecise conversion.
+///
+/// # Arithmetic
+///
+/// Arithmetic operations ([`Self::checked_add()`], [`Self::checked_sub()`], [`Self::checked_mul()`]) are checked.
+/// They are exact if the result fits into the precision limits; otherwise, the result is rounded to
+/// [`Self::MAX_PRECISION`] significant digits (round to nearest, ties to even). `None` is returned on overflow
+/// (including the exponent overflow) and, for subtraction, if the result is negative.
+///
+/// # As config param
+///
+/// `Decimal` is [well-known](crate::de::WellKnown) and can be deserialized from integers, floats and strings.
+/// Strings support decimal and scientific notation, e.g. `1.5`, `0.000_1` or `2.5e-9`. For floats, the conversion is checked
+/// to be lossless (i.e., a float must have at most 15 significant digits); to avoid precision loss, quote such values.
+/// Unlike arithmetic operations, parsing never rounds: a value with more significant digits than can be represented
+/// is rejected.
+///
+/// On serialization, a decimal is output as a number if this is lossless (i.e., the value is an integer
+/// fitting into `u64`, or can be converted to `f64` and back without changes); otherwise, it's output as a string.
+///
+/// Decimals are totally ordered, so they can be validated using ranges. To limit the [precision](Self::precision())
+/// and [scale](Self::scale()) of accepted values, use the [`DecimalLimits`](crate::de::DecimalLimits) deserializer.
+///
+/// ```
+/// # use smart_config::{testing, DescribeConfig, DeserializeConfig};
+/// use smart_config::Decimal;
+///
+/// #[derive(DescribeConfig, DeserializeConfig)]
+/// struct TestConfig {
+///     #[config(validate(Decimal::new(1, 0)..=Decimal::new(5, 0)))]
+///     fee_multiplier: Decimal,
+///     #[config(default_t = Decimal::new(5, -1))]
+///     ratio: Decimal,
+/// }
+///
+/// let input = smart_config::config!("fee_multiplier": "1.123456789012345678");
+/// let config: TestConfig = testing::test(input)?;
+/// assert_eq!(config.fee_multiplier, Decimal::new(1_123_456_789_012_345_678, -18));
+/// assert_eq!(config.ratio.to_string(), "0.5");
+///
+/// let input = smart_config::config!("fee_multiplier": 10);
+/// assert!(testing::test::<TestConfig>(input).is_err());
+/// # anyhow::Ok(())
+/// ```
 #[derive(Clone, Copy, Default)]
-pub(crate) struct Decimal {
+pub struct Decimal {
     mantissa: u64,
     exponent: i16,
 }
@@ -139,6 +187,47 @@ impl PartialEq for Decimal {
     }
 }
 
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        match (self.mantissa, other.mantissa) {
+            (0, 0) => return cmp::Ordering::Equal,
+            (0, _) => return cmp::Ordering::Less,
+            (_, 0) => return cmp::Ordering::Greater,
+            _ => { /* continue */ }
+        }
+
+        let (this, other) = (self.reduced(), other.reduced());
+        let by_exponent = this.adjusted_exponent().cmp(&other.adjusted_exponent());
+        if by_exponent.is_ne() {
+            return by_exponent;
+        }
+        // Adjusted exponents are equal, so it's sufficient to compare mantissas padded to the same number of digits.
+        // The padding cannot overflow since mantissas have at most 20 digits.
+        let (this_digits, other_digits) = (this.digit_count(), other.digit_count());
+        let this_mantissa =
+            u128::from(this.mantissa) * 10_u128.pow(other_digits.saturating_sub(this_digits));
+        let other_mantissa =
+            u128::from(other.mantissa) * 10_u128.pow(this_digits.saturating_sub(other_digits));
+        this_mantissa.cmp(&other_mantissa)
+    }
+}
+
+impl hash::Hash for Decimal {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        let reduced = self.reduced();
+        reduced.mantissa.hash(state);
+        reduced.exponent.hash(state);
+    }
+}
+
 impl From<u64> for Decimal {
     fn from(value: u64) -> Self {
         Self::new(value, 0)
@@ -148,25 +237,78 @@ impl From<u64> for Decimal {
 impl Decimal {
     const EXPECTING: &'static str = "decimal fraction like 1.5";
 
-    const ZERO: Self = Self {
+    /// Maximum number of significant decimal digits that can be represented exactly.
+    pub const MAX_PRECISION: u32 = 19;
+
+    /// Zero value.
+    pub const ZERO: Self = Self {
         mantissa: 0,
         exponent: 0,
     };
 
-    pub(crate) const fn new(mantissa: u64, exponent: i16) -> Self {
+    /// Maximum representable value.
+    pub const MAX: Self = Self {
+        mantissa: u64::MAX,
+        exponent: i16::MAX,
+    };
+
+    /// Creates a decimal equal to `mantissa * 10^exponent`.
+    pub const fn new(mantissa: u64, exponent: i16) -> Self {
         Self { mantissa, exponent }.reduced()
     }
 
-    /// Adjusts the exponent so that the mantissa is in 1.0..10.0, e.g. 9876e3 = 9.876e6.
-    fn adjusted_exponent(self) -> i32 {
-        let (mut pow10, mut digit_count) = (1_u128, 0_i32);
-        while pow10 <= u128::from(self.mantissa) {
-            pow10 *= 10;
-            digit_count += 1;
+    /// Returns the mantissa of this value in the reduced form (i.e., without trailing zeros).
+    pub const fn mantissa(self) -> u64 {
+        self.mantissa
+    }
+
+    /// Returns the decimal exponent of this value in the reduced form.
+    pub const fn exponent(self) -> i16 {
+        self.exponent
+    }
+
+    /// Returns the number of significant decimal digits in this value, e.g. 3 for `1.25` or `0.00125`,
+    /// and 1 for `1000`.
+    pub const fn precision(self) -> u32 {
+        self.digit_count()
+    }
+
+    /// Returns the number of digits after the decimal point, e.g. 2 for `1.25`, 5 for `0.00125`,
+    /// and 0 for `1000`.
+    pub const fn scale(self) -> u16 {
+        if self.exponent < 0 {
+            self.exponent.unsigned_abs()
+        } else {
+            0
+        }
+    }
+
+    /// Checks whether this value is zero.
+    pub const fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Converts this value to `f64`. This conversion may lose precision.
+    #[allow(clippy::missing_panics_doc)] // false positive
+    pub fn to_f64(self) -> f64 {
+        // Parsing is the simplest way to get correct rounding, esp. for values close to `f64` limits.
+        format!("{self:e}")
+            .parse()
+            .expect("exponential format is always parsed")
+    }
+
+    const fn digit_count(self) -> u32 {
+        if self.mantissa == 0 {
+            1
+        } else {
+            self.mantissa.ilog10() + 1
         }
-        digit_count = digit_count.max(1); // for `mantissa == 0`
+    }
 
-        i32::from(self.exponent) + digit_count - 1
+    /// Adjusts the exponent so that the mantissa is in 1.0..10.0, e.g. 9876e3 = 9.876e6.
+    fn adjusted_exponent(self) -> i32 {
+        // `digit_count()` is at most 20, so the cast is lossless
+        i32::from(self.exponent) + self.digit_count().cast_signed() - 1
     }
 
     fn format_generic(
@@ -308,21 +450,29 @@ impl Decimal {
             u128::from(self.mantissa).checked_mul(10_u128.checked_pow(exp)?)
         } else {
             // `self.exponent` is negative.
-            let exp = -self.exponent as u32;
-            let Some(pow10) = 10_u128.checked_pow(exp) else {
-                return Some(0); // The value is too small
-            };
+            Self::round_wide(u128::from(self.mantissa), -self.exponent as u16)
+        }
+    }
 
-            let mut value = u128::from(self.mantissa) / pow10;
-            let rem = u128::from(self.mantissa) % pow10;
-            match rem.cmp(&(pow10 / 2)) {
-                cmp::Ordering::Greater => value = value.checked_add(1)?,
-                cmp::Ordering::Equal if value % 2 == 1 => value = value.checked_add(1)?,
-                _ => { /* do nothing */ }
-            }
+    /// Divides `value` by `10^exp` rounding to nearest, ties to even. Returns `None` on overflow.
+    pub(crate) fn round_wide(value: u128, exp: u16) -> Option<u128> {
+        let Some(pow10) = 10_u128.checked_pow(exp.into()) else {
+            return Some(0); // The value is too small
+        };
 
-            Some(value)
+        let mut rounded = value / pow10;
+        let rem = value % pow10;
+        match rem.cmp(&(pow10 / 2)) {
+            cmp::Ordering::Greater => rounded = rounded.checked_add(1)?,
+            cmp::Ordering::Equal if rounded % 2 == 1 => rounded = rounded.checked_add(1)?,
+            _ => { /* do nothing */ }
         }
+        Some(rounded)
+    }
+
+    fn to_exact_u64(self) -> Option<u64> {
+        let exp = u32::try_from(self.exponent).ok()?;
+        self.mantissa.checked_mul(10_u64.checked_pow(exp)?)
     }
 
     /// Multiplies this number by `10^scale` and returns the integer result.
@@ -330,7 +480,7 @@ impl Decimal {
     /// # Errors
     ///
     /// Errors on overflow, or if the output is not an integer (has a fractional part).
-    pub(crate) fn scale(self, scale: i16) -> Result<u128, serde_json::Error> {
+    pub(crate) fn scale_to_int(self, scale: i16) -> Result<u128, serde_json::Error> {
         let scaled = Self::new(
             self.mantissa,
             self.exponent.checked_add(scale).ok_or_else(|| {
@@ -348,7 +498,90 @@ impl Decimal {
         })
     }
 
-    pub(crate) fn checked_mul(self, rhs: Self) -> Option<Self> {
+    /// Multiplies this value by `rhs`. Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // Do higher precision lossless computations first, then round the result if necessary
+        let mantissa = u128::from(self.mantissa) * u128::from(rhs.mantissa);
+        let exp = i32::from(self.exponent) + i32::from(rhs.exponent);
+        Self::from_wide(mantissa, exp, false)
+    }
+
+    /// Adds `rhs` to this value. Returns `None` on overflow.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.add_or_sub(rhs, false)
+    }
+
+    /// Subtracts `rhs` from this value. Returns `None` on overflow or if the result is negative.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.add_or_sub(rhs, true)
+    }
+
+    fn add_or_sub(self, rhs: Self, is_sub: bool) -> Option<Self> {
+        if rhs.mantissa == 0 {
+            return Some(self.reduced());
+        }
+        if self.mantissa == 0 {
+            return if is_sub { None } else { Some(rhs.reduced()) };
+        }
+        if is_sub && self < rhs {
+            return None;
+        }
+
+        let (greater, lesser) = if self.exponent >= rhs.exponent {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let exp_diff = u32::from(greater.exponent.abs_diff(lesser.exponent));
+        // Max shift of the greater mantissa so that it doesn't overflow `u128`. The shifted value always exceeds `u64::MAX`
+        // (even after subtracting the lesser value), so that the result is rounded in `from_wide()`.
+        let max_shift = if greater.mantissa < 4 { 20 } else { 19 };
+
+        if exp_diff <= max_shift {
+            // The operation can be performed exactly.
+            let greater_mantissa = u128::from(greater.mantissa) * 10_u128.pow(exp_diff);
+            let lesser_mantissa = u128::from(lesser.mantissa);
+            let mantissa = if !is_sub {
+                greater_mantissa + lesser_mantissa
+            } else if self.exponent >= rhs.exponent {
+                greater_mantissa - lesser_mantissa
+            } else {
+                lesser_mantissa - greater_mantissa
+            };
+            let exp = i32::from(lesser.exponent);
+            return Self::from_wide(mantissa, exp, false);
+        }
+
+        // Since `self >= rhs` for subtraction and the exponents are far apart, `self` is the greater value here.
+        debug_assert!(!is_sub || self.exponent > rhs.exponent);
+        let greater_mantissa = u128::from(greater.mantissa) * 10_u128.pow(max_shift);
+        // `checked_pow()` may overflow for very large exponent differences; in this case, the lesser value
+        // only contributes to rounding.
+        let (lesser_mantissa, has_remainder) = match 10_u128.checked_pow(exp_diff - max_shift) {
+            Some(pow10) => {
+                let lesser = u128::from(lesser.mantissa);
+                (lesser / pow10, lesser % pow10 != 0)
+            }
+            None => (0, true),
+        };
+        let exp = i32::from(greater.exponent) - max_shift.cast_signed();
+        if is_sub {
+            // The exact value is `greater_mantissa - lesser_mantissa - (fraction in [0, 1))`; we represent it
+            // as `mantissa + (fraction in (0, 1))` if the fraction is non-zero.
+            let mantissa = greater_mantissa - lesser_mantissa - u128::from(has_remainder);
+            Self::from_wide(mantissa, exp, has_remainder)
+        } else {
+            Self::from_wide(greater_mantissa + lesser_mantissa, exp, has_remainder)
+        }
+    }
+
+    /// Converts a wide mantissa into a decimal, rounding to nearest, ties to even, if necessary.
+    /// If `has_remainder` is set, the exact value is slightly greater than `mantissa * 10^exp`;
+    /// in this case, `mantissa` must exceed `u64::MAX` (i.e., rounding must be performed).
+    fn from_wide(mut mantissa: u128, mut exp: i32, has_remainder: bool) -> Option<Self> {
         const fn threshold(i: u32) -> (u128, u128) {
             assert!(i > 0 && i <= 19);
             let pow10 = 10_u128.pow(i);
@@ -365,12 +598,8 @@ impl Decimal {
             (10_u128.pow(20), u128::MAX),
         ];
 
-        // Do higher precision lossless computations first, then return `None` if they don't work out
-        let mut mantissa = u128::from(self.mantissa) * u128::from(rhs.mantissa);
-        let mut exp = i32::from(self.exponent) + i32::from(rhs.exponent);
-
         // Reduce without precision loss if possible.
-        while mantissa > 0 && mantissa % 10 == 0 {
+        while !has_remainder && mantissa > 0 && mantissa.is_multiple_of(10) {
             mantissa /= 10;
             exp += 1;
         }
@@ -392,15 +621,18 @@ impl Decimal {
             // Round to nearest, ties to even. There can be no overflow adding 1 to `mantissa` due to the threshold checks above.
             match rem.cmp(&(pow10 / 2)) {
                 cmp::Ordering::Greater => mantissa += 1,
-                cmp::Ordering::Equal if mantissa % 2 == 1 => mantissa += 1,
+                cmp::Ordering::Equal if has_remainder || mantissa % 2 == 1 => mantissa += 1,
                 _ => { /* do nothing */ }
             }
+        } else {
+            debug_assert!(!has_remainder, "value with remainder must be rounded");
         }
 
-        let exponent = i16::try_from(exp).ok()?;
         #[allow(clippy::cast_possible_truncation)] // Doesn't happen due to checks above
         let mantissa = mantissa as u64;
-        Some(Self { mantissa, exponent })
+        let reduced = Self::new(mantissa, 0);
+        let exponent = i16::try_from(exp + i32::from(reduced.exponent)).ok()?;
+        Some(Self::new(reduced.mantissa, exponent))
     }
 
     fn from_f64<E: de::Error>(value: f64) -> Result<Self, E> {
@@ -519,6 +751,22 @@ impl FromStr for Decimal {
     }
 }
 
+impl Serialize for Decimal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let reduced = self.reduced();
+        if let Some(int) = reduced.to_exact_u64() {
+            return serializer.serialize_u64(int);
+        }
+
+        let float = reduced.to_f64();
+        if Self::from_f64::<serde_json::Error>(float).is_ok_and(|dec| dec == reduced) {
+            serializer.serialize_f64(float)
+        } else {
+            serializer.collect_str(&reduced)
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Decimal {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         struct DecimalVisitor;
@@ -617,41 +865,41 @@ mod tests {
     #[test]
     fn parsing_decimals() {
         let dec: Decimal = "1".parse().unwrap();
-        assert_eq!(dec.scale(0).unwrap(), 1);
+        assert_eq!(dec.scale_to_int(0).unwrap(), 1);
         assert_eq!(dec.to_string(), "1");
         assert_eq!(format!("{dec:e}"), "1e0");
 
         let dec: Decimal = "1.5".parse().unwrap();
-        assert_eq!(dec.scale(1).unwrap(), 15);
+        assert_eq!(dec.scale_to_int(1).unwrap(), 15);
         assert_eq!(dec.to_string(), "1.5");
         assert_eq!(format!("{dec:e}"), "1.5e0");
 
         for input in ["1500", "1500.", "1500.0", "1_500.00"] {
             let dec: Decimal = input.parse().unwrap();
-            assert_eq!(dec.scale(0).unwrap(), 1_500);
+            assert_eq!(dec.scale_to_int(0).unwrap(), 1_500);
             assert_eq!(dec.to_string(), "1500");
             assert_eq!(format!("{dec:e}"), "1.5e3");
         }
 
         for input in [".15", "0.1500", "00.150", ".150_00"] {
             let dec: Decimal = input.parse().unwrap();
-            assert_eq!(dec.scale(2).unwrap(), 15);
+            assert_eq!(dec.scale_to_int(2).unwrap(), 15);
             assert_eq!(dec.to_string(), "0.15");
             assert_eq!(format!("{dec:e}"), "1.5e-1");
         }
 
         let dec: Decimal = "1.500".parse().unwrap();
-        assert_eq!(dec.scale(1).unwrap(), 15);
+        assert_eq!(dec.scale_to_int(1).unwrap(), 15);
         assert_eq!(dec.to_string(), "1.5");
         assert_eq!(format!("{dec:e}"), "1.5e0");
 
         let dec: Decimal = "1.5001".parse().unwrap();
-        assert_eq!(dec.scale(6).unwrap(), 1_500_100);
+        assert_eq!(dec.scale_to_int(6).unwrap(), 1_500_100);
         assert_eq!(dec.to_string(), "1.5001");
         assert_eq!(format!("{dec:e}"), "1.5001e0");
 
         let dec: Decimal = "1_001.500_1".parse().unwrap();
-        assert_eq!(dec.scale(4).unwrap(), 10_015_001);
+        assert_eq!(dec.scale_to_int(4).unwrap(), 10_015_001);
         assert_eq!(dec.to_string(), "1001.5001");
         assert_eq!(format!("{dec:e}"), "1.0015001e3");
     }
@@ -803,6 +1051,171 @@ mod tests {
         assert_eq!(product.exponent, 25);
     }
 
+    #[test]
+    fn comparing_decimals() {
+        assert!(Decimal::new(15, -1) < Decimal::from(2));
+        assert!(Decimal::new(15, -1) > Decimal::from(1));
+        assert!(Decimal::new(1, -20) > Decimal::ZERO);
+        assert!(Decimal::new(999, 0) < Decimal::new(1, 3));
+        assert!(Decimal::new(1_000_001, -3) > Decimal::new(1, 3));
+        assert!(Decimal::new(u64::MAX, -19) > Decimal::new(18, -1));
+        assert!(Decimal::new(u64::MAX, -19) < Decimal::new(19, -1));
+        assert_eq!(
+            Decimal::new(15, -1).cmp(&Decimal::new(1_500, -3)),
+            cmp::Ordering::Equal
+        );
+        assert!(Decimal::MAX > Decimal::new(u64::MAX, 0));
+
+        let range = Decimal::new(1, 0)..=Decimal::new(5, 0);
+        assert!(range.contains(&Decimal::new(5, 0)));
+        assert!(range.contains(&Decimal::new(25, -1)));
+        assert!(!range.contains(&Decimal::new(5_001, -3)));
+    }
+
+    #[test]
+    fn mantissa_and_exponent() {
+        let dec: Decimal = "1.25".parse().unwrap();
+        assert_eq!((dec.mantissa(), dec.exponent()), (125, -2));
+        let dec: Decimal = "0.001_25".parse().unwrap();
+        assert_eq!((dec.mantissa(), dec.exponent()), (125, -5));
+        let dec: Decimal = "1000".parse().unwrap();
+        assert_eq!((dec.mantissa(), dec.exponent()), (1, 3));
+        assert_eq!((Decimal::ZERO.mantissa(), Decimal::ZERO.exponent()), (0, 0));
+    }
+
+    #[test]
+    fn precision_and_scale() {
+        let dec: Decimal = "1.25".parse().unwrap();
+        assert_eq!((dec.precision(), dec.scale()), (3, 2));
+        let dec: Decimal = "0.001_25".parse().unwrap();
+        assert_eq!((dec.precision(), dec.scale()), (3, 5));
+        let dec: Decimal = "1000".parse().unwrap();
+        assert_eq!((dec.precision(), dec.scale()), (1, 0));
+        assert_eq!(Decimal::ZERO.precision(), 1);
+        assert_eq!(Decimal::new(u64::MAX, 0).precision(), 20);
+    }
+
+    #[test]
+    fn deserializing_decimals_with_limits() {
+        use crate::{DescribeConfig, DeserializeConfig, de::DecimalLimits, testing};
+
+        #[derive(Debug, DescribeConfig, DeserializeConfig)]
+        #[config(crate = crate)]
+        struct ConfigWithLimits {
+            #[config(with = DecimalLimits::new(4, 2))]
+            value: Decimal,
+        }
+
+        for (input, expected) in [
+            ("12.34", Decimal::new(1_234, -2)),
+            ("0.5", Decimal::new(5, -1)),
+            ("1e6", Decimal::new(1, 6)),
+        ] {
+            let config: ConfigWithLimits = testing::test(crate::config!("value": input)).unwrap();
+            assert_eq!(config.value, expected);
+        }
+
+        for (input, expected_err) in [
+            ("123.45", "precision 5 that exceeds the limit 4"),
+            ("0.001", "scale 3 that exceeds the limit 2"),
+        ] {
+            let err =
+                testing::test::<ConfigWithLimits>(crate::config!("value": input)).unwrap_err();
+            let err = err.first();
+            assert_eq!(err.path(), "value");
+            let err = err.inner().to_string();
+            assert!(err.contains(expected_err), "{err}");
+        }
+
+        let description = ConfigWithLimits::DESCRIPTION.params[0].type_description();
+        let details = description.details().unwrap();
+        assert!(
+            details.contains("at most 4 significant digits"),
+            "{details}"
+        );
+    }
+
+    #[test]
+    fn parsing_value_exceeding_max_precision() {
+        let dec: Decimal = "1.234_567_890_123_456_789".parse().unwrap();
+        assert_eq!(dec, Decimal::new(1_234_567_890_123_456_789, -18));
+
+        let err = "9.999_999_999_999_999_999_9"
+            .parse::<Decimal>()
+            .unwrap_err();
+        assert!(err.to_string().contains("too many digits"), "{err}");
+    }
+
+    #[test]
+    fn addition_and_subtraction() {
+        let x = Decimal::new(15, -1);
+        let y = Decimal::new(25, -3);
+        assert_eq!(x.checked_add(y), Some(Decimal::new(1_525, -3)));
+        assert_eq!(x.checked_sub(y), Some(Decimal::new(1_475, -3)));
+        assert_eq!(y.checked_sub(x), None);
+        assert_eq!(x.checked_sub(x), Some(Decimal::ZERO));
+        assert_eq!(x.checked_add(Decimal::ZERO), Some(x));
+        assert_eq!(Decimal::ZERO.checked_add(x), Some(x));
+        assert_eq!(Decimal::ZERO.checked_sub(x), None);
+        assert_eq!(
+            Decimal::new(5, -1).checked_add(Decimal::new(5, -1)),
+            Some(Decimal::from(1))
+        );
+
+        // Rounding
+        let sum = Decimal::new(u64::MAX, 0)
+            .checked_add(Decimal::from(1))
+            .unwrap();
+        assert_eq!(sum, Decimal::new(1_844_674_407_370_955_162, 1));
+        let sum = Decimal::from(1).checked_add(Decimal::new(1, -30)).unwrap();
+        assert_eq!(sum, Decimal::from(1));
+        let diff = Decimal::from(1).checked_sub(Decimal::new(1, -19)).unwrap();
+        assert_eq!(diff, Decimal::new(9_999_999_999_999_999_999, -19));
+        let diff = Decimal::from(1).checked_sub(Decimal::new(1, -30)).unwrap();
+        assert_eq!(diff, Decimal::from(1));
+        let diff = Decimal::from(1).checked_sub(Decimal::new(1, -300)).unwrap();
+        assert_eq!(diff, Decimal::from(1));
+        // Tie broken to even
+        let sum = Decimal::new(2, 19).checked_add(Decimal::from(5)).unwrap();
+        assert_eq!(sum, Decimal::new(2, 19));
+        // Tie broken by the remainder
+        let sum = Decimal::new(2, 19)
+            .checked_add(Decimal::new(5_000_000_000_000_000_001, -18))
+            .unwrap();
+        assert_eq!(sum, Decimal::new(2_000_000_000_000_000_001, 1));
+
+        // Overflow
+        assert_eq!(Decimal::MAX.checked_add(Decimal::MAX), None);
+        assert_eq!(Decimal::MAX.checked_add(Decimal::ZERO), Some(Decimal::MAX));
+    }
+
+    #[test]
+    fn serializing_decimals() {
+        let cases = [
+            (Decimal::ZERO, serde_json::json!(0)),
+            (Decimal::new(123, 3), serde_json::json!(123_000)),
+            (Decimal::new(15, -1), serde_json::json!(1.5)),
+            (Decimal::new(1, -1), serde_json::json!(0.1)),
+            (Decimal::new(25, -10), serde_json::json!(2.5e-9)),
+            (Decimal::new(u64::MAX, 0), serde_json::json!(u64::MAX)),
+            (
+                Decimal::new(u64::MAX, 1),
+                serde_json::json!("1.8446744073709551615e20"),
+            ),
+            (
+                Decimal::new(1_123_456_789_012_345_678, -18),
+                serde_json::json!("1.123456789012345678"),
+            ),
+            (Decimal::new(1, -400), serde_json::json!("1e-400")),
+        ];
+        for (dec, expected) in cases {
+            let serialized = serde_json::to_value(dec).unwrap();
+            assert_eq!(serialized, expected, "{dec:?}");
+            let restored: Decimal = serde_json::from_value(serialized).unwrap();
+            assert_eq!(restored, dec);
+        }
+    }
+
     #[test]
     fn converting_decimals_from_f64() {
         let dec = Decimal::from_f64::<serde_json::Error>(0.0).unwrap();
@@ -934,6 +1347,32 @@ mod prop_tests {
             prop_assert_eq!(x_dec.checked_mul(y_dec), Some(Decimal::from(x * y)));
         }
 
+        #[test]
+        fn u64_addition(x: u64, y: u64) {
+            let sum = Decimal::from(x).checked_add(Decimal::from(y)).unwrap();
+            prop_assert!(sum.exponent >= 0);
+            let pow10 = 10_u128.pow(sum.exponent.try_into().unwrap());
+            let actual = u128::from(sum.mantissa) * pow10;
+            let expected = u128::from(x) + u128::from(y);
+            prop_assert!(actual.abs_diff(expected) <= pow10 / 2, "{actual}, {expected}");
+        }
+
+        #[test]
+        fn decimal_addition_and_subtraction(x: u32, x_exp in -4_i16..5, y: u32, y_exp in -4_i16..5) {
+            // Exponents are chosen so that operations are exact
+            let (x, y) = (Decimal::new(x.into(), x_exp), Decimal::new(y.into(), y_exp));
+            let sum = x.checked_add(y).unwrap();
+            prop_assert!(sum >= x && sum >= y);
+            prop_assert_eq!(sum.checked_sub(y), Some(x));
+            prop_assert_eq!(sum.checked_sub(x), Some(y));
+            prop_assert_eq!(x.checked_sub(y).is_some(), x >= y);
+        }
+
+        #[test]
+        fn decimal_ordering_is_consistent_with_f64((_, x) in f64_string(15), (_, y) in f64_string(15)) {
+            prop_assert_eq!(x.cmp(&y), x.to_f64().total_cmp(&y.to_f64()));
+        }
+
         #[test]
         fn u64_multiplication(x: u64, y: u64) {
             let x_dec = Decimal::from(x);
@@ -2,7 +2,7 @@
 
 use serde::de;
 
-pub(crate) use self::decimal::Decimal;
+pub use self::decimal::Decimal;
 use crate::metadata::ConfigMetadata;
 
 mod decimal;
@@ -87,55 +87,79 @@ pub(crate) struct EnumVariant<'a> {
 
 impl<'a> EnumVariant<'a> {
     pub(crate) fn new(raw: &'a str) -> Option<Self> {
+        let (case, sep) = Self::detect_case(raw)?;
+        let words = if let Some(sep) = sep {
+            raw.split(char::from(sep))
+                .filter(|word| !word.is_empty())
+                .collect()
+        } else if matches!(case, VariantCase::CamelCase) {
+            let mut words = vec![];
+            let mut word_start = 0;
+            for (pos, ch) in raw.bytes().enumerate() {
+                if ch.is_ascii_uppercase() && pos > 0 {
+                    words.push(&raw[word_start..pos]);
+                    word_start = pos;
+                }
+            }
+            words.push(&raw[word_start..]);
+            words
+        } else {
+            vec![raw]
+        };
+        Some(Self { raw, words, case })
+    }
+
+    /// Checks whether the variant name can be parsed, i.e., whether [`Self::new()`] returns `Some(_)`.
+    pub(crate) const fn is_valid(raw: &str) -> bool {
+        Self::detect_case(raw).is_some()
+    }
+
+    /// Detects the case of the variant name together with the word separator.
+    const fn detect_case(raw: &str) -> Option<(VariantCase, Option<u8>)> {
         if raw.is_empty() || !raw.is_ascii() {
             return None;
         }
 
+        let bytes = raw.as_bytes();
         let mut sep = None::<u8>;
-        let mut words = vec![];
         let mut word_start = 0;
+        let mut has_words = false;
         let mut is_lowercase = true;
         let mut is_uppercase = true;
-        for (pos, ch) in raw.bytes().enumerate() {
-            match ch {
-                b'-' | b'_' => {
-                    if let Some(prev_sep) = sep
-                        && prev_sep != ch
-                    {
-                        return None; // Inconsistent separator
-                    }
-                    if word_start == pos {
-                        // Two separators in a row
-                        return None;
-                    }
-
-                    sep = Some(ch);
-                    let word = &raw[word_start..pos];
-                    if !word.is_empty() {
-                        words.push(word);
-                    }
-                    word_start = pos + 1;
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let ch = bytes[pos];
+            if ch == b'-' || ch == b'_' {
+                if let Some(prev_sep) = sep
+                    && prev_sep != ch
+                {
+                    return None; // Inconsistent separator
                 }
-                ch if ch.is_ascii_alphanumeric() => {
-                    // Part of a word.
-                    if ch.is_ascii_uppercase() {
-                        is_lowercase = false;
-                    } else if ch.is_ascii_lowercase() {
-                        is_uppercase = false;
-                    }
+                if word_start == pos {
+                    // Two separators in a row
+                    return None;
                 }
-                _ => return None, // Unknown separator
+                sep = Some(ch);
+                has_words = true;
+                word_start = pos + 1;
+            } else if ch.is_ascii_alphanumeric() {
+                // Part of a word.
+                if ch.is_ascii_uppercase() {
+                    is_lowercase = false;
+                } else if ch.is_ascii_lowercase() {
+                    is_uppercase = false;
+                }
+            } else {
+                return None; // Unknown separator
             }
 
             if !is_lowercase && !is_uppercase && sep.is_some() {
                 return None; // Mixed case + splitter
             }
+            pos += 1;
         }
-        let last_word = &raw[word_start..];
-        if !last_word.is_empty() {
-            words.push(last_word);
-        }
-        if words.is_empty() {
+        has_words |= word_start < bytes.len();
+        if !has_words {
             return None; // Degenerate case like `_`
         }
 
@@ -144,26 +168,11 @@ impl<'a> EnumVariant<'a> {
             Some(b'_') | None if is_uppercase => VariantCase::ScreamingSnakeCase,
             Some(b'-') if is_lowercase => VariantCase::KebabCase,
             Some(b'-') if is_uppercase => VariantCase::ScreamingKebabCase,
-            None => {
-                // Guaranteed to have mixed case at this point.
-                debug_assert_eq!(words.len(), 1);
-
-                words.clear();
-                let mut word_start = 0;
-                for (pos, ch) in raw.bytes().enumerate() {
-                    if ch.is_ascii_uppercase() && pos > 0 {
-                        words.push(&raw[word_start..pos]);
-                        word_start = pos;
-                    }
-                }
-                words.push(&raw[word_start..]);
-
-                VariantCase::CamelCase
-            }
+            // Guaranteed to have mixed case at this point.
+            None => VariantCase::CamelCase,
             _ => return None, // mixed case etc.
         };
-
-        Some(Self { raw, words, case })
+        Some((case, sep))
     }
 
     fn transform(&self, to_case: TargetCase) -> String {
@@ -197,6 +206,19 @@ impl<'a> EnumVariant<'a> {
         self.transform(TargetCase::SnakeCase)
     }
 
+    pub(crate) fn to_kebab_case(&self) -> String {
+        self.transform(TargetCase::KebabCase)
+    }
+
+    /// Transforms to `camelCase` with the lowercase first letter (as opposed to `TargetCase::CamelCase`).
+    pub(crate) fn to_lower_camel_case(&self) -> String {
+        let mut transformed = self.transform(TargetCase::CamelCase);
+        if let Some(first_char) = transformed.get_mut(..1) {
+            first_char.make_ascii_lowercase();
+        }
+        transformed
+    }
+
     // This logic can be optimized, e.g. by detecting the case in `variants`.
     pub(crate) fn try_match(&self, variants: &[&'static str]) -> Option<&'static str> {
         // First, search a complete match to provide a shortcut for the common case.
@@ -243,6 +265,19 @@ pub(crate) fn merge_json(
     deep_merge(target, value);
 }
 
+/// Parses an application version as a [semantic version](semver::Version). Unlike with `semver`, the version may have
+/// a `v` prefix, and minor / patch components may be omitted (e.g., `1.0` or `v2`), in which case they are treated as zeros.
+pub(crate) fn parse_version(version: &str) -> Result<semver::Version, semver::Error> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    let core_len = version.find(['-', '+']).unwrap_or(version.len());
+    let (core, suffix) = version.split_at(core_len);
+    match core.bytes().filter(|&ch| ch == b'.').count() {
+        0 => format!("{core}.0.0{suffix}").parse(),
+        1 => format!("{core}.0{suffix}").parse(),
+        _ => version.parse(),
+    }
+}
+
 fn deep_merge(dest: &mut JsonObject, src: JsonObject) {
     for (key, value) in src {
         if let Some(existing) = dest.get_mut(&key) {
@@ -267,6 +302,27 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn parsing_versions() {
+        let version = parse_version("1.0").unwrap();
+        assert_eq!(version, semver::Version::new(1, 0, 0));
+        assert_eq!(parse_version("v2").unwrap(), semver::Version::new(2, 0, 0));
+        assert_eq!(
+            parse_version("v1.2.0").unwrap(),
+            semver::Version::new(1, 2, 0)
+        );
+
+        let version = parse_version("1.0-rc.1+abcdef").unwrap();
+        assert_eq!(version.to_string(), "1.0.0-rc.1+abcdef");
+        let prev_version = parse_version("1.0.0-rc.0").unwrap();
+        assert!(prev_version < version);
+        assert!(version < semver::Version::new(1, 0, 0));
+
+        for invalid_version in ["", "1.0.x", "1..0", "1.0.0.1", "1.0-", "not a version"] {
+            parse_version(invalid_version).unwrap_err();
+        }
+    }
+
     #[test]
     fn detecting_cases() {
         let variant = EnumVariant::new("snake_case10_12").unwrap();
@@ -330,6 +386,20 @@ mod tests {
         // Mixed case + separator
         let variant = EnumVariant::new("snake_Case10_12");
         assert!(variant.is_none(), "{variant:?}");
+
+        for raw in [
+            "змея",
+            "snake!case10",
+            "snake_case10-12",
+            "snake_Case10_12",
+            "_",
+            "",
+        ] {
+            assert!(!EnumVariant::is_valid(raw), "{raw}");
+        }
+        for raw in ["snake_case10_12", "KEBAB-CASE", "CamelCase", "snake"] {
+            assert!(EnumVariant::is_valid(raw), "{raw}");
+        }
     }
 
     fn assert_case_transforms(variant: &EnumVariant) {
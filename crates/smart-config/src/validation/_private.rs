@@ -1,6 +1,10 @@
-use std::{any, fmt, marker::PhantomData};
+use std::{any, fmt, marker::PhantomData, sync::Arc};
 
-use crate::{ErrorWithOrigin, validation::Validate};
+use crate::{
+    ErrorWithOrigin,
+    validation::{Severity, Validate},
+    value::ValueOrigin,
+};
 
 /// Tag for `WithDescription` wrapping a type that already implements a validation.
 #[derive(Debug)]
@@ -43,6 +47,22 @@ impl<T, V: Validate<T>> Validate<T> for WithDescription<V, Delegated> {
     fn validate(&self, target: &T) -> Result<(), ErrorWithOrigin> {
         self.inner.validate(target)
     }
+
+    fn severity(&self) -> Severity {
+        self.inner.severity()
+    }
+
+    fn allowed_values(&self) -> Option<Vec<String>> {
+        self.inner.allowed_values()
+    }
+
+    fn validate_with_origin(
+        &self,
+        target: &T,
+        origin: &Arc<ValueOrigin>,
+    ) -> Result<(), ErrorWithOrigin> {
+        self.inner.validate_with_origin(target, origin)
+    }
 }
 
 impl<T, F> Validate<T> for WithDescription<F, BoolPredicate>
@@ -74,6 +94,45 @@ where
     }
 }
 
+/// Wrapper for validation allowing to redefine its severity.
+#[derive(Debug)]
+pub struct WithSeverity<V> {
+    inner: V,
+    severity: Severity,
+}
+
+impl<V> WithSeverity<V> {
+    pub const fn new(inner: V, severity: Severity) -> Self {
+        Self { inner, severity }
+    }
+}
+
+impl<T: ?Sized, V: Validate<T>> Validate<T> for WithSeverity<V> {
+    fn describe(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.describe(formatter)
+    }
+
+    fn validate(&self, target: &T) -> Result<(), ErrorWithOrigin> {
+        self.inner.validate(target)
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn allowed_values(&self) -> Option<Vec<String>> {
+        self.inner.allowed_values()
+    }
+
+    fn validate_with_origin(
+        &self,
+        target: &T,
+        origin: &Arc<ValueOrigin>,
+    ) -> Result<(), ErrorWithOrigin> {
+        self.inner.validate_with_origin(target, origin)
+    }
+}
+
 /// Erases the validated type (`T`) from `Validate`.
 #[derive(Debug)]
 pub struct ErasedValidation<T, V> {
@@ -88,6 +147,12 @@ impl<T: 'static, V: Validate<T>> ErasedValidation<T, V> {
             _ty: PhantomData,
         }
     }
+
+    fn downcast(target: &dyn any::Any) -> &T {
+        target
+            .downcast_ref()
+            .expect("Internal error: unexpected target type")
+    }
 }
 
 impl<T: 'static, V: Validate<T>> Validate<dyn any::Any> for ErasedValidation<T, V> {
@@ -96,9 +161,23 @@ impl<T: 'static, V: Validate<T>> Validate<dyn any::Any> for ErasedValidation<T,
     }
 
     fn validate(&self, target: &dyn any::Any) -> Result<(), ErrorWithOrigin> {
-        let target: &T = target
-            .downcast_ref()
-            .expect("Internal error: unexpected target type");
-        self.validation.validate(target)
+        self.validation.validate(Self::downcast(target))
+    }
+
+    fn severity(&self) -> Severity {
+        self.validation.severity()
+    }
+
+    fn allowed_values(&self) -> Option<Vec<String>> {
+        self.validation.allowed_values()
+    }
+
+    fn validate_with_origin(
+        &self,
+        target: &dyn any::Any,
+        origin: &Arc<ValueOrigin>,
+    ) -> Result<(), ErrorWithOrigin> {
+        self.validation
+            .validate_with_origin(Self::downcast(target), origin)
     }
 }
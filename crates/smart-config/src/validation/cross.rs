@@ -0,0 +1,104 @@
+//! Schema-level validations spanning multiple configs.
+
+use std::{any, fmt, sync::Arc};
+
+use crate::{DeserializeConfig, ErrorWithOrigin, metadata::ConfigMetadata};
+
+/// Tuple of configs participating in a [schema-level validation](crate::ConfigSchema::validate_configs()).
+///
+/// This trait is implemented for tuples of 1 to 4 [`DeserializeConfig`] types. It cannot be implemented
+/// outside the crate.
+pub trait ConfigTuple: 'static + sealed::Sealed {
+    /// Tuple of references to configs passed to the validation closure.
+    type Refs<'a>;
+
+    #[doc(hidden)] // implementation detail
+    fn metadata() -> Vec<&'static ConfigMetadata>;
+
+    #[doc(hidden)] // implementation detail
+    fn downcast<'a>(configs: &[&'a dyn any::Any]) -> Option<Self::Refs<'a>>;
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_config_tuple {
+    ($($ty:ident: $idx:tt),+) => {
+        impl<$($ty: DeserializeConfig,)+> sealed::Sealed for ($($ty,)+) {}
+
+        impl<$($ty: DeserializeConfig,)+> ConfigTuple for ($($ty,)+) {
+            type Refs<'a> = ($(&'a $ty,)+);
+
+            fn metadata() -> Vec<&'static ConfigMetadata> {
+                vec![$(&$ty::DESCRIPTION,)+]
+            }
+
+            fn downcast<'a>(configs: &[&'a dyn any::Any]) -> Option<Self::Refs<'a>> {
+                Some(($(configs.get($idx)?.downcast_ref::<$ty>()?,)+))
+            }
+        }
+    };
+}
+
+impl_config_tuple!(A: 0);
+impl_config_tuple!(A: 0, B: 1);
+impl_config_tuple!(A: 0, B: 1, C: 2);
+impl_config_tuple!(A: 0, B: 1, C: 2, D: 3);
+
+type ErasedValidation = dyn Fn(&[&dyn any::Any]) -> Result<(), ErrorWithOrigin> + Send + Sync;
+
+/// Location of a param participating in a schema-level validation.
+#[derive(Debug, Clone)]
+pub(crate) struct ParticipatingParam {
+    pub(crate) path: String,
+    /// Index of the config in [`SchemaValidation::configs`].
+    pub(crate) config_idx: usize,
+    pub(crate) param_idx: usize,
+}
+
+/// Type-erased schema-level validation.
+#[derive(Clone)]
+pub(crate) struct SchemaValidation {
+    pub(crate) description: &'static str,
+    /// Participating configs together with their canonical prefixes.
+    pub(crate) configs: Vec<(&'static ConfigMetadata, String)>,
+    pub(crate) params: Vec<ParticipatingParam>,
+    validation: Arc<ErasedValidation>,
+}
+
+impl fmt::Debug for SchemaValidation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("SchemaValidation")
+            .field("description", &self.description)
+            .field("configs", &self.configs)
+            .field("params", &self.params)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SchemaValidation {
+    pub(crate) fn new<Cs: ConfigTuple>(
+        description: &'static str,
+        configs: Vec<(&'static ConfigMetadata, String)>,
+        params: Vec<ParticipatingParam>,
+        validation: impl for<'a> Fn(Cs::Refs<'a>) -> Result<(), ErrorWithOrigin> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            description,
+            configs,
+            params,
+            validation: Arc::new(move |configs| {
+                let refs = Cs::downcast(configs)
+                    .expect("internal error: unexpected config types in schema validation");
+                validation(refs)
+            }),
+        }
+    }
+
+    /// Runs validation on parsed configs, which must be provided in the same order as [`Self::configs`].
+    pub(crate) fn validate(&self, configs: &[&dyn any::Any]) -> Result<(), ErrorWithOrigin> {
+        (self.validation)(configs)
+    }
+}
@@ -4,6 +4,10 @@
 //!
 //! The core validation functionality is encapsulated in the [`Validate`] trait.
 //!
+//! Validations spanning multiple configs (potentially mounted at different paths) can be registered
+//! on the [`ConfigSchema`](crate::ConfigSchema) via [`validate_configs()`](crate::ConfigSchema::validate_configs()).
+//! Such validations are run by [`ConfigRepository::validate()`](crate::ConfigRepository::validate()).
+//!
 //! # Examples
 //!
 //! ## Validation
@@ -114,10 +118,58 @@ use serde::de;
 use crate::{
     ErrorWithOrigin,
     pat::{LazyRegex, RawStr},
+    value::ValueOrigin,
+};
+
+pub(crate) use self::cross::{ParticipatingParam, SchemaValidation};
+pub use self::{
+    builtin::{InNetwork, IsDir, IsFile, Len, MultipleOf, OneOf, PathExists, Unique, UrlScheme},
+    cross::ConfigTuple,
 };
 
 #[doc(hidden)] // only used in proc macros
 pub mod _private;
+mod builtin;
+mod cross;
+
+/// Severity of a [validation](Validate).
+///
+/// Only [`Deny`](Self::Deny) validations cause parsing to fail. Other failed validations are reported as warnings;
+/// they can be retrieved using [`ConfigParser::parse_with_warnings()`](crate::ConfigParser::parse_with_warnings())
+/// or [`ParseErrors::warnings()`](crate::ParseErrors::warnings()), and are output by CLI tooling.
+/// This allows to tighten checks gradually; e.g., a validation can be introduced with the `Warn` severity
+/// and promoted to `Deny` once all deployments conform to it.
+///
+/// In derive macros, the severity can be specified as `severity = deny | warn | info` in the `validate` attribute,
+/// e.g. `#[config(validate(100.., "is unusually low", severity = warn))]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Severity {
+    /// Informational message.
+    Info,
+    /// Non-fatal warning.
+    Warn,
+    /// Failed validation leads to a parsing error. This is the default severity.
+    #[default]
+    Deny,
+}
+
+impl Severity {
+    /// Checks whether a failed validation with this severity is fatal.
+    pub fn is_fatal(self) -> bool {
+        matches!(self, Self::Deny)
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        })
+    }
+}
 
 /// Generic post-validation for a configuration parameter or a config.
 ///
@@ -127,6 +179,14 @@ pub mod _private;
 ///
 /// - [`NotEmpty`]. Validates that a string or a collection, such as `Vec`, is not empty.
 /// - [`Range`](ops::Range), [`RangeInclusive`](ops::RangeInclusive) etc. Validates whether the type is within the provided bounds.
+/// - [`LazyRegex`]. Validates that a string matches the regex.
+/// - [`OneOf`]. Validates that a value is one of the allowed values.
+/// - [`Len`]. Validates that the length of a string or a collection is within the provided bounds.
+/// - [`Unique`]. Validates that `Vec` items are unique.
+/// - [`PathExists`], [`IsDir`], [`IsFile`]. Validate paths in the file system.
+/// - [`InNetwork`]. Validates that an IP address belongs to a network.
+/// - [`UrlScheme`]. Validates that a URL has one of the allowed schemes.
+/// - [`MultipleOf`]. Validates that an integer or a value with a unit of measurement is a multiple of the provided value.
 pub trait Validate<T: ?Sized>: 'static + Send + Sync {
     /// Describes this validation.
     ///
@@ -141,6 +201,34 @@ pub trait Validate<T: ?Sized>: 'static + Send + Sync {
     ///
     /// Should return an error if validation fails.
     fn validate(&self, target: &T) -> Result<(), ErrorWithOrigin>;
+
+    /// Returns the severity of this validation. By default, returns [`Severity::Deny`].
+    fn severity(&self) -> Severity {
+        Severity::Deny
+    }
+
+    /// Returns the allowed values if this validation restricts the value to an enumeration (e.g., [`OneOf`]).
+    /// Values should be formatted using `Debug`. By default, returns `None`.
+    ///
+    /// Allowed values are surfaced in the [type description](crate::metadata::TypeDescription::allowed_values()).
+    fn allowed_values(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    /// Validates a parameter taking into account the origin of its value. By default, delegates to [`Self::validate()`].
+    ///
+    /// # Errors
+    ///
+    /// Should return an error if validation fails.
+    #[doc(hidden)] // not stable yet
+    fn validate_with_origin(
+        &self,
+        target: &T,
+        origin: &Arc<ValueOrigin>,
+    ) -> Result<(), ErrorWithOrigin> {
+        let _ = origin;
+        self.validate(target)
+    }
 }
 
 impl<T: 'static + ?Sized> fmt::Debug for dyn Validate<T> {
@@ -167,6 +255,22 @@ impl<T: ?Sized, V: Validate<T> + ?Sized> Validate<T> for &'static V {
     fn validate(&self, target: &T) -> Result<(), ErrorWithOrigin> {
         (**self).validate(target)
     }
+
+    fn severity(&self) -> Severity {
+        (**self).severity()
+    }
+
+    fn allowed_values(&self) -> Option<Vec<String>> {
+        (**self).allowed_values()
+    }
+
+    fn validate_with_origin(
+        &self,
+        target: &T,
+        origin: &Arc<ValueOrigin>,
+    ) -> Result<(), ErrorWithOrigin> {
+        (**self).validate_with_origin(target, origin)
+    }
 }
 
 macro_rules! impl_validate_for_range {
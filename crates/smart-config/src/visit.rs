@@ -2,7 +2,12 @@
 
 use std::{any, any::Any, mem};
 
-use crate::{SerializerOptions, metadata::ConfigMetadata, utils::JsonObject, value::Pointer};
+use crate::{
+    SerializerOptions,
+    metadata::{ConfigMetadata, ConfigVariant, TagRepresentation},
+    utils::{JsonObject, merge_json},
+    value::Pointer,
+};
 
 /// Visitor of configuration parameters in a particular configuration.
 #[doc(hidden)] // API is not stable yet
@@ -45,6 +50,8 @@ pub(crate) struct Serializer {
     metadata: &'static ConfigMetadata,
     // Only filled when serializing into a flat object.
     current_prefix: Option<String>,
+    // Only filled for externally tagged enum configs.
+    variant_key: Option<String>,
     json: JsonObject,
     options: SerializerOptions,
 }
@@ -59,6 +66,7 @@ impl Serializer {
         Self {
             metadata,
             current_prefix: options.flat.then(|| prefix.to_owned()),
+            variant_key: None,
             json: serde_json::Map::new(),
             options,
         }
@@ -69,13 +77,37 @@ impl Serializer {
         self.json
     }
 
-    fn insert(&mut self, param_name: &str, value: serde_json::Value) {
-        let key = if let Some(prefix) = &self.current_prefix {
-            Pointer(prefix).join(param_name)
+    /// Returns the path to a variant-specific param or nested config relative to the current config.
+    fn variant_path(&self, name: &str) -> String {
+        match &self.variant_key {
+            Some(variant_key) => Pointer(variant_key).join(name),
+            None => name.to_owned(),
+        }
+    }
+
+    fn insert(&mut self, path: &str, value: serde_json::Value) {
+        if let Some(prefix) = &self.current_prefix {
+            self.json.insert(Pointer(prefix).join(path), value);
+        } else if let Some((parent, name)) = path.rsplit_once('.') {
+            let object = serde_json::Map::from_iter([(name.to_owned(), value)]);
+            merge_json(&mut self.json, self.metadata, parent, object);
         } else {
-            param_name.to_owned()
+            self.json.insert(path.to_owned(), value);
+        }
+    }
+
+    /// Checks whether a param specific to `tag_variant` must be serialized so that the enum variant can be detected when parsing.
+    fn is_detected_by_params(&self, tag_variant: Option<&ConfigVariant>) -> bool {
+        let Some(tag) = &self.metadata.tag else {
+            return false;
         };
-        self.json.insert(key, value);
+        let is_default_variant = tag
+            .default_variant
+            .zip(tag_variant)
+            .is_some_and(|(default, variant)| default.rust_name == variant.rust_name);
+        tag.representation != TagRepresentation::Internal
+            && tag_variant.is_some()
+            && !is_default_variant
     }
 }
 
@@ -88,8 +120,26 @@ impl ConfigVisitor for Serializer {
             || tag
                 .default_variant
                 .is_none_or(|default_variant| default_variant.rust_name != tag_variant.rust_name);
-        if should_insert {
-            self.insert(tag.param.name, tag_variant.name.into());
+        match tag.representation {
+            TagRepresentation::External => {
+                // Variant names are checked to be convertible to object keys when validating config metadata.
+                let variant_key = tag_variant
+                    .external_keys()
+                    .next()
+                    .expect("Internal error: variant cannot be converted to an object key");
+                if should_insert && self.current_prefix.is_none() {
+                    // Insert an empty variant object so that the variant is detected even if it has no params.
+                    self.json
+                        .insert(variant_key.clone(), serde_json::Map::new().into());
+                }
+                self.variant_key = Some(variant_key);
+            }
+            TagRepresentation::Untagged => { /* the variant is detected by params */ }
+            TagRepresentation::Internal => {
+                if should_insert {
+                    self.insert(tag.param.name, tag_variant.name.into());
+                }
+            }
         }
     }
 
@@ -100,29 +150,45 @@ impl ConfigVisitor for Serializer {
 
         // If a parameter has a fallback, it should be inserted regardless of whether it has the default value;
         // otherwise, since fallbacks have higher priority than defaults, the parameter value may be unexpected after parsing
-        // the produced JSON.
+        // the produced JSON. Similarly, variant-specific params for non-internally tagged enums are always inserted
+        // so that the variant can be detected.
         let should_insert = !self.options.diff_with_default
             || param.fallback.is_some()
+            || self.is_detected_by_params(param.tag_variant)
             || param.default_value_json().as_ref() != Some(&value);
         if should_insert {
-            if let (Some(placeholder), true) = (
-                &self.options.secret_placeholder,
-                param.type_description().contains_secrets(),
-            ) {
-                value = placeholder.clone().into();
+            if let Some(placeholder) = &self.options.secret_placeholder {
+                let description = param.type_description();
+                if description.contains_secrets() {
+                    value = placeholder.clone().into();
+                } else {
+                    description.redact_json(&mut value, placeholder);
+                }
             }
-            self.insert(param.name, value);
+
+            let path = if param.tag_variant.is_some() {
+                self.variant_path(param.name)
+            } else {
+                param.name.to_owned()
+            };
+            self.insert(&path, value);
         }
     }
 
     fn visit_nested_config(&mut self, config_index: usize, config: &dyn VisitConfig) {
         let nested_metadata = &self.metadata.nested_configs[config_index];
+        let path = if nested_metadata.tag_variant.is_some() {
+            self.variant_path(nested_metadata.name)
+        } else {
+            nested_metadata.name.to_owned()
+        };
         let prev_metadata = mem::replace(&mut self.metadata, nested_metadata.meta);
+        let prev_variant_key = self.variant_key.take();
 
-        if nested_metadata.name.is_empty() {
+        if path.is_empty() {
             config.visit_config(self);
         } else if let Some(prefix) = &mut self.current_prefix {
-            let new_prefix = Pointer(prefix).join(nested_metadata.name);
+            let new_prefix = Pointer(prefix).join(&path);
             let prev_prefix = mem::replace(prefix, new_prefix);
             config.visit_config(self);
             self.current_prefix = Some(prev_prefix);
@@ -133,12 +199,15 @@ impl ConfigVisitor for Serializer {
             let nested_json = mem::take(&mut self.json);
             let should_insert = !self.options.diff_with_default || !nested_json.is_empty();
             if should_insert {
-                prev_json.insert(nested_metadata.name.to_owned(), nested_json.into());
+                let (parent, name) = path.rsplit_once('.').unwrap_or(("", &path));
+                let object = serde_json::Map::from_iter([(name.to_owned(), nested_json.into())]);
+                merge_json(&mut prev_json, prev_metadata, parent, object);
             }
             self.json = prev_json;
         }
 
         self.metadata = prev_metadata;
+        self.variant_key = prev_variant_key;
     }
 }
 
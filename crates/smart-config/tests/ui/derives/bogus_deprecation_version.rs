@@ -0,0 +1,9 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    #[config(deprecated(since = "0.9.x", note = "use `max_rps`"))]
+    rps: u64,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    #[config(env = "legacy_port")]
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    port: u16,
+    #[config(nest, default_from(|port| NestedConfig { port: *port }))]
+    nested: NestedConfig,
+}
+
+#[derive(DescribeConfig)]
+struct NestedConfig {
+    port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    port: u16,
+    #[config(default_t = 3001, default_from(|port| port + 1))]
+    metrics_port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    port: u16,
+    #[config(default_from(|debug_port| debug_port - 1))]
+    metrics_port: u16,
+    #[config(default_from(|port| port + 2))]
+    debug_port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    port: u16,
+    #[config(default_from(3001))]
+    metrics_port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig<'a> {
+    name: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    cert: String,
+    #[config(required_with = cert)]
+    key: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+#[config(one_of_required(url, path))]
+struct TestConfig {
+    url: Option<String>,
+    file: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+#[config(tag = "type")]
+enum TestConfig {
+    #[config(deprecated = "google")]
+    Gcs { bucket: String },
+    S3 { bucket: String },
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct NestedConfig {
+    str: String,
+}
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    #[config(flatten, deprecated(since = "0.9"))]
+    nested: NestedConfig,
+}
+
+fn main() {}
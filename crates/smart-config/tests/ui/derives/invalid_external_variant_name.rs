@@ -0,0 +1,11 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+#[config(tag = external)]
+enum TestConfig {
+    #[config(rename = "in_memory-store")]
+    Memory,
+    S3 { bucket: String },
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+#[config(tag = internal)]
+enum TestConfig {
+    V0 { str: String },
+    V1 { value: u64 },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    #[config(validate(..10, "is too large", severity = fatal))]
+    int: u32,
+}
+
+fn main() {}
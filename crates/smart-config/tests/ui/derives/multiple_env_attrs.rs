@@ -0,0 +1,9 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    #[config(env = "LEGACY_PORT", env = "PORT")]
+    port: u16,
+}
+
+fn main() {}
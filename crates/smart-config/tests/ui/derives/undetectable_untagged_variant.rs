@@ -0,0 +1,10 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+#[config(untagged)]
+enum TestConfig {
+    V0,
+    V1 { value: u64 },
+}
+
+fn main() {}
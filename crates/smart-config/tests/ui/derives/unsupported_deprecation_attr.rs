@@ -0,0 +1,9 @@
+use smart_config::DescribeConfig;
+
+#[derive(DescribeConfig)]
+struct TestConfig {
+    #[config(deprecated(reason = "use `max_rps`"))]
+    rps: u64,
+}
+
+fn main() {}